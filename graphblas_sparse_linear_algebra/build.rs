@@ -8,4 +8,9 @@ fn main() {
              exclusive. Enable only one allocator feature at a time."
         );
     }
+
+    println!("cargo:rerun-if-changed=c/burble.c");
+    cc::Build::new()
+        .file("c/burble.c")
+        .compile("graphblas_sparse_linear_algebra_burble");
 }
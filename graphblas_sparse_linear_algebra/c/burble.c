@@ -0,0 +1,67 @@
+// Formats the diagnostic output of SuiteSparse:GraphBLAS, and passes the text to Rust.
+// GraphBLAS calls a printf-like variadic function, which cannot be defined in stable Rust.
+// The handlers are atomic, because GraphBLAS may print from another thread while they are set.
+
+#include <stdarg.h>
+#include <stdatomic.h>
+#include <stddef.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+typedef void (*burble_writer)(const char *text, size_t length);
+typedef void (*burble_flusher)(void);
+
+static _Atomic(burble_writer) writer = NULL;
+static _Atomic(burble_flusher) flusher = NULL;
+
+void graphblas_sparse_linear_algebra_burble_set_handlers(burble_writer new_writer,
+                                                         burble_flusher new_flusher)
+{
+    atomic_store(&writer, new_writer);
+    atomic_store(&flusher, new_flusher);
+}
+
+int graphblas_sparse_linear_algebra_burble_printf(const char *format, ...)
+{
+    burble_writer current_writer = atomic_load(&writer);
+    char buffer[1024];
+    va_list arguments;
+
+    va_start(arguments, format);
+    int length = vsnprintf(buffer, sizeof(buffer), format, arguments);
+    va_end(arguments);
+
+    if (length < 0 || current_writer == NULL)
+    {
+        return length;
+    }
+
+    if ((size_t)length < sizeof(buffer))
+    {
+        current_writer(buffer, (size_t)length);
+        return length;
+    }
+
+    char *long_buffer = malloc((size_t)length + 1);
+    if (long_buffer == NULL)
+    {
+        current_writer(buffer, sizeof(buffer) - 1);
+        return length;
+    }
+    va_start(arguments, format);
+    vsnprintf(long_buffer, (size_t)length + 1, format, arguments);
+    va_end(arguments);
+    current_writer(long_buffer, (size_t)length);
+    free(long_buffer);
+    return length;
+}
+
+int graphblas_sparse_linear_algebra_burble_flush(void)
+{
+    burble_flusher current_flusher = atomic_load(&flusher);
+    if (current_flusher != NULL)
+    {
+        current_flusher();
+    }
+    return 0;
+}
@@ -0,0 +1,249 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::{GetMatrixDimensions, Size, SparseMatrix};
+use crate::collections::sparse_vector::SparseVector;
+use crate::context::GetContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{ApplyBinaryOperator, BinaryOperatorApplier};
+use crate::operators::binary_operator::{Assignment, BinaryOperator, Minus, Plus, Times};
+use crate::operators::element_wise_addition::{
+    ApplyElementWiseMatrixAdditionBinaryOperator, ElementWiseMatrixAdditionBinaryOperator,
+};
+use crate::operators::element_wise_union::{
+    ApplyElementWiseMatrixUnionBinaryOperator, ElementWiseMatrixUnionBinaryOperator,
+};
+use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+use crate::operators::multiplication::{
+    MatrixMultiplicationOperator, MatrixVectorMultiplicationOperator, MultiplyMatrices,
+    MultiplyMatrixByVector,
+};
+use crate::operators::options::{
+    OptionsForOperatorWithMatrixArgument, OptionsForOperatorWithMatrixArguments,
+    OptionsForOperatorWithMatrixAsFirstArgument,
+};
+use crate::operators::semiring::{PlusTimes, Semiring};
+use crate::operators::transpose::{MatrixTranspose, TransposeMatrix};
+use crate::value_type::ValueType;
+
+// Each operator allocates its product in the context of the left argument,
+// using the default mask, accumulator and options.
+
+impl<T: ValueType> SparseMatrix<T> {
+    /// Returns the transpose as a new matrix.
+    pub fn t(&self) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let size = self.size()?;
+        let mut transpose = SparseMatrix::<T>::new(
+            self.context(),
+            Size::new(size.column_width(), size.row_height()),
+        )?;
+        TransposeMatrix::<T>::apply(
+            &MatrixTranspose::new(),
+            self,
+            &Assignment::new(),
+            &mut transpose,
+            &SelectEntireMatrix::new(self.context()),
+            &OptionsForOperatorWithMatrixArgument::new_default(),
+        )?;
+        Ok(transpose)
+    }
+}
+
+/// Matrix multiplication with the PlusTimes semiring.
+impl<'a, T> Mul<&'a SparseMatrix<T>> for &'a SparseMatrix<T>
+where
+    T: ValueType,
+    PlusTimes<T>: Semiring<T>,
+{
+    type Output = Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+
+    fn mul(self, multiplicant: &'a SparseMatrix<T>) -> Self::Output {
+        let mut product = SparseMatrix::<T>::new(
+            self.context(),
+            Size::new(self.row_height()?, multiplicant.column_width()?),
+        )?;
+        MultiplyMatrices::<T>::apply(
+            &MatrixMultiplicationOperator::new(),
+            self,
+            &PlusTimes::<T>::new(),
+            multiplicant,
+            &Assignment::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &OptionsForOperatorWithMatrixArguments::new_default(),
+        )?;
+        Ok(product)
+    }
+}
+
+/// Matrix-vector multiplication with the PlusTimes semiring.
+impl<'a, T> Mul<&'a SparseVector<T>> for &'a SparseMatrix<T>
+where
+    T: ValueType,
+    PlusTimes<T>: Semiring<T>,
+{
+    type Output = Result<SparseVector<T>, SparseLinearAlgebraError>;
+
+    fn mul(self, multiplicant: &'a SparseVector<T>) -> Self::Output {
+        let mut product = SparseVector::<T>::new(self.context(), self.row_height()?)?;
+        MultiplyMatrixByVector::<T>::apply(
+            &MatrixVectorMultiplicationOperator::new(),
+            self,
+            &PlusTimes::<T>::new(),
+            multiplicant,
+            &Assignment::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &OptionsForOperatorWithMatrixAsFirstArgument::new_default(),
+        )?;
+        Ok(product)
+    }
+}
+
+/// Scales every stored element.
+impl<T> Mul<T> for &SparseMatrix<T>
+where
+    T: ValueType,
+    Times<T>: BinaryOperator<T>,
+    BinaryOperatorApplier: ApplyBinaryOperator<T>,
+{
+    type Output = Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        let mut product = SparseMatrix::<T>::new(self.context(), self.size()?)?;
+        BinaryOperatorApplier::new().apply_with_matrix_as_left_argument(
+            self,
+            &Times::<T>::new(),
+            scalar,
+            &Assignment::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &OptionsForOperatorWithMatrixAsFirstArgument::new_default(),
+        )?;
+        Ok(product)
+    }
+}
+
+/// Element-wise addition, on the union of the stored elements.
+impl<'a, T> Add<&'a SparseMatrix<T>> for &'a SparseMatrix<T>
+where
+    T: ValueType,
+    Plus<T>: BinaryOperator<T>,
+{
+    type Output = Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+
+    fn add(self, addend: &'a SparseMatrix<T>) -> Self::Output {
+        let mut sum = SparseMatrix::<T>::new(self.context(), self.size()?)?;
+        ApplyElementWiseMatrixAdditionBinaryOperator::<T>::apply(
+            &ElementWiseMatrixAdditionBinaryOperator::new(),
+            self,
+            &Plus::<T>::new(),
+            addend,
+            &Assignment::new(),
+            &mut sum,
+            &SelectEntireMatrix::new(self.context()),
+            &OptionsForOperatorWithMatrixArguments::new_default(),
+        )?;
+        Ok(sum)
+    }
+}
+
+/// Element-wise subtraction, where an element stored in only one argument is subtracted from or by zero.
+impl<'a, T> Sub<&'a SparseMatrix<T>> for &'a SparseMatrix<T>
+where
+    T: ValueType + Default,
+    Minus<T>: BinaryOperator<T>,
+    ElementWiseMatrixUnionBinaryOperator: ApplyElementWiseMatrixUnionBinaryOperator<T>,
+{
+    type Output = Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+
+    fn sub(self, subtrahend: &'a SparseMatrix<T>) -> Self::Output {
+        let mut difference = SparseMatrix::<T>::new(self.context(), self.size()?)?;
+        ElementWiseMatrixUnionBinaryOperator::new().apply(
+            self,
+            T::default(),
+            &Minus::<T>::new(),
+            subtrahend,
+            T::default(),
+            &Assignment::new(),
+            &mut difference,
+            &SelectEntireMatrix::new(self.context()),
+            &OptionsForOperatorWithMatrixArguments::new_default(),
+        )?;
+        Ok(difference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    fn matrix(context: &std::sync::Arc<Context>) -> SparseMatrix<i32> {
+        SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            (2, 3).into(),
+            MatrixElementList::<i32>::from_element_vector(vec![
+                (0, 0, 1).into(),
+                (0, 2, 2).into(),
+                (1, 1, 3).into(),
+            ]),
+            &First::<i32>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn matrix_arithmetic() {
+        let context = Context::init_default().unwrap();
+        let a = matrix(&context);
+
+        let transpose = a.t().unwrap();
+        assert_eq!(transpose.size().unwrap(), Size::new(3, 2));
+        assert_eq!(transpose.element_value_or_default(2, 0).unwrap(), 2);
+
+        let product = (&a * &transpose).unwrap();
+        assert_eq!(product.size().unwrap(), Size::new(2, 2));
+        assert_eq!(product.element_value_or_default(0, 0).unwrap(), 5);
+        assert_eq!(product.element_value_or_default(1, 1).unwrap(), 9);
+
+        let sum = (&a + &a).unwrap();
+        assert_eq!(sum.element_value_or_default(0, 2).unwrap(), 4);
+
+        let scaled = (&a * 3).unwrap();
+        assert_eq!(scaled.element_value_or_default(1, 1).unwrap(), 9);
+
+        let difference = (&a - &scaled).unwrap();
+        assert_eq!(difference.element_value_or_default(0, 0).unwrap(), -2);
+        assert_eq!(difference.element_value(1, 0).unwrap(), None);
+
+        assert!((&a * &a).is_err());
+    }
+
+    #[test]
+    fn matrix_vector_multiplication() {
+        let context = Context::init_default().unwrap();
+        let a = matrix(&context);
+        let vector = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::<i32>::from_element_vector(vec![(0, 1).into(), (2, 1).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        let product = (&a * &vector).unwrap();
+        assert_eq!(product.element_value_or_default(0).unwrap(), 3);
+        assert_eq!(product.element_value(1).unwrap(), None);
+    }
+}
@@ -1,3 +1,4 @@
+mod arithmetic;
 mod coordinate;
 mod element;
 mod handle;
@@ -18,7 +18,7 @@ use crate::graphblas_bindings::{
 
 use crate::collections::sparse_matrix::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_matrix::SparseMatrix;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::index::DiagonalIndex;
 use crate::index::DiagonalIndexConversion;
 use crate::index::IndexConversion;
@@ -128,7 +128,7 @@ macro_rules! sparse_matrix_from_element_vector {
 
                 {
                     let number_of_elements = elements.length().to_graphblas_index()?;
-                    context.call(
+                    context.call_operation(
                         || unsafe {
                             $build_function(
                                 matrix.graphblas_matrix_ptr(),
@@ -139,7 +139,12 @@ macro_rules! sparse_matrix_from_element_vector {
                                 reduction_operator_for_duplicates.graphblas_type(),
                             )
                         },
+                        Operation::new(OperationKind::Build, stringify!($build_function))
+                            .with_binary_operator(
+                                reduction_operator_for_duplicates.graphblas_type(),
+                            ),
                         unsafe { matrix.graphblas_matrix_ptr_ref() },
+                        &[],
                     )?;
                 }
                 Ok(matrix)
@@ -4,9 +4,10 @@ use std::{ffi::c_void, mem::MaybeUninit};
 use suitesparse_graphblas_sys::{GrB_Index, GrB_Matrix, GxB_Matrix_serialize};
 
 use crate::collections::GetGraphblasSerializerDescriptor;
-use crate::context::CallGraphBlasContext;
 use crate::context::GetContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::index::IndexConversion;
+use crate::property::Operand;
 use crate::{error::SparseLinearAlgebraError, index::ElementIndex};
 
 pub trait SerializeSuitesparseGraphblasSparseMatrix {
@@ -23,7 +24,7 @@ pub unsafe fn serialize_suitesparse_grapblas_sparse_matrix(
     let mut size_of_serialized_matrix: MaybeUninit<GrB_Index> = MaybeUninit::uninit();
     let mut serialized_matrix_pointer: MaybeUninit<*mut c_void> = MaybeUninit::uninit();
 
-    serializer.context_ref().call(
+    serializer.context_ref().call_operation(
         || unsafe {
             GxB_Matrix_serialize(
                 serialized_matrix_pointer.as_mut_ptr(),
@@ -32,7 +33,9 @@ pub unsafe fn serialize_suitesparse_grapblas_sparse_matrix(
                 serializer.graphblas_serializer_descriptor(),
             )
         },
+        Operation::new(OperationKind::Serialize, "GxB_Matrix_serialize"),
         &serializer.graphblas_serializer_descriptor(),
+        &[Operand::Matrix(suitesparse_graphblas_sparse_matrix)],
     )?;
 
     let size_of_serialized_matrix =
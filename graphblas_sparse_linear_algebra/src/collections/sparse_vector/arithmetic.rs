@@ -0,0 +1,183 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::SparseMatrix;
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::SparseVector;
+use crate::context::GetContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{ApplyBinaryOperator, BinaryOperatorApplier};
+use crate::operators::binary_operator::{Assignment, BinaryOperator, Minus, Plus, Times};
+use crate::operators::element_wise_addition::{
+    ApplyElementWiseVectorAdditionBinaryOperator, ElementWiseVectorAdditionBinaryOperator,
+};
+use crate::operators::element_wise_union::{
+    ApplyElementWiseVectorUnionBinaryOperator, ElementWiseVectorUnionBinaryOperator,
+};
+use crate::operators::mask::SelectEntireVector;
+use crate::operators::multiplication::{
+    MultiplyVectorByMatrix, VectorMatrixMultiplicationOperator,
+};
+use crate::operators::options::{OperatorOptions, OptionsForOperatorWithMatrixAsSecondArgument};
+use crate::operators::semiring::{PlusTimes, Semiring};
+use crate::value_type::ValueType;
+
+// Each operator allocates its product in the context of the left argument,
+// using the default mask, accumulator and options.
+
+/// Vector-matrix multiplication with the PlusTimes semiring.
+impl<'a, T> Mul<&'a SparseMatrix<T>> for &'a SparseVector<T>
+where
+    T: ValueType,
+    PlusTimes<T>: Semiring<T>,
+{
+    type Output = Result<SparseVector<T>, SparseLinearAlgebraError>;
+
+    fn mul(self, multiplicant: &'a SparseMatrix<T>) -> Self::Output {
+        let mut product = SparseVector::<T>::new(self.context(), multiplicant.column_width()?)?;
+        MultiplyVectorByMatrix::<T>::apply(
+            &VectorMatrixMultiplicationOperator::new(),
+            self,
+            &PlusTimes::<T>::new(),
+            multiplicant,
+            &Assignment::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &OptionsForOperatorWithMatrixAsSecondArgument::new_default(),
+        )?;
+        Ok(product)
+    }
+}
+
+/// Scales every stored element.
+impl<T> Mul<T> for &SparseVector<T>
+where
+    T: ValueType,
+    Times<T>: BinaryOperator<T>,
+    BinaryOperatorApplier: ApplyBinaryOperator<T>,
+{
+    type Output = Result<SparseVector<T>, SparseLinearAlgebraError>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        let mut product = SparseVector::<T>::new(self.context(), self.length()?)?;
+        BinaryOperatorApplier::new().apply_with_vector_as_left_argument(
+            self,
+            &Times::<T>::new(),
+            scalar,
+            &Assignment::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &OperatorOptions::new_default(),
+        )?;
+        Ok(product)
+    }
+}
+
+/// Element-wise addition, on the union of the stored elements.
+impl<'a, T> Add<&'a SparseVector<T>> for &'a SparseVector<T>
+where
+    T: ValueType,
+    Plus<T>: BinaryOperator<T>,
+{
+    type Output = Result<SparseVector<T>, SparseLinearAlgebraError>;
+
+    fn add(self, addend: &'a SparseVector<T>) -> Self::Output {
+        let mut sum = SparseVector::<T>::new(self.context(), self.length()?)?;
+        ApplyElementWiseVectorAdditionBinaryOperator::<T>::apply(
+            &ElementWiseVectorAdditionBinaryOperator::new(),
+            self,
+            &Plus::<T>::new(),
+            addend,
+            &Assignment::new(),
+            &mut sum,
+            &SelectEntireVector::new(self.context()),
+            &OperatorOptions::new_default(),
+        )?;
+        Ok(sum)
+    }
+}
+
+/// Element-wise subtraction, where an element stored in only one argument is subtracted from or by zero.
+impl<'a, T> Sub<&'a SparseVector<T>> for &'a SparseVector<T>
+where
+    T: ValueType + Default,
+    Minus<T>: BinaryOperator<T>,
+    ElementWiseVectorUnionBinaryOperator: ApplyElementWiseVectorUnionBinaryOperator<T>,
+{
+    type Output = Result<SparseVector<T>, SparseLinearAlgebraError>;
+
+    fn sub(self, subtrahend: &'a SparseVector<T>) -> Self::Output {
+        let mut difference = SparseVector::<T>::new(self.context(), self.length()?)?;
+        ElementWiseVectorUnionBinaryOperator::new().apply(
+            self,
+            T::default(),
+            &Minus::<T>::new(),
+            subtrahend,
+            T::default(),
+            &Assignment::new(),
+            &mut difference,
+            &SelectEntireVector::new(self.context()),
+            &OperatorOptions::new_default(),
+        )?;
+        Ok(difference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn vector_arithmetic() {
+        let context = Context::init_default().unwrap();
+        let u = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::<f64>::from_element_vector(vec![(0, 1.0).into(), (2, 2.0).into()]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let v = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::<f64>::from_element_vector(vec![(1, 4.0).into(), (2, 0.5).into()]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+
+        let sum = (&u + &v).unwrap();
+        assert_eq!(sum.element_value_or_default(1).unwrap(), 4.0);
+        assert_eq!(sum.element_value_or_default(2).unwrap(), 2.5);
+
+        let difference = (&u - &v).unwrap();
+        assert_eq!(difference.element_value_or_default(1).unwrap(), -4.0);
+        assert_eq!(difference.element_value_or_default(2).unwrap(), 1.5);
+
+        let scaled = (&u * 2.0).unwrap();
+        assert_eq!(scaled.element_value_or_default(2).unwrap(), 4.0);
+        assert_eq!(scaled.element_value(1).unwrap(), None);
+
+        let matrix = SparseMatrix::<f64>::from_element_list(
+            context.clone(),
+            (3, 2).into(),
+            MatrixElementList::<f64>::from_element_vector(vec![
+                (0, 1, 3.0).into(),
+                (2, 1, 1.0).into(),
+            ]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let product = (&u * &matrix).unwrap();
+        assert_eq!(product.length().unwrap(), 2);
+        assert_eq!(product.element_value_or_default(1).unwrap(), 5.0);
+    }
+}
@@ -1,4 +1,5 @@
 // pub mod data;
+mod arithmetic;
 mod element;
 mod handle;
 mod sparse_vector;
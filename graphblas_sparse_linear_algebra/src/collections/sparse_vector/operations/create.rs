@@ -7,8 +7,8 @@ use crate::graphblas_bindings::{
 };
 
 use crate::collections::sparse_vector::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
 use crate::context::GetContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::index::IndexConversion;
 use crate::value_type::ConvertVector;
 use crate::{
@@ -52,7 +52,7 @@ macro_rules! sparse_matrix_from_element_vector {
                 }
                 let number_of_elements = elements.length().to_graphblas_index()?;
                 let element_values = elements.values_ref().to_owned().to_type()?;
-                vector.context_ref().call(
+                vector.context_ref().call_operation(
                     || unsafe {
                         $build_function(
                             vector.graphblas_vector_ptr(),
@@ -62,7 +62,10 @@ macro_rules! sparse_matrix_from_element_vector {
                             reduction_operator_for_duplicates.graphblas_type(),
                         )
                     },
+                    Operation::new(OperationKind::Build, stringify!($build_function))
+                        .with_binary_operator(reduction_operator_for_duplicates.graphblas_type()),
                     unsafe { &vector.graphblas_vector_ptr() },
+                    &[],
                 )?;
                 Ok(vector)
             }
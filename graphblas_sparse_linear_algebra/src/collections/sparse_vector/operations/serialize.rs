@@ -3,8 +3,9 @@ use std::{ffi::c_void, mem::MaybeUninit};
 
 use suitesparse_graphblas_sys::{GrB_Index, GrB_Vector, GxB_Vector_serialize};
 
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::index::IndexConversion;
+use crate::property::Operand;
 use crate::{
     collections::GetGraphblasSerializerDescriptor, context::GetContext,
     error::SparseLinearAlgebraError, index::ElementIndex,
@@ -24,7 +25,7 @@ pub unsafe fn serialize_suitesparse_grapblas_sparse_vector(
     let mut size_of_serialized_vector: MaybeUninit<GrB_Index> = MaybeUninit::uninit();
     let mut serialized_vector_pointer: MaybeUninit<*mut c_void> = MaybeUninit::uninit();
 
-    serializer.context_ref().call(
+    serializer.context_ref().call_operation(
         || unsafe {
             GxB_Vector_serialize(
                 serialized_vector_pointer.as_mut_ptr(),
@@ -33,7 +34,9 @@ pub unsafe fn serialize_suitesparse_grapblas_sparse_vector(
                 serializer.graphblas_serializer_descriptor(),
             )
         },
+        Operation::new(OperationKind::Serialize, "GxB_Vector_serialize"),
         serializer.graphblas_serializer_descriptor_ref(),
+        &[Operand::Vector(suitesparse_graphblas_sparse_vector)],
     )?;
 
     let size_of_serialized_vector =
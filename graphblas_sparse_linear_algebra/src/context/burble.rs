@@ -0,0 +1,326 @@
+use std::ffi::{c_char, c_int, c_void};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::{
+    GxB_Global_Option_set_FUNCTION, GxB_Option_Field_GxB_FLUSH, GxB_Option_Field_GxB_PRINTF,
+};
+
+use crate::error::SparseLinearAlgebraError;
+use crate::property::GlobalProperty;
+
+use super::Context;
+
+extern "C" {
+    fn graphblas_sparse_linear_algebra_burble_set_handlers(
+        writer: Option<unsafe extern "C" fn(text: *const c_char, length: usize)>,
+        flusher: Option<unsafe extern "C" fn()>,
+    );
+    fn graphblas_sparse_linear_algebra_burble_printf(format: *const c_char, ...) -> c_int;
+    fn graphblas_sparse_linear_algebra_burble_flush() -> c_int;
+}
+
+/// Where GraphBLAS writes its diagnostic output.
+/// The output of GxB_print is routed in the same way.
+#[derive(Clone)]
+pub enum BurbleOutput {
+    /// The C standard output, as GraphBLAS does by default
+    StandardOutput,
+    /// Receives the output line by line, without the line break.
+    /// The callback must not call GraphBLAS.
+    Callback(Arc<dyn Fn(&str) + Send + Sync>),
+    /// Logs the output line by line with the target "graphblas". Requires the `log` feature.
+    #[cfg(feature = "log")]
+    Log(log::Level),
+}
+
+impl std::fmt::Debug for BurbleOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BurbleOutput::StandardOutput => write!(f, "StandardOutput"),
+            BurbleOutput::Callback(_) => write!(f, "Callback"),
+            #[cfg(feature = "log")]
+            BurbleOutput::Log(level) => write!(f, "Log({:?})", level),
+        }
+    }
+}
+
+struct BurbleSink {
+    output: BurbleOutput,
+    incomplete_line: String,
+}
+
+impl BurbleSink {
+    fn write(&mut self, text: &str) -> BurbleLines {
+        self.incomplete_line.push_str(text);
+        let mut lines = Vec::new();
+        while let Some(line_end) = self.incomplete_line.find('\n') {
+            let line: String = self.incomplete_line.drain(..=line_end).collect();
+            lines.push(line.trim_end_matches(['\n', '\r']).to_owned());
+        }
+        self.complete_lines(lines)
+    }
+
+    fn flush(&mut self) -> BurbleLines {
+        let mut lines = Vec::new();
+        if !self.incomplete_line.is_empty() {
+            lines.push(std::mem::take(&mut self.incomplete_line));
+        }
+        self.complete_lines(lines)
+    }
+
+    fn complete_lines(&self, lines: Vec<String>) -> BurbleLines {
+        BurbleLines {
+            output: self.output.clone(),
+            lines,
+        }
+    }
+}
+
+/// Lines taken out of the sink, to emit after releasing its lock.
+/// Otherwise, a panicking callback would poison the lock,
+/// and a callback that sets the burble output would deadlock.
+struct BurbleLines {
+    output: BurbleOutput,
+    lines: Vec<String>,
+}
+
+impl BurbleLines {
+    fn emit(&self) {
+        for line in self.lines.iter() {
+            match &self.output {
+                BurbleOutput::StandardOutput => (),
+                BurbleOutput::Callback(callback) => callback(line),
+                #[cfg(feature = "log")]
+                BurbleOutput::Log(level) => log::log!(target: "graphblas", *level, "{}", line),
+            }
+        }
+    }
+}
+
+static BURBLE_SINK: Lazy<Mutex<BurbleSink>> = Lazy::new(|| {
+    Mutex::new(BurbleSink {
+        output: BurbleOutput::StandardOutput,
+        incomplete_line: String::new(),
+    })
+});
+
+// The sink stays consistent when a thread panics, because callbacks run without the lock.
+fn lock_burble_sink() -> MutexGuard<'static, BurbleSink> {
+    BURBLE_SINK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+unsafe extern "C" fn write_burble(text: *const c_char, length: usize) {
+    let text = unsafe { slice::from_raw_parts(text as *const u8, length) };
+    let text = String::from_utf8_lossy(text);
+    // Panics must not unwind into GraphBLAS
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        let lines = lock_burble_sink().write(&text);
+        lines.emit();
+    }));
+}
+
+unsafe extern "C" fn flush_burble() {
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        let lines = lock_burble_sink().flush();
+        lines.emit();
+    }));
+}
+
+impl Context {
+    /// Burble makes GraphBLAS explain each operation it performs,
+    /// including the chosen kernels, format conversions and JIT activity.
+    /// Burble is process-wide; it explains the operations of all threads.
+    pub fn is_burble_enabled(&self) -> Result<bool, SparseLinearAlgebraError> {
+        Ok(self.get_property(GlobalProperty::Burble)? != 0)
+    }
+
+    pub fn set_burble(&self, enabled: bool) -> Result<(), SparseLinearAlgebraError> {
+        self.set_property(GlobalProperty::Burble, i32::from(enabled))
+    }
+
+    /// Enables burble until the returned guard is dropped, which restores the previous setting.
+    pub fn enable_burble_in_scope(&self) -> Result<BurbleScope<'_>, SparseLinearAlgebraError> {
+        let was_enabled = self.is_burble_enabled()?;
+        self.set_burble(true)?;
+        Ok(BurbleScope {
+            context: self,
+            was_enabled,
+        })
+    }
+
+    /// Routes the output of GraphBLAS to the given destination, for all contexts in the process.
+    pub fn set_burble_output(&self, output: BurbleOutput) -> Result<(), SparseLinearAlgebraError> {
+        let is_standard_output = matches!(output, BurbleOutput::StandardOutput);
+        let lines = {
+            let mut sink = lock_burble_sink();
+            let lines = sink.flush();
+            sink.output = output;
+            lines
+        };
+        lines.emit();
+
+        let (printf, flush): (*mut c_void, *mut c_void) = if is_standard_output {
+            (ptr::null_mut(), ptr::null_mut())
+        } else {
+            unsafe {
+                graphblas_sparse_linear_algebra_burble_set_handlers(
+                    Some(write_burble),
+                    Some(flush_burble),
+                )
+            };
+            (
+                graphblas_sparse_linear_algebra_burble_printf as *mut c_void,
+                graphblas_sparse_linear_algebra_burble_flush as *mut c_void,
+            )
+        };
+
+        self.call_without_detailed_error_information(|| unsafe {
+            GxB_Global_Option_set_FUNCTION(GxB_Option_Field_GxB_PRINTF as i32, printf)
+        })?;
+        self.call_without_detailed_error_information(|| unsafe {
+            GxB_Global_Option_set_FUNCTION(GxB_Option_Field_GxB_FLUSH as i32, flush)
+        })?;
+        Ok(())
+    }
+}
+
+/// Restores the previous burble setting when dropped.
+#[derive(Debug)]
+pub struct BurbleScope<'a> {
+    context: &'a Context,
+    was_enabled: bool,
+}
+
+impl Drop for BurbleScope<'_> {
+    fn drop(&mut self) {
+        let _ = self.context.set_burble(self.was_enabled);
+        let lines = lock_burble_sink().flush();
+        lines.emit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::context::lock_global_configuration;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::multiplication::{MatrixMultiplicationOperator, MultiplyMatrices};
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+    use crate::operators::semiring::PlusTimes;
+
+    #[test]
+    fn capture_burble_output() {
+        let _global_configuration = lock_global_configuration();
+        let context = Context::init_default().unwrap();
+
+        let matrix_size: Size = (3, 3).into();
+        let matrix = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            matrix_size,
+            MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 0, 1.0).into(),
+                (1, 1, 2.0).into(),
+            ]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<f32>::new(context.clone(), matrix_size).unwrap();
+
+        let lines = Arc::new(Mutex::new(Vec::<String>::new()));
+        let captured_lines = lines.clone();
+        context
+            .set_burble_output(BurbleOutput::Callback(Arc::new(move |line: &str| {
+                captured_lines.lock().unwrap().push(line.to_owned())
+            })))
+            .unwrap();
+
+        {
+            let _burble = context.enable_burble_in_scope().unwrap();
+            assert!(context.is_burble_enabled().unwrap());
+
+            MatrixMultiplicationOperator::new()
+                .apply(
+                    &matrix,
+                    &PlusTimes::<f32>::new(),
+                    &matrix,
+                    &Assignment::new(),
+                    &mut product,
+                    &SelectEntireMatrix::new(context.clone()),
+                    &OptionsForOperatorWithMatrixArguments::new_default(),
+                )
+                .unwrap();
+        }
+        context
+            .set_burble_output(BurbleOutput::StandardOutput)
+            .unwrap();
+
+        assert!(lines
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|line| line.contains("GrB_mxm")));
+        assert!(lines
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|line| !line.contains('\n')));
+    }
+
+    #[test]
+    fn split_burble_into_lines() {
+        let lines = Arc::new(Mutex::new(Vec::<String>::new()));
+        let captured_lines = lines.clone();
+        let mut sink = BurbleSink {
+            output: BurbleOutput::Callback(Arc::new(move |line: &str| {
+                captured_lines.lock().unwrap().push(line.to_owned())
+            })),
+            incomplete_line: String::new(),
+        };
+
+        sink.write(" [ GrB_mxm ").emit();
+        sink.write("C=A*B ]\n [ GrB_reduce").emit();
+        sink.flush().emit();
+
+        assert_eq!(
+            *lines.lock().unwrap(),
+            vec![
+                String::from(" [ GrB_mxm C=A*B ]"),
+                String::from(" [ GrB_reduce")
+            ]
+        );
+    }
+
+    #[test]
+    fn keep_capturing_burble_output_after_callback_panics() {
+        let _global_configuration = lock_global_configuration();
+        let context = Context::init_default().unwrap();
+
+        let lines = Arc::new(Mutex::new(Vec::<String>::new()));
+        let captured_lines = lines.clone();
+        context
+            .set_burble_output(BurbleOutput::Callback(Arc::new(move |line: &str| {
+                if line == "panic" {
+                    panic!("burble callback panicked");
+                }
+                captured_lines.lock().unwrap().push(line.to_owned())
+            })))
+            .unwrap();
+
+        for text in ["panic\n", "after panic\n"] {
+            unsafe { write_burble(text.as_ptr() as *const c_char, text.len()) };
+        }
+        context
+            .set_burble_output(BurbleOutput::StandardOutput)
+            .unwrap();
+
+        assert_eq!(*lines.lock().unwrap(), vec![String::from("after panic")]);
+    }
+}
@@ -1,9 +1,12 @@
 use suitesparse_graphblas_sys::{
-    GxB_Format_Value, GxB_Format_Value_GxB_BY_COL, GxB_Format_Value_GxB_BY_ROW,
-    GxB_Global_Option_set, GxB_Option_Field_GxB_FORMAT,
+    GrB_GLOBAL, GrB_Global_set_INT32, GxB_CHUNK, GxB_Format_Value, GxB_Format_Value_GxB_BY_COL,
+    GxB_Format_Value_GxB_BY_ROW, GxB_Global_Option_get_FP64, GxB_Global_Option_get_INT32,
+    GxB_Global_Option_set_FP64, GxB_Global_Option_set_FP64_ARRAY, GxB_Global_Option_set_INT32,
+    GxB_NBITMAP_SWITCH, GxB_NTHREADS, GxB_Option_Field_GxB_BITMAP_SWITCH,
+    GxB_Option_Field_GxB_FORMAT, GxB_Option_Field_GxB_HYPER_SWITCH,
 };
 
-use crate::error::SparseLinearAlgebraError;
+use crate::error::{LogicError, SparseLinearAlgebraError};
 
 use super::Context;
 
@@ -35,15 +38,253 @@ impl SetMatrixFormat for Context {
         format: MatrixStorageFormat,
     ) -> Result<(), SparseLinearAlgebraError> {
         self.call_without_detailed_error_information(|| unsafe {
-            // TODO: use GrB_set() once it becomes available
-            GxB_Global_Option_set(
-                GxB_Option_Field_GxB_FORMAT as i32,
+            GrB_Global_set_INT32(
+                GrB_GLOBAL,
                 <MatrixStorageFormat as Into<GxB_Format_Value>>::into(format),
+                GxB_Option_Field_GxB_FORMAT as i32,
+            )
+        })?;
+        Ok(())
+    }
+}
+
+/// One bitmap switch per range of matrix dimensions, from small to large.
+/// A matrix is converted to bitmap when its density exceeds the switch for its dimensions.
+pub type BitmapSwitch = [f64; GxB_NBITMAP_SWITCH as usize];
+
+/// Process-wide tuning of GraphBLAS.
+/// Settings that are None keep their current value when the configuration is applied.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RuntimeConfiguration {
+    number_of_threads: Option<usize>,
+    chunk: Option<f64>,
+    hyper_switch: Option<f64>,
+    bitmap_switch: Option<BitmapSwitch>,
+}
+
+impl RuntimeConfiguration {
+    pub fn new(
+        number_of_threads: Option<usize>,
+        chunk: Option<f64>,
+        hyper_switch: Option<f64>,
+        bitmap_switch: Option<BitmapSwitch>,
+    ) -> Self {
+        Self {
+            number_of_threads,
+            chunk,
+            hyper_switch,
+            bitmap_switch,
+        }
+    }
+
+    /// Keeps all current settings
+    pub fn new_default() -> Self {
+        Self::default()
+    }
+
+    pub fn number_of_threads(&self) -> Option<usize> {
+        self.number_of_threads
+    }
+
+    pub fn chunk(&self) -> Option<f64> {
+        self.chunk
+    }
+
+    pub fn hyper_switch(&self) -> Option<f64> {
+        self.hyper_switch
+    }
+
+    pub fn bitmap_switch(&self) -> Option<BitmapSwitch> {
+        self.bitmap_switch
+    }
+}
+
+impl Context {
+    pub fn set_runtime_configuration(
+        &self,
+        configuration: &RuntimeConfiguration,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if let Some(number_of_threads) = configuration.number_of_threads {
+            self.set_number_of_threads(number_of_threads)?;
+        }
+        if let Some(chunk) = configuration.chunk {
+            self.set_chunk(chunk)?;
+        }
+        if let Some(hyper_switch) = configuration.hyper_switch {
+            self.set_hyper_switch(hyper_switch)?;
+        }
+        if let Some(bitmap_switch) = configuration.bitmap_switch {
+            self.set_bitmap_switch(bitmap_switch)?;
+        }
+        Ok(())
+    }
+
+    /// The current value of every setting
+    pub fn runtime_configuration(&self) -> Result<RuntimeConfiguration, SparseLinearAlgebraError> {
+        Ok(RuntimeConfiguration::new(
+            Some(self.number_of_threads()?),
+            Some(self.chunk()?),
+            Some(self.hyper_switch()?),
+            Some(self.bitmap_switch()?),
+        ))
+    }
+
+    /// The maximum number of threads used by GraphBLAS operations.
+    pub fn number_of_threads(&self) -> Result<usize, SparseLinearAlgebraError> {
+        let mut number_of_threads: i32 = 0;
+        self.call_without_detailed_error_information(|| unsafe {
+            GxB_Global_Option_get_INT32(GxB_NTHREADS as i32, &mut number_of_threads)
+        })?;
+        match number_of_threads.try_into() {
+            Ok(number_of_threads) => Ok(number_of_threads),
+            Err(error) => Err(LogicError::from(error).into()),
+        }
+    }
+
+    /// Zero lets GraphBLAS choose the number of threads.
+    pub fn set_number_of_threads(
+        &self,
+        number_of_threads: usize,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let number_of_threads: i32 = match number_of_threads.try_into() {
+            Ok(number_of_threads) => number_of_threads,
+            Err(error) => return Err(LogicError::from(error).into()),
+        };
+        self.call_without_detailed_error_information(|| unsafe {
+            GxB_Global_Option_set_INT32(GxB_NTHREADS as i32, number_of_threads)
+        })?;
+        Ok(())
+    }
+
+    /// The amount of work per thread below which GraphBLAS uses fewer threads.
+    pub fn chunk(&self) -> Result<f64, SparseLinearAlgebraError> {
+        self.get_global_fp64_option(GxB_CHUNK as i32)
+    }
+
+    /// Zero or less restores the default chunk size.
+    pub fn set_chunk(&self, chunk: f64) -> Result<(), SparseLinearAlgebraError> {
+        self.set_global_fp64_option(GxB_CHUNK as i32, chunk)
+    }
+
+    /// The default hyper switch of new matrices.
+    /// A matrix becomes hypersparse when the fraction of non-empty vectors is below the switch.
+    pub fn hyper_switch(&self) -> Result<f64, SparseLinearAlgebraError> {
+        self.get_global_fp64_option(GxB_Option_Field_GxB_HYPER_SWITCH as i32)
+    }
+
+    pub fn set_hyper_switch(&self, hyper_switch: f64) -> Result<(), SparseLinearAlgebraError> {
+        self.set_global_fp64_option(GxB_Option_Field_GxB_HYPER_SWITCH as i32, hyper_switch)
+    }
+
+    /// The default bitmap switch of new matrices.
+    pub fn bitmap_switch(&self) -> Result<BitmapSwitch, SparseLinearAlgebraError> {
+        let mut bitmap_switch: BitmapSwitch = [0.0; GxB_NBITMAP_SWITCH as usize];
+        // For the bitmap switch, GraphBLAS writes GxB_NBITMAP_SWITCH values.
+        self.call_without_detailed_error_information(|| unsafe {
+            GxB_Global_Option_get_FP64(
+                GxB_Option_Field_GxB_BITMAP_SWITCH as i32,
+                bitmap_switch.as_mut_ptr(),
+            )
+        })?;
+        Ok(bitmap_switch)
+    }
+
+    pub fn set_bitmap_switch(
+        &self,
+        mut bitmap_switch: BitmapSwitch,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        self.call_without_detailed_error_information(|| unsafe {
+            GxB_Global_Option_set_FP64_ARRAY(
+                GxB_Option_Field_GxB_BITMAP_SWITCH as i32,
+                bitmap_switch.as_mut_ptr(),
             )
         })?;
         Ok(())
     }
+
+    fn get_global_fp64_option(&self, field: i32) -> Result<f64, SparseLinearAlgebraError> {
+        let mut value: f64 = 0.0;
+        self.call_without_detailed_error_information(|| unsafe {
+            GxB_Global_Option_get_FP64(field, &mut value)
+        })?;
+        Ok(value)
+    }
+
+    fn set_global_fp64_option(
+        &self,
+        field: i32,
+        value: f64,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        self.call_without_detailed_error_information(|| unsafe {
+            GxB_Global_Option_set_FP64(field, value)
+        })?;
+        Ok(())
+    }
+}
+
+// Tests that change process-wide settings hold this lock, so that they do not observe each other.
+#[cfg(test)]
+pub(crate) fn lock_global_configuration() -> std::sync::MutexGuard<'static, ()> {
+    static GLOBAL_CONFIGURATION: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    GLOBAL_CONFIGURATION
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use crate::context::Mode;
+
+    // Restores the runtime configuration when dropped, also when an assertion fails.
+    struct RestoreRuntimeConfiguration {
+        context: Arc<Context>,
+        configuration: RuntimeConfiguration,
+    }
+
+    impl RestoreRuntimeConfiguration {
+        fn new(context: Arc<Context>) -> Self {
+            let configuration = context.runtime_configuration().unwrap();
+            Self {
+                context,
+                configuration,
+            }
+        }
+    }
+
+    impl Drop for RestoreRuntimeConfiguration {
+        fn drop(&mut self) {
+            let _ = self.context.set_runtime_configuration(&self.configuration);
+        }
+    }
+
+    #[test]
+    fn apply_and_read_runtime_configuration() {
+        let _global_configuration = lock_global_configuration();
+        let _restore_runtime_configuration =
+            RestoreRuntimeConfiguration::new(Context::init_default().unwrap());
+
+        let bitmap_switch: BitmapSwitch = [0.05, 0.05, 0.05, 0.05, 0.1, 0.1, 0.1, 0.1];
+        let context = Context::init_with_runtime_configuration(
+            Mode::NonBlocking,
+            MatrixStorageFormat::ByRow,
+            &RuntimeConfiguration::new(Some(3), Some(32768.0), Some(0.0625), Some(bitmap_switch)),
+        )
+        .unwrap();
+
+        let configuration = context.runtime_configuration().unwrap();
+        assert_eq!(configuration.number_of_threads(), Some(3));
+        assert_eq!(configuration.chunk(), Some(32768.0));
+        assert_eq!(configuration.hyper_switch(), Some(0.0625));
+        assert_eq!(configuration.bitmap_switch(), Some(bitmap_switch));
+
+        context
+            .set_runtime_configuration(&RuntimeConfiguration::new(Some(2), None, None, None))
+            .unwrap();
+        assert_eq!(context.number_of_threads().unwrap(), 2);
+        assert_eq!(context.chunk().unwrap(), 32768.0);
+    }
+}
@@ -4,8 +4,8 @@
 
 use core::mem::MaybeUninit;
 use std::ffi::{c_char, CStr};
-use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
 
 use once_cell::sync::Lazy;
 use suitesparse_graphblas_sys::{
@@ -13,10 +13,10 @@ use suitesparse_graphblas_sys::{
     GrB_IndexUnaryOp_error, GrB_Info_GxB_JIT_ERROR, GrB_Info_GxB_OUTPUT_IS_READONLY, GrB_Matrix,
     GrB_Matrix_error, GrB_Monoid, GrB_Monoid_error, GrB_Scalar, GrB_Scalar_error, GrB_Semiring,
     GrB_Semiring_error, GrB_Type, GrB_Type_error, GrB_UnaryOp, GrB_UnaryOp_error, GrB_Vector,
-    GrB_Vector_error, GrB_finalize, GxB_init,
+    GrB_Vector_error, GrB_finalize, GxB_Context, GxB_Context_error, GxB_init,
 };
 
-use crate::context::{MemoryAllocator, MemoryAllocatorFuctionPointers};
+use crate::context::{MemoryAllocator, MemoryAllocatorFuctionPointers, MemoryAllocatorType};
 use crate::graphblas_bindings::{
     GrB_Info,
     GrB_Info_GrB_DIMENSION_MISMATCH,
@@ -44,20 +44,27 @@ use crate::graphblas_bindings::{
 };
 
 use crate::error::SparseLinearAlgebraError;
-use crate::error::{GraphblasError, GraphblasErrorType};
+use crate::error::{FailedOperation, GraphblasError, GraphblasErrorSource, GraphblasErrorType};
 use crate::error::{SystemError, SystemErrorType};
+use crate::property::Operand;
 
-use super::{MatrixStorageFormat, SetMatrixFormat};
+use super::operation_statistics::OperationStatisticsCollector;
+#[cfg(feature = "tracing")]
+use super::operation_tracing::{
+    operation_span, record_operation_outcome, trace_unsuccessful_status,
+};
+use super::Operation;
+
+use super::{MatrixStorageFormat, RuntimeConfiguration, SetMatrixFormat};
 
 /*
-TO REVIEW: The GraphBLAS context can only be initialized once per process (i.e. not per thread)
+GraphBLAS can only be initialized once per process (i.e. not per thread).
 Also, after calling GrB_finalize(), the process must be restarted before GrB_init() can be called again.
-These limtations are not compatible with the context concept implemented in this module.
 
-This module assumes that multiple contexts can be started, closed, and re-started per thread.
-It is expected that a future-version of GraphBLAS will support this concept.
-If this expectation is false, or given the current GraphBLAS C-specifcation version 2.0,
-the context concet implemented in this module is not useful.
+Therefore, all contexts share one process-wide runtime.
+The first context to start initializes the runtime with its mode and memory allocator.
+Later contexts must ask for the same mode and memory allocator.
+The runtime is only finalized by an explicit call to Context::shutdown(), when no context exists anymore.
 */
 
 // lazy_static! {
@@ -71,8 +78,19 @@ the context concet implemented in this module is not useful.
 // static NUMBER_OF_READY_CONTEXTS: Arc<Mutex<AtomicIsize>> = Arc::new(Mutex::new(AtomicIsize::new(0)));
 // static IS_GRAPHBLAS_BUSY: Arc<Mutex<AtomicBool>> = Arc::new(Mutex::new(AtomicBool::new(false)));
 
-static NUMBER_OF_READY_CONTEXTS: Lazy<Mutex<AtomicIsize>> =
-    Lazy::new(|| Mutex::new(AtomicIsize::new(0)));
+#[derive(Debug)]
+enum RuntimeState {
+    Uninitialized,
+    Running {
+        mode: Mode,
+        memory_allocator_type: MemoryAllocatorType,
+        number_of_contexts: usize,
+    },
+    ShutDown,
+}
+
+static RUNTIME_STATE: Lazy<Mutex<RuntimeState>> =
+    Lazy::new(|| Mutex::new(RuntimeState::Uninitialized));
 static IS_GRAPHBLAS_BUSY: Lazy<Mutex<AtomicBool>> =
     Lazy::new(|| Mutex::new(AtomicBool::new(false)));
 
@@ -85,6 +103,16 @@ pub trait GetContext {
     fn context_ref(&self) -> &Arc<Context>;
 }
 
+impl<C: GetContext + ?Sized> GetContext for &C {
+    fn context(&self) -> Arc<Context> {
+        (**self).context()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        (**self).context_ref()
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Mode {
     Blocking,
@@ -119,10 +147,20 @@ impl Into<i32> for Mode {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub struct Context {
     pub(crate) mode: Mode,
-    pub(crate) memory_allocator_function_pointers: MemoryAllocatorFuctionPointers
+    pub(crate) memory_allocator_type: MemoryAllocatorType,
+    pub(crate) memory_allocator_function_pointers: MemoryAllocatorFuctionPointers,
+    pub(crate) operation_statistics: OperationStatisticsCollector,
+}
+
+// Contexts with different operation statistics are configured the same.
+// Function pointers are not compared, because their addresses are not guaranteed to be unique.
+impl PartialEq for Context {
+    fn eq(&self, other: &Self) -> bool {
+        self.mode == other.mode && self.memory_allocator_type == other.memory_allocator_type
+    }
 }
 
 impl Context {
@@ -142,7 +180,21 @@ impl Context {
         Ok(Arc::new(context))
     }
 
+    /// Applies the runtime configuration after initialising GraphBLAS.
+    /// The runtime configuration is process-wide, and can be changed later.
+    pub fn init_with_runtime_configuration(
+        mode: Mode,
+        matrix_storage_format: MatrixStorageFormat,
+        runtime_configuration: &RuntimeConfiguration,
+    ) -> Result<Arc<Self>, SparseLinearAlgebraError> {
+        let mut context = Context::start(mode, MemoryAllocator::SystemDefault)?;
+        context.set_matrix_format(matrix_storage_format)?;
+        context.set_runtime_configuration(runtime_configuration)?;
+        Ok(Arc::new(context))
+    }
+
     /// Initialise GraphBLAS with a custom memory allocator.
+    /// Fails if GraphBLAS is already running with another mode or memory allocator.
     ///
     /// # Panics / UB
     /// The allocator passed here **must** be the same allocator that is set
@@ -161,23 +213,94 @@ impl Context {
         mode: Mode,
         allocator: MemoryAllocator,
     ) -> Result<Self, SparseLinearAlgebraError> {
-        let number_of_ready_contexts = NUMBER_OF_READY_CONTEXTS.lock().unwrap();
-        // println!("number_of_ready_contexts before starting: {:?}",number_of_ready_contexts.load(Ordering::SeqCst));
+        let mut runtime_state = RUNTIME_STATE.lock().map_err(SystemError::from)?;
 
+        let memory_allocator_type = allocator.memory_allocator_type();
         let memory_allocator_function_pointers = allocator.memory_allocator_function_pointers();
 
-        if number_of_ready_contexts.load(Ordering::SeqCst) == 0 {
-            let _status = initialize(mode, allocator, number_of_ready_contexts)?;
-        } else {
-            number_of_ready_contexts.fetch_add(1, Ordering::SeqCst);
+        match &mut *runtime_state {
+            RuntimeState::Uninitialized => {
+                initialize(mode, &memory_allocator_function_pointers)?;
+                *runtime_state = RuntimeState::Running {
+                    mode,
+                    memory_allocator_type,
+                    number_of_contexts: 1,
+                };
+            }
+            RuntimeState::Running {
+                mode: running_mode,
+                memory_allocator_type: running_memory_allocator_type,
+                number_of_contexts,
+            } => {
+                if *running_mode != mode || *running_memory_allocator_type != memory_allocator_type
+                {
+                    return Err(SystemError::new(
+                        SystemErrorType::ContextAlreadyInitialized,
+                        format!(
+                            "GraphBLAS is already running with mode {:?} and memory allocator {:?}, which differs from the requested mode {:?} and memory allocator {:?}",
+                            running_mode, running_memory_allocator_type, mode, memory_allocator_type
+                        ),
+                        None,
+                    )
+                    .into());
+                }
+                *number_of_contexts += 1;
+            }
+            RuntimeState::ShutDown => {
+                return Err(SystemError::new(
+                    SystemErrorType::UninitialisedContext,
+                    String::from(
+                        "GraphBLAS was shut down, and cannot be restarted in the same process",
+                    ),
+                    None,
+                )
+                .into());
+            }
         }
 
         Ok(Self {
             mode,
-            memory_allocator_function_pointers
+            memory_allocator_type,
+            memory_allocator_function_pointers,
+            operation_statistics: OperationStatisticsCollector::default(),
         })
     }
 
+    /// Finalizes GraphBLAS, which frees all memory that GraphBLAS still holds.
+    ///
+    /// GraphBLAS cannot be restarted in the same process; afterwards, every init returns an error.
+    /// Collections, operators and execution contexts keep their Context alive.
+    /// Therefore, shutdown fails while any Context exists,
+    /// instead of invalidating objects that are still in use.
+    /// Shutting down is optional; the operating system reclaims all memory when the process exits.
+    pub fn shutdown() -> Result<(), SparseLinearAlgebraError> {
+        let mut runtime_state = RUNTIME_STATE.lock().map_err(SystemError::from)?;
+        match &*runtime_state {
+            RuntimeState::Running {
+                number_of_contexts: 0,
+                ..
+            } => {
+                call_graphblas_implementation_without_detailed_error_information(|| unsafe {
+                    GrB_finalize()
+                })?;
+                *runtime_state = RuntimeState::ShutDown;
+                Ok(())
+            }
+            RuntimeState::Running {
+                number_of_contexts, ..
+            } => Err(SystemError::new(
+                SystemErrorType::Other,
+                format!(
+                    "Unable to shut down GraphBLAS while {} contexts exist",
+                    number_of_contexts
+                ),
+                None,
+            )
+            .into()),
+            RuntimeState::Uninitialized | RuntimeState::ShutDown => Ok(()),
+        }
+    }
+
     pub fn call_without_detailed_error_information<F>(
         &self,
         function_to_call: F,
@@ -191,25 +314,20 @@ impl Context {
 
 fn initialize(
     mode: Mode,
-    allocator: MemoryAllocator,
-    number_of_ready_contexts: MutexGuard<AtomicIsize>,
+    memory_allocator_function_pointers: &MemoryAllocatorFuctionPointers,
 ) -> Result<Status, SparseLinearAlgebraError> {
-    let memory_allocator_function_pointers = allocator.memory_allocator_function_pointers();
-
-    let status = unsafe {
-            graphblas_result(
-                GxB_init(
-                    mode.into(),
-                    Some(memory_allocator_function_pointers.malloc),
-                    Some(memory_allocator_function_pointers.calloc),
-                    Some(memory_allocator_function_pointers.realloc),
-                    Some(memory_allocator_function_pointers.free),
-                ),
-                || String::from("Failed to initialise GraphBLAS context with custom allocator"),
-            )?
-        };
-    number_of_ready_contexts.fetch_add(1, Ordering::SeqCst);
-    Ok(status)
+    unsafe {
+        graphblas_result(
+            GxB_init(
+                mode.into(),
+                Some(memory_allocator_function_pointers.malloc),
+                Some(memory_allocator_function_pointers.calloc),
+                Some(memory_allocator_function_pointers.realloc),
+                Some(memory_allocator_function_pointers.free),
+            ),
+            || String::from("Failed to initialise GraphBLAS context with custom allocator"),
+        )
+    }
 }
 
 pub trait CallGraphBlasContext<T> {
@@ -235,17 +353,15 @@ where
     })
 }
 
-impl Context {
-    fn finalize_context(&self) -> Result<Status, SparseLinearAlgebraError> {
-        Ok(self.call_without_detailed_error_information(|| unsafe { GrB_finalize() })?)
-    }
-}
-
 impl Drop for Context {
-    fn drop(&mut self) -> () {
-        let number_of_ready_contexts = NUMBER_OF_READY_CONTEXTS.lock().unwrap();
-        if number_of_ready_contexts.load(Ordering::SeqCst) == 0 {
-            self.finalize_context().unwrap();
+    fn drop(&mut self) {
+        if let Ok(mut runtime_state) = RUNTIME_STATE.lock() {
+            if let RuntimeState::Running {
+                number_of_contexts, ..
+            } = &mut *runtime_state
+            {
+                *number_of_contexts -= 1;
+            }
         }
     }
 }
@@ -273,8 +389,8 @@ macro_rules! implement_CallGraphBlasContext {
 
             fn [<generate_closure_to_retrieve_detailed_error_message_ $graphblas_type>]<'a>(
                 struct_with_debugging_info: &'a $graphblas_type,
-            ) -> impl Fn() -> String + 'a {
-                return || -> String {
+            ) -> impl Fn() -> ErrorDiagnostics + 'a {
+                return || -> ErrorDiagnostics {
                     let mut graphblas_error_message: MaybeUninit<*const c_char> = MaybeUninit::uninit();
                     let graphblas_call_status;
                     unsafe {
@@ -292,11 +408,15 @@ macro_rules! implement_CallGraphBlasContext {
                                 message = CStr::from_ptr(graphblas_error_message).to_str();
                             }
                             match message {
-                                Ok(message) => message.to_owned(),
-                                Err(error) => format!("Failed to execute GraphBLAS function. Unable to parse detailed error message due to: {}", error)
+                                Ok(message) => message.to_owned().into(),
+                                Err(error) => ErrorDiagnostics {
+                                    explanation: String::from("Failed to execute GraphBLAS function. Unable to parse detailed error message."),
+                                    failed_operation: None,
+                                    source: Some(GraphblasErrorSource::ErrorMessageEncoding(error)),
+                                }
                             }
                         }
-                        _ => return String::from("Failed to execute GraphBLAS function. Unable to retrieve more detailed error information.")
+                        _ => return String::from("Failed to execute GraphBLAS function. Unable to retrieve more detailed error information.").into()
                     }
                 };
             }
@@ -314,19 +434,157 @@ implement_CallGraphBlasContext!(GrB_BinaryOp, GrB_BinaryOp_error);
 implement_CallGraphBlasContext!(GrB_Semiring, GrB_Semiring_error);
 implement_CallGraphBlasContext!(GrB_Monoid, GrB_Monoid_error);
 implement_CallGraphBlasContext!(GrB_IndexUnaryOp, GrB_IndexUnaryOp_error);
+implement_CallGraphBlasContext!(GxB_Context, GxB_Context_error);
 // implement_CallGraphBlasContext!(GxB_Iterator, GxB_Iterator_error);
 
-fn graphblas_result<F>(
+/// Like CallGraphBlasContext, but names the output and input collections of an operation in error messages.
+/// Every wrapped GraphBLAS operation is called through this trait, which makes it the place to instrument operations.
+pub(crate) trait CallGraphBlasOperation<T> {
+    fn call_operation<F>(
+        &self,
+        function_to_call: F,
+        operation: Operation,
+        output: &T,
+        inputs: &[Operand],
+    ) -> Result<Status, SparseLinearAlgebraError>
+    where
+        F: FnMut() -> GrB_Info;
+}
+
+macro_rules! implement_CallGraphBlasOperation {
+    ($graphblas_type: ty, |$output: ident| $output_operand: expr) => {
+        paste::paste! {
+            impl CallGraphBlasOperation<$graphblas_type> for Context {
+                fn call_operation<F>(
+                    &self,
+                    function_to_call: F,
+                    operation: Operation,
+                    output: &$graphblas_type,
+                    inputs: &[Operand],
+                ) -> Result<Status, SparseLinearAlgebraError>
+                where
+                    F: FnMut() -> GrB_Info,
+                {
+                    let get_detailed_error_information =
+                        [<generate_closure_to_retrieve_detailed_error_message_ $graphblas_type>](output);
+                    let output_operand: Option<Operand> = {
+                        let $output = output;
+                        $output_operand
+                    };
+                    self.call_instrumented_operation(
+                        function_to_call,
+                        operation,
+                        output_operand,
+                        inputs,
+                        get_detailed_error_information,
+                    )
+                }
+            }
+        }
+    };
+}
+
+implement_CallGraphBlasOperation!(GrB_Matrix, |output| Some(Operand::Matrix(*output)));
+implement_CallGraphBlasOperation!(GrB_Vector, |output| Some(Operand::Vector(*output)));
+implement_CallGraphBlasOperation!(GrB_Scalar, |output| Some(Operand::Scalar(*output)));
+// For operations without a GraphBLAS output object, like serialization, that report errors through their descriptor.
+implement_CallGraphBlasOperation!(GrB_Descriptor, |_output| None);
+
+impl Context {
+    /// For operations that write into a Rust value, like reducing to a scalar.
+    pub(crate) fn call_operation_without_output<F>(
+        &self,
+        function_to_call: F,
+        operation: Operation,
+        inputs: &[Operand],
+    ) -> Result<Status, SparseLinearAlgebraError>
+    where
+        F: FnMut() -> GrB_Info,
+    {
+        self.call_instrumented_operation(function_to_call, operation, None, inputs, || {
+            ErrorDiagnostics::from(format!(
+                "Failed to execute {}",
+                operation.graphblas_function()
+            ))
+        })
+    }
+
+    fn call_instrumented_operation<F, D>(
+        &self,
+        mut function_to_call: F,
+        operation: Operation,
+        output: Option<Operand>,
+        inputs: &[Operand],
+        get_detailed_error_information: D,
+    ) -> Result<Status, SparseLinearAlgebraError>
+    where
+        F: FnMut() -> GrB_Info,
+        D: Fn() -> ErrorDiagnostics,
+    {
+        #[cfg(feature = "tracing")]
+        let span = operation_span(self, &operation, inputs);
+        #[cfg(feature = "tracing")]
+        let _entered_span = span.enter();
+        let measurement = self
+            .operation_statistics
+            .start_measurement(&operation, inputs);
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        let graphblas_info = function_to_call();
+
+        if let Some(measurement) = measurement {
+            self.operation_statistics.finish_measurement(
+                measurement,
+                output,
+                graphblas_info == GrB_Info_GrB_SUCCESS,
+            );
+        }
+        #[cfg(feature = "tracing")]
+        record_operation_outcome(&span, output, start.elapsed());
+
+        // The arguments are described after the call, because the description is only needed for errors.
+        graphblas_result(graphblas_info, || ErrorDiagnostics {
+            failed_operation: Some(operation.failed_operation(self, output, inputs)),
+            ..get_detailed_error_information()
+        })
+    }
+}
+
+/// What is known about a failed call into GraphBLAS.
+pub(crate) struct ErrorDiagnostics {
+    explanation: String,
+    failed_operation: Option<FailedOperation>,
+    source: Option<GraphblasErrorSource>,
+}
+
+impl From<String> for ErrorDiagnostics {
+    fn from(explanation: String) -> Self {
+        Self {
+            explanation,
+            failed_operation: None,
+            source: None,
+        }
+    }
+}
+
+fn graphblas_result<F, D>(
     grb_info: GrB_Info,
     get_detailed_error_information: F,
 ) -> Result<Status, SparseLinearAlgebraError>
 where
-    F: Fn() -> String,
+    F: Fn() -> D,
+    D: Into<ErrorDiagnostics>,
 {
     let status = Status::from(grb_info);
     match status {
         Status::Success => Ok(Status::Success),
-        _ => Err(status.into_sparse_linear_algebra_error(get_detailed_error_information())),
+        _ => {
+            let error_diagnostics: ErrorDiagnostics = get_detailed_error_information().into();
+            #[cfg(feature = "tracing")]
+            trace_unsuccessful_status(&status, &error_diagnostics.explanation);
+            Err(status.into_sparse_linear_algebra_error(grb_info, error_diagnostics))
+        }
     }
 }
 
@@ -386,101 +644,54 @@ impl From<GrB_Info> for Status {
 impl Status {
     fn into_sparse_linear_algebra_error(
         self,
-        detailed_error_information: String,
+        graphblas_info: GrB_Info,
+        error_diagnostics: ErrorDiagnostics,
     ) -> SparseLinearAlgebraError {
-        match self {
-            Status::Success => SystemError::new(
-                SystemErrorType::CreateGraphBlasErrorOnSuccessValue,
-                format!("Logic error, called into<GraphBlasError> for success status"),
-                None,
-            )
-            .into(),
-            Status::NoValue => {
-                GraphblasError::new(GraphblasErrorType::NoValue, detailed_error_information).into()
-            }
-            Status::UnitializedObject => GraphblasError::new(
-                GraphblasErrorType::UnitializedObject,
-                detailed_error_information,
-            )
-            .into(),
-            Status::InvalidObject => GraphblasError::new(
-                GraphblasErrorType::InvalidObject,
-                detailed_error_information,
-            )
-            .into(),
-            Status::NotImplemented => GraphblasError::new(
-                GraphblasErrorType::NotImplemented,
-                detailed_error_information,
-            )
-            .into(),
-            Status::NullPointer => {
-                GraphblasError::new(GraphblasErrorType::NullPointer, detailed_error_information)
-                    .into()
-            }
-            Status::InvalidValue => {
-                GraphblasError::new(GraphblasErrorType::InvalidValue, detailed_error_information)
-                    .into()
+        let error_type = match self {
+            Status::Success => {
+                return SystemError::new(
+                    SystemErrorType::CreateGraphBlasErrorOnSuccessValue,
+                    format!("Logic error, called into<GraphBlasError> for success status"),
+                    None,
+                )
+                .into()
             }
-            Status::InvalidIndex => {
-                GraphblasError::new(GraphblasErrorType::InvalidIndex, detailed_error_information)
-                    .into()
-            }
-            Status::DomainMismatch => GraphblasError::new(
-                GraphblasErrorType::DomainMismatch,
-                detailed_error_information,
-            )
-            .into(),
-            Status::DimensionMismatch => GraphblasError::new(
-                GraphblasErrorType::DimensionMismatch,
-                detailed_error_information,
-            )
-            .into(),
-            Status::EmptyObject => {
-                GraphblasError::new(GraphblasErrorType::EmptyObject, detailed_error_information)
-                    .into()
-            }
-            Status::OutputNotEmpty => GraphblasError::new(
-                GraphblasErrorType::OutputNotEmpty,
-                detailed_error_information,
-            )
-            .into(),
-            Status::OutOfMemory => {
-                GraphblasError::new(GraphblasErrorType::OutOfMemory, detailed_error_information)
-                    .into()
-            }
-            Status::InsufficientSpace => GraphblasError::new(
-                GraphblasErrorType::InsufficientSpace,
-                detailed_error_information,
-            )
-            .into(),
-            Status::IndexOutOfBounds => GraphblasError::new(
-                GraphblasErrorType::IndexOutOfBounds,
-                detailed_error_information,
-            )
-            .into(),
-            Status::IteratorExhausted => GraphblasError::new(
-                GraphblasErrorType::IteratorExhausted,
-                detailed_error_information,
-            )
-            .into(),
-            Status::Panic => {
-                GraphblasError::new(GraphblasErrorType::Panic, detailed_error_information).into()
-            }
-            Status::JITError => {
-                GraphblasError::new(GraphblasErrorType::JITError, detailed_error_information).into()
-            }
-            Status::ReadOnlyOutput => GraphblasError::new(
-                GraphblasErrorType::ReadOnlyOutput,
-                detailed_error_information,
-            )
-            .into(),
-            Status::UnknownStatusType => SystemError::new(
+            Status::UnknownStatusType => return SystemError::new(
                 SystemErrorType::UnsupportedGraphBlasErrorValue,
-                String::from("Something went wrong while calling the GrapBLAS implementation"),
+                format!(
+                    "Something went wrong while calling the GrapBLAS implementation, GrB_Info: {}",
+                    graphblas_info
+                ),
                 None,
             )
             .into(),
-        }
+            Status::NoValue => GraphblasErrorType::NoValue,
+            Status::UnitializedObject => GraphblasErrorType::UnitializedObject,
+            Status::InvalidObject => GraphblasErrorType::InvalidObject,
+            Status::NotImplemented => GraphblasErrorType::NotImplemented,
+            Status::NullPointer => GraphblasErrorType::NullPointer,
+            Status::InvalidValue => GraphblasErrorType::InvalidValue,
+            Status::InvalidIndex => GraphblasErrorType::InvalidIndex,
+            Status::DomainMismatch => GraphblasErrorType::DomainMismatch,
+            Status::DimensionMismatch => GraphblasErrorType::DimensionMismatch,
+            Status::EmptyObject => GraphblasErrorType::EmptyObject,
+            Status::OutputNotEmpty => GraphblasErrorType::OutputNotEmpty,
+            Status::OutOfMemory => GraphblasErrorType::OutOfMemory,
+            Status::InsufficientSpace => GraphblasErrorType::InsufficientSpace,
+            Status::IndexOutOfBounds => GraphblasErrorType::IndexOutOfBounds,
+            Status::IteratorExhausted => GraphblasErrorType::IteratorExhausted,
+            Status::Panic => GraphblasErrorType::Panic,
+            Status::JITError => GraphblasErrorType::JITError,
+            Status::ReadOnlyOutput => GraphblasErrorType::ReadOnlyOutput,
+        };
+        GraphblasError::new_with_details(
+            error_type,
+            error_diagnostics.explanation,
+            graphblas_info,
+            error_diagnostics.failed_operation,
+            error_diagnostics.source,
+        )
+        .into()
     }
 }
 
@@ -596,7 +807,42 @@ mod tests {
     }
 
     #[test]
-    fn custom_mode() {
-        let _context = Context::init(Mode::Blocking, MatrixStorageFormat::ByColumn).unwrap();
+    fn reject_incompatible_mode() {
+        let _context = Context::init_default().unwrap();
+        assert!(Context::init(Mode::Blocking, MatrixStorageFormat::ByColumn).is_err());
+    }
+
+    #[test]
+    fn reject_incompatible_memory_allocator() {
+        let context = Context::init_default().unwrap();
+        assert_eq!(*context, *Context::init_default().unwrap());
+
+        // Identical function pointers do not make a custom allocator the system default allocator.
+        let allocator = MemoryAllocator::Custom {
+            identity: "libc",
+            malloc: libc::malloc,
+            calloc: libc::calloc,
+            realloc: libc::realloc,
+            free: libc::free,
+        };
+        assert!(unsafe {
+            Context::init_with_allocator(Mode::NonBlocking, MatrixStorageFormat::ByRow, allocator)
+        }
+        .is_err());
+    }
+
+    #[test]
+    fn count_contexts() {
+        let context = Context::init_default().unwrap();
+        let other_context = Context::init_default().unwrap();
+
+        assert!(Context::shutdown().is_err());
+
+        drop(context);
+        drop(other_context);
+        match &*RUNTIME_STATE.lock().unwrap() {
+            RuntimeState::Running { mode, .. } => assert_eq!(*mode, Mode::NonBlocking),
+            _ => panic!("GraphBLAS is not running"),
+        }
     }
 }
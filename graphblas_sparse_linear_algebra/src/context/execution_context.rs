@@ -0,0 +1,309 @@
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+use suitesparse_graphblas_sys::{
+    GxB_Context, GxB_Context_Field_GxB_CONTEXT_CHUNK, GxB_Context_Field_GxB_CONTEXT_NTHREADS,
+    GxB_Context_disengage, GxB_Context_engage, GxB_Context_free, GxB_Context_get_FP64,
+    GxB_Context_get_INT32, GxB_Context_new, GxB_Context_set_FP64, GxB_Context_set_INT32,
+};
+
+use crate::error::{LogicError, LogicErrorType, SparseLinearAlgebraError};
+
+use super::{CallGraphBlasContext, Context, GetContext};
+
+thread_local! {
+    // The execution context engaged on this thread, and the number of its live guards.
+    static ENGAGED_EXECUTION_CONTEXT: Cell<Option<(GxB_Context, usize)>> = const { Cell::new(None) };
+}
+
+/// Thread budget and work chunk size for GraphBLAS operations called from the threads that engage it.
+/// Threads without an engaged ExecutionContext use the global settings.
+/// The execution context is freed when it is dropped.
+#[derive(Debug)]
+pub struct ExecutionContext {
+    context: Arc<Context>,
+    graphblas_context: GxB_Context,
+}
+
+// Setting the thread budget or the chunk size requires mutable access.
+// SuiteSparse:GraphBLAS allows multiple threads to engage the same GxB_Context at once.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl Send for ExecutionContext {}
+unsafe impl Sync for ExecutionContext {}
+
+impl ExecutionContext {
+    /// Starts with the global thread budget and chunk size.
+    pub fn new(context: Arc<Context>) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_context: MaybeUninit<GxB_Context> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GxB_Context_new(graphblas_context.as_mut_ptr())
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_context: unsafe { graphblas_context.assume_init() },
+        })
+    }
+
+    pub fn number_of_threads(&self) -> Result<usize, SparseLinearAlgebraError> {
+        let mut number_of_threads: i32 = 0;
+        self.context.call(
+            || unsafe {
+                GxB_Context_get_INT32(
+                    self.graphblas_context,
+                    GxB_Context_Field_GxB_CONTEXT_NTHREADS as i32,
+                    &mut number_of_threads,
+                )
+            },
+            &self.graphblas_context,
+        )?;
+        match number_of_threads.try_into() {
+            Ok(number_of_threads) => Ok(number_of_threads),
+            Err(error) => Err(LogicError::from(error).into()),
+        }
+    }
+
+    /// Zero lets GraphBLAS choose the number of threads.
+    pub fn set_number_of_threads(
+        &mut self,
+        number_of_threads: usize,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let number_of_threads: i32 = match number_of_threads.try_into() {
+            Ok(number_of_threads) => number_of_threads,
+            Err(error) => return Err(LogicError::from(error).into()),
+        };
+        self.context.call(
+            || unsafe {
+                GxB_Context_set_INT32(
+                    self.graphblas_context,
+                    GxB_Context_Field_GxB_CONTEXT_NTHREADS as i32,
+                    number_of_threads,
+                )
+            },
+            &self.graphblas_context,
+        )?;
+        Ok(())
+    }
+
+    /// The amount of work per thread below which GraphBLAS uses fewer threads.
+    pub fn chunk(&self) -> Result<f64, SparseLinearAlgebraError> {
+        let mut chunk: f64 = 0.0;
+        self.context.call(
+            || unsafe {
+                GxB_Context_get_FP64(
+                    self.graphblas_context,
+                    GxB_Context_Field_GxB_CONTEXT_CHUNK as i32,
+                    &mut chunk,
+                )
+            },
+            &self.graphblas_context,
+        )?;
+        Ok(chunk)
+    }
+
+    /// Zero or less restores the default chunk size.
+    pub fn set_chunk(&mut self, chunk: f64) -> Result<(), SparseLinearAlgebraError> {
+        self.context.call(
+            || unsafe {
+                GxB_Context_set_FP64(
+                    self.graphblas_context,
+                    GxB_Context_Field_GxB_CONTEXT_CHUNK as i32,
+                    chunk,
+                )
+            },
+            &self.graphblas_context,
+        )?;
+        Ok(())
+    }
+
+    /// Engages the execution context on the current thread until the returned guard is dropped.
+    /// Engaging it again on the same thread, as nested rayon tasks may,
+    /// keeps it engaged until the outermost guard is dropped.
+    /// Fails if another execution context is engaged on the current thread.
+    pub fn engage(&self) -> Result<EngagedExecutionContext<'_>, SparseLinearAlgebraError> {
+        match ENGAGED_EXECUTION_CONTEXT.get() {
+            Some((graphblas_context, depth)) if graphblas_context == self.graphblas_context => {
+                ENGAGED_EXECUTION_CONTEXT.set(Some((graphblas_context, depth + 1)));
+            }
+            Some(_) => {
+                return Err(LogicError::new(
+                    LogicErrorType::Other,
+                    String::from("Another execution context is engaged on the current thread"),
+                    None,
+                )
+                .into())
+            }
+            None => {
+                self.context.call(
+                    || unsafe { GxB_Context_engage(self.graphblas_context) },
+                    &self.graphblas_context,
+                )?;
+                ENGAGED_EXECUTION_CONTEXT.set(Some((self.graphblas_context, 1)));
+            }
+        }
+        Ok(EngagedExecutionContext {
+            execution_context: self,
+            _bound_to_thread: PhantomData,
+        })
+    }
+}
+
+impl GetContext for ExecutionContext {
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl Drop for ExecutionContext {
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GxB_Context_free(&mut self.graphblas_context)
+            });
+    }
+}
+
+/// Disengages the ExecutionContext from the current thread when dropped.
+/// The guard cannot be sent to another thread, because engagement is per thread.
+#[derive(Debug)]
+pub struct EngagedExecutionContext<'a> {
+    execution_context: &'a ExecutionContext,
+    _bound_to_thread: PhantomData<*const ()>,
+}
+
+impl<'a> EngagedExecutionContext<'a> {
+    pub fn execution_context(&self) -> &'a ExecutionContext {
+        self.execution_context
+    }
+}
+
+impl Drop for EngagedExecutionContext<'_> {
+    fn drop(&mut self) {
+        if let Some((graphblas_context, depth)) = ENGAGED_EXECUTION_CONTEXT.get() {
+            if depth > 1 {
+                ENGAGED_EXECUTION_CONTEXT.set(Some((graphblas_context, depth - 1)));
+                return;
+            }
+        }
+        ENGAGED_EXECUTION_CONTEXT.set(None);
+        let _ = self
+            .execution_context
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GxB_Context_disengage(self.execution_context.graphblas_context)
+            });
+    }
+}
+
+impl Context {
+    /// An ExecutionContext limited to the given number of threads.
+    pub fn execution_context(
+        self: &Arc<Self>,
+        number_of_threads: usize,
+    ) -> Result<ExecutionContext, SparseLinearAlgebraError> {
+        let mut execution_context = ExecutionContext::new(self.clone())?;
+        execution_context.set_number_of_threads(number_of_threads)?;
+        Ok(execution_context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rayon::prelude::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::multiplication::{MatrixMultiplicationOperator, MultiplyMatrices};
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+    use crate::operators::semiring::PlusTimes;
+
+    #[test]
+    fn configure_execution_context() {
+        let context = Context::init_default().unwrap();
+
+        let mut execution_context = context.execution_context(2).unwrap();
+        assert_eq!(execution_context.number_of_threads().unwrap(), 2);
+
+        execution_context.set_number_of_threads(1).unwrap();
+        assert_eq!(execution_context.number_of_threads().unwrap(), 1);
+
+        execution_context.set_chunk(4096.0).unwrap();
+        assert_eq!(execution_context.chunk().unwrap(), 4096.0);
+    }
+
+    #[test]
+    fn engage_execution_context_per_thread() {
+        let context = Context::init_default().unwrap();
+
+        let matrix_size: Size = (3, 3).into();
+        let matrix = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            matrix_size,
+            MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 0, 1.0).into(),
+                (1, 1, 2.0).into(),
+                (2, 2, 3.0).into(),
+            ]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+
+        let execution_context = context.execution_context(1).unwrap();
+
+        let traces: Vec<f32> = (0..8)
+            .into_par_iter()
+            .map(|_| {
+                let _engaged = execution_context.engage().unwrap();
+
+                let mut product = SparseMatrix::<f32>::new(context.clone(), matrix_size).unwrap();
+                MatrixMultiplicationOperator::new()
+                    .apply(
+                        &matrix,
+                        &PlusTimes::<f32>::new(),
+                        &matrix,
+                        &Assignment::new(),
+                        &mut product,
+                        &SelectEntireMatrix::new(context.clone()),
+                        &OptionsForOperatorWithMatrixArguments::new_default(),
+                    )
+                    .unwrap();
+
+                (0..3)
+                    .map(|index| product.element_value_or_default(index, index).unwrap())
+                    .sum()
+            })
+            .collect();
+
+        assert!(traces.iter().all(|trace| *trace == 14.0));
+    }
+
+    #[test]
+    fn engage_execution_context_again_on_the_same_thread() {
+        let context = Context::init_default().unwrap();
+
+        let execution_context = context.execution_context(1).unwrap();
+        let other_execution_context = context.execution_context(2).unwrap();
+
+        let engaged = execution_context.engage().unwrap();
+        let engaged_again = execution_context.engage().unwrap();
+        drop(engaged_again);
+        assert!(other_execution_context.engage().is_err());
+
+        drop(engaged);
+        assert!(other_execution_context.engage().is_ok());
+    }
+}
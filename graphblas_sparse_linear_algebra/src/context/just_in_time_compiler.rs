@@ -0,0 +1,259 @@
+#![allow(non_upper_case_globals)]
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+use suitesparse_graphblas_sys::{
+    GxB_JIT_Control, GxB_JIT_Control_GxB_JIT_LOAD, GxB_JIT_Control_GxB_JIT_OFF,
+    GxB_JIT_Control_GxB_JIT_ON, GxB_JIT_Control_GxB_JIT_PAUSE, GxB_JIT_Control_GxB_JIT_RUN,
+};
+
+use crate::error::{
+    GraphblasErrorType, LogicError, LogicErrorType, SparseLinearAlgebraError,
+    SparseLinearAlgebraErrorType, SystemErrorType,
+};
+use crate::property::GlobalProperty;
+
+use super::Context;
+
+/// What the JIT may do with kernels that are not built into the library.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum JitControl {
+    /// Use generic kernels only, and unload all JIT kernels
+    Off,
+    /// Use generic kernels only, but keep loaded JIT kernels
+    Pause,
+    /// Use loaded JIT kernels, without loading or compiling new ones
+    Run,
+    /// Load JIT kernels from the cache, without compiling new ones
+    Load,
+    /// Load and compile JIT kernels as needed
+    On,
+}
+
+impl TryFrom<GxB_JIT_Control> for JitControl {
+    type Error = LogicError;
+
+    fn try_from(control: GxB_JIT_Control) -> Result<Self, Self::Error> {
+        match control {
+            GxB_JIT_Control_GxB_JIT_OFF => Ok(Self::Off),
+            GxB_JIT_Control_GxB_JIT_PAUSE => Ok(Self::Pause),
+            GxB_JIT_Control_GxB_JIT_RUN => Ok(Self::Run),
+            GxB_JIT_Control_GxB_JIT_LOAD => Ok(Self::Load),
+            GxB_JIT_Control_GxB_JIT_ON => Ok(Self::On),
+            _ => Err(LogicError::new(
+                LogicErrorType::Other,
+                format!("JIT control not supported: {}", control),
+                None,
+            )),
+        }
+    }
+}
+
+impl From<JitControl> for GxB_JIT_Control {
+    fn from(control: JitControl) -> Self {
+        match control {
+            JitControl::Off => GxB_JIT_Control_GxB_JIT_OFF,
+            JitControl::Pause => GxB_JIT_Control_GxB_JIT_PAUSE,
+            JitControl::Run => GxB_JIT_Control_GxB_JIT_RUN,
+            JitControl::Load => GxB_JIT_Control_GxB_JIT_LOAD,
+            JitControl::On => GxB_JIT_Control_GxB_JIT_ON,
+        }
+    }
+}
+
+/// Why an operation failed with a JIT error.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum JitErrorCause {
+    /// The configured C compiler cannot be found.
+    /// Setting JitControl::Load or lower falls back to generic kernels for kernels that are not in the cache.
+    MissingCompiler,
+    /// Compiling, linking or loading a kernel failed. The JIT error log has details.
+    KernelFailure,
+}
+
+impl Context {
+    /// GraphBLAS lowers the control to JitControl::Load when a kernel fails to compile.
+    pub fn jit_control(&self) -> Result<JitControl, SparseLinearAlgebraError> {
+        let control = self.get_property(GlobalProperty::JitCControl)?;
+        Ok(JitControl::try_from(control as GxB_JIT_Control)?)
+    }
+
+    /// The control is limited to JitControl::Run if the library was built without a JIT.
+    pub fn set_jit_control(&self, control: JitControl) -> Result<(), SparseLinearAlgebraError> {
+        self.set_property(
+            GlobalProperty::JitCControl,
+            GxB_JIT_Control::from(control) as i32,
+        )
+    }
+
+    pub fn jit_compiler_name(&self) -> Result<String, SparseLinearAlgebraError> {
+        self.get_property(GlobalProperty::JitCCompilerName)
+    }
+
+    pub fn set_jit_compiler_name(&self, name: &str) -> Result<(), SparseLinearAlgebraError> {
+        self.set_property(GlobalProperty::JitCCompilerName, name.to_owned())
+    }
+
+    pub fn jit_compiler_flags(&self) -> Result<String, SparseLinearAlgebraError> {
+        self.get_property(GlobalProperty::JitCCompilerFlags)
+    }
+
+    pub fn set_jit_compiler_flags(&self, flags: &str) -> Result<(), SparseLinearAlgebraError> {
+        self.set_property(GlobalProperty::JitCCompilerFlags, flags.to_owned())
+    }
+
+    pub fn jit_linker_flags(&self) -> Result<String, SparseLinearAlgebraError> {
+        self.get_property(GlobalProperty::JitCLinkerFlags)
+    }
+
+    pub fn set_jit_linker_flags(&self, flags: &str) -> Result<(), SparseLinearAlgebraError> {
+        self.set_property(GlobalProperty::JitCLinkerFlags, flags.to_owned())
+    }
+
+    /// The directory with the sources and compiled libraries of JIT kernels.
+    pub fn jit_cache_path(&self) -> Result<PathBuf, SparseLinearAlgebraError> {
+        Ok(PathBuf::from(
+            self.get_property(GlobalProperty::JitCachePath)?,
+        ))
+    }
+
+    pub fn set_jit_cache_path(&self, path: &Path) -> Result<(), SparseLinearAlgebraError> {
+        self.set_property(GlobalProperty::JitCachePath, path_to_str(path)?.to_owned())
+    }
+
+    /// The file that compiler errors are written to. Empty if errors are written to stderr.
+    pub fn jit_error_log(&self) -> Result<PathBuf, SparseLinearAlgebraError> {
+        Ok(PathBuf::from(
+            self.get_property(GlobalProperty::JitErrorLog)?,
+        ))
+    }
+
+    pub fn set_jit_error_log(&self, path: &Path) -> Result<(), SparseLinearAlgebraError> {
+        self.set_property(GlobalProperty::JitErrorLog, path_to_str(path)?.to_owned())
+    }
+
+    /// Whether the configured C compiler is an existing file, or a program on the PATH.
+    pub fn is_jit_compiler_available(&self) -> Result<bool, SparseLinearAlgebraError> {
+        Ok(is_program_available(&self.jit_compiler_name()?))
+    }
+
+    /// None if the error is not a JIT error.
+    /// GraphBLAS does not report why a kernel failed. JitErrorCause::MissingCompiler is a best guess,
+    /// from looking up jit_compiler_name() as a file or on the PATH.
+    pub fn jit_error_cause(
+        &self,
+        error: &SparseLinearAlgebraError,
+    ) -> Result<Option<JitErrorCause>, SparseLinearAlgebraError> {
+        match error.error_type() {
+            SparseLinearAlgebraErrorType::SystemErrorType(SystemErrorType::GraphBLAS(
+                GraphblasErrorType::JITError,
+            )) => {
+                if self.is_jit_compiler_available()? {
+                    Ok(Some(JitErrorCause::KernelFailure))
+                } else {
+                    Ok(Some(JitErrorCause::MissingCompiler))
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+fn path_to_str(path: &Path) -> Result<&str, SparseLinearAlgebraError> {
+    match path.to_str() {
+        Some(path) => Ok(path),
+        None => Err(LogicError::new(
+            LogicErrorType::Other,
+            format!("Path is not valid UTF-8: {:?}", path),
+            None,
+        )
+        .into()),
+    }
+}
+
+fn is_program_available(program: &str) -> bool {
+    let program = Path::new(program.trim());
+    if program.as_os_str().is_empty() {
+        return false;
+    }
+    if program.components().count() > 1 {
+        return program.is_file();
+    }
+    match env::var_os("PATH") {
+        Some(paths) => env::split_paths(&paths).any(|directory| directory.join(program).is_file()),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::lock_global_configuration;
+    use crate::error::GraphblasError;
+
+    #[test]
+    fn set_and_get_jit_control() {
+        let _global_configuration = lock_global_configuration();
+        let context = Context::init_default().unwrap();
+
+        let initial_control = context.jit_control().unwrap();
+
+        context.set_jit_control(JitControl::Pause).unwrap();
+        assert_eq!(context.jit_control().unwrap(), JitControl::Pause);
+
+        context.set_jit_control(initial_control).unwrap();
+        assert_eq!(context.jit_control().unwrap(), initial_control);
+    }
+
+    #[test]
+    fn reject_unknown_jit_control() {
+        assert!(JitControl::try_from(GxB_JIT_Control_GxB_JIT_ON + 1).is_err());
+        assert_eq!(
+            JitControl::try_from(GxB_JIT_Control_GxB_JIT_LOAD).unwrap(),
+            JitControl::Load
+        );
+    }
+
+    #[test]
+    fn get_jit_configuration() {
+        let context = Context::init_default().unwrap();
+
+        assert!(!context.jit_compiler_name().unwrap().is_empty());
+        context.jit_compiler_flags().unwrap();
+        context.jit_linker_flags().unwrap();
+        context.jit_cache_path().unwrap();
+        context.jit_error_log().unwrap();
+    }
+
+    #[test]
+    fn find_program() {
+        assert!(!is_program_available(""));
+        assert!(!is_program_available("/nonexistent/directory/cc"));
+        assert!(is_program_available(
+            env::current_exe().unwrap().to_str().unwrap()
+        ));
+    }
+
+    #[test]
+    fn classify_jit_errors() {
+        let context = Context::init_default().unwrap();
+
+        let jit_error: SparseLinearAlgebraError =
+            GraphblasError::new(GraphblasErrorType::JITError, String::new()).into();
+        let expected_cause = if context.is_jit_compiler_available().unwrap() {
+            JitErrorCause::KernelFailure
+        } else {
+            JitErrorCause::MissingCompiler
+        };
+        assert_eq!(
+            context.jit_error_cause(&jit_error).unwrap(),
+            Some(expected_cause)
+        );
+
+        let other_error: SparseLinearAlgebraError =
+            GraphblasError::new(GraphblasErrorType::DimensionMismatch, String::new()).into();
+        assert_eq!(context.jit_error_cause(&other_error).unwrap(), None);
+    }
+}
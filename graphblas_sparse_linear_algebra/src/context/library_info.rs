@@ -0,0 +1,245 @@
+use std::fmt;
+
+use suitesparse_graphblas_sys::GrB_getVersion;
+
+use crate::error::{LogicError, SparseLinearAlgebraError};
+use crate::property::{GlobalProperty, Property};
+
+use super::{Context, MemoryAllocatorType};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl Version {
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    pub fn patch(&self) -> u32 {
+        self.patch
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The cargo features this crate was compiled with.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CargoFeatures {
+    build_standard_kernels: bool,
+    disable_just_in_time_compiler: bool,
+    memory_allocator_mimalloc: bool,
+    memory_allocator_jemalloc: bool,
+}
+
+impl CargoFeatures {
+    pub(crate) fn new() -> Self {
+        Self {
+            build_standard_kernels: cfg!(feature = "build-standard-kernels"),
+            disable_just_in_time_compiler: cfg!(feature = "disable-just-in-time-compiler"),
+            memory_allocator_mimalloc: cfg!(feature = "memory-allocator-mimalloc"),
+            memory_allocator_jemalloc: cfg!(feature = "memory-allocator-jemalloc"),
+        }
+    }
+
+    pub fn build_standard_kernels(&self) -> bool {
+        self.build_standard_kernels
+    }
+
+    pub fn disable_just_in_time_compiler(&self) -> bool {
+        self.disable_just_in_time_compiler
+    }
+
+    pub fn memory_allocator_mimalloc(&self) -> bool {
+        self.memory_allocator_mimalloc
+    }
+
+    pub fn memory_allocator_jemalloc(&self) -> bool {
+        self.memory_allocator_jemalloc
+    }
+}
+
+/// What GraphBLAS implementation is linked, and how it was built.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LibraryInfo {
+    api_version: Version,
+    implementation_name: String,
+    implementation_version: Version,
+    implementation_date: String,
+    implementation_license: String,
+    is_just_in_time_compiler_enabled: bool,
+    is_openmp_enabled: bool,
+    memory_allocator_type: MemoryAllocatorType,
+    cargo_features: CargoFeatures,
+}
+
+impl LibraryInfo {
+    /// The version of the GraphBLAS C API specification
+    pub fn api_version(&self) -> Version {
+        self.api_version
+    }
+
+    pub fn implementation_name(&self) -> &str {
+        &self.implementation_name
+    }
+
+    pub fn implementation_version(&self) -> Version {
+        self.implementation_version
+    }
+
+    pub fn implementation_date(&self) -> &str {
+        &self.implementation_date
+    }
+
+    pub fn implementation_license(&self) -> &str {
+        &self.implementation_license
+    }
+
+    /// False if GraphBLAS was built without a JIT, in which case only built-in kernels are used.
+    pub fn is_just_in_time_compiler_enabled(&self) -> bool {
+        self.is_just_in_time_compiler_enabled
+    }
+
+    pub fn is_openmp_enabled(&self) -> bool {
+        self.is_openmp_enabled
+    }
+
+    pub fn memory_allocator_type(&self) -> MemoryAllocatorType {
+        self.memory_allocator_type
+    }
+
+    pub fn cargo_features(&self) -> CargoFeatures {
+        self.cargo_features
+    }
+}
+
+impl fmt::Display for LibraryInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} {} ({}), GraphBLAS C API {}",
+            self.implementation_name,
+            self.implementation_version,
+            self.implementation_date,
+            self.api_version
+        )?;
+        writeln!(f, "License: {}", self.implementation_license)?;
+        writeln!(
+            f,
+            "JIT: {}, OpenMP: {}, memory allocator: {:?}",
+            self.is_just_in_time_compiler_enabled,
+            self.is_openmp_enabled,
+            self.memory_allocator_type
+        )?;
+        write!(f, "Cargo features: {:?}", self.cargo_features)
+    }
+}
+
+impl Context {
+    pub fn library_info(&self) -> Result<LibraryInfo, SparseLinearAlgebraError> {
+        let mut api_version_major: u32 = 0;
+        let mut api_version_minor: u32 = 0;
+        self.call_without_detailed_error_information(|| unsafe {
+            GrB_getVersion(&mut api_version_major, &mut api_version_minor)
+        })?;
+
+        Ok(LibraryInfo {
+            api_version: Version::new(
+                api_version_major,
+                api_version_minor,
+                self.unsigned_integer_property(GlobalProperty::ApiVersionPatch)?,
+            ),
+            implementation_name: self.get_property(GlobalProperty::Name)?,
+            implementation_version: Version::new(
+                self.unsigned_integer_property(GlobalProperty::LibraryVersionMajor)?,
+                self.unsigned_integer_property(GlobalProperty::LibraryVersionMinor)?,
+                self.unsigned_integer_property(GlobalProperty::LibraryVersionPatch)?,
+            ),
+            implementation_date: self.get_property(GlobalProperty::LibraryDate)?,
+            implementation_license: self.get_property(GlobalProperty::LibraryLicense)?,
+            is_just_in_time_compiler_enabled: is_library_built_with_just_in_time_compiler(),
+            is_openmp_enabled: self.unsigned_integer_property(GlobalProperty::LibraryOpenMp)? != 0,
+            memory_allocator_type: self.memory_allocator_type,
+            cargo_features: CargoFeatures::new(),
+        })
+    }
+
+    fn unsigned_integer_property(
+        &self,
+        field: Property<GlobalProperty, i32>,
+    ) -> Result<u32, SparseLinearAlgebraError> {
+        match self.get_property(field)?.try_into() {
+            Ok(value) => Ok(value),
+            Err(error) => Err(LogicError::from(error).into()),
+        }
+    }
+}
+
+// suitesparse_graphblas_sys builds GraphBLAS with GRAPHBLAS_USE_JIT off exactly when this feature is enabled.
+fn is_library_built_with_just_in_time_compiler() -> bool {
+    !cfg!(feature = "disable-just-in-time-compiler")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::JitControl;
+
+    #[test]
+    fn report_library_info() {
+        let context = Context::init_default().unwrap();
+
+        let library_info = context.library_info().unwrap();
+
+        assert_eq!(library_info.implementation_name(), "SuiteSparse:GraphBLAS");
+        assert!(library_info.api_version() >= Version::new(2, 0, 0));
+        assert!(library_info.implementation_version().major() >= 9);
+        assert!(!library_info.implementation_license().is_empty());
+        assert_eq!(
+            library_info.memory_allocator_type(),
+            MemoryAllocatorType::SystemDefault
+        );
+        assert!(!library_info.to_string().is_empty());
+    }
+
+    #[test]
+    fn just_in_time_compiler_follows_build_configuration() {
+        let context = Context::init_default().unwrap();
+
+        let library_info = context.library_info().unwrap();
+
+        assert_eq!(
+            library_info
+                .cargo_features()
+                .disable_just_in_time_compiler(),
+            !library_info.is_just_in_time_compiler_enabled()
+        );
+        // GraphBLAS limits the JIT control to JitControl::Run if it was built without a JIT.
+        if matches!(
+            context.jit_control().unwrap(),
+            JitControl::Load | JitControl::On
+        ) {
+            assert!(library_info.is_just_in_time_compiler_enabled());
+        }
+    }
+}
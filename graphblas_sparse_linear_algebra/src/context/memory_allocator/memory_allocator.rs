@@ -26,7 +26,9 @@ pub enum MemoryAllocator {
 
     /// Supply raw allocator function pointers. You are responsible for
     /// ensuring these match the active global allocator.
+    /// The identity names the allocator, to check that every context uses the same one.
     Custom {
+        identity: &'static str,
         malloc: unsafe extern "C" fn(usize) -> *mut c_void,
         calloc: unsafe extern "C" fn(usize, usize) -> *mut c_void,
         realloc: unsafe extern "C" fn(*mut c_void, usize) -> *mut c_void,
@@ -34,7 +36,16 @@ pub enum MemoryAllocator {
     },
 }
 
-#[derive(Debug, PartialEq)]
+/// Which MemoryAllocator GraphBLAS was initialised with.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MemoryAllocatorType {
+    SystemDefault,
+    MiMalloc,
+    Jemalloc,
+    Custom { identity: &'static str },
+}
+
+#[derive(Copy, Clone, Debug)]
 pub(crate) struct MemoryAllocatorFuctionPointers {
     pub malloc: unsafe extern "C" fn(usize) -> *mut c_void,
     pub calloc: unsafe extern "C" fn(usize, usize) -> *mut c_void,
@@ -43,6 +54,19 @@ pub(crate) struct MemoryAllocatorFuctionPointers {
 }
 
 impl MemoryAllocator {
+    pub fn memory_allocator_type(&self) -> MemoryAllocatorType {
+        match self {
+            MemoryAllocator::SystemDefault => MemoryAllocatorType::SystemDefault,
+            #[cfg(feature = "memory-allocator-mimalloc")]
+            MemoryAllocator::MiMalloc => MemoryAllocatorType::MiMalloc,
+            #[cfg(feature = "memory-allocator-jemalloc")]
+            MemoryAllocator::Jemalloc => MemoryAllocatorType::Jemalloc,
+            MemoryAllocator::Custom { identity, .. } => MemoryAllocatorType::Custom {
+                identity: *identity,
+            },
+        }
+    }
+
     pub(crate) fn memory_allocator_function_pointers(
         &self,
     ) -> MemoryAllocatorFuctionPointers {
@@ -71,6 +95,7 @@ impl MemoryAllocator {
             },
 
             MemoryAllocator::Custom {
+                identity: _,
                 malloc,
                 calloc,
                 realloc,
@@ -1,7 +1,23 @@
+mod burble;
 mod configuration;
 mod context;
+mod execution_context;
+mod just_in_time_compiler;
+mod library_info;
 mod memory_allocator;
+mod operation;
+mod operation_statistics;
+#[cfg(feature = "tracing")]
+mod operation_tracing;
 
+pub use burble::{BurbleOutput, BurbleScope};
 pub use configuration::*;
+pub(crate) use context::CallGraphBlasOperation;
 pub use context::{CallGraphBlasContext, Context, GetContext, Mode, Status};
+pub use execution_context::{EngagedExecutionContext, ExecutionContext};
+pub use just_in_time_compiler::{JitControl, JitErrorCause};
+pub use library_info::{CargoFeatures, LibraryInfo, Version};
 pub use memory_allocator::*;
+pub(crate) use operation::Operation;
+pub use operation::OperationKind;
+pub use operation_statistics::{OperationStatistics, OperationStatisticsSnapshot};
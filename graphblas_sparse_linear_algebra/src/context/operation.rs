@@ -0,0 +1,277 @@
+use suitesparse_graphblas_sys::{
+    GrB_BinaryOp, GrB_Index, GrB_IndexUnaryOp, GrB_Info_GrB_SUCCESS, GrB_Matrix_ncols,
+    GrB_Matrix_nrows, GrB_Matrix_nvals, GrB_Monoid, GrB_Scalar_nvals, GrB_Semiring, GrB_UnaryOp,
+    GrB_Vector_nvals, GrB_Vector_size,
+};
+
+use crate::collections::sparse_matrix::Size;
+use crate::error::{ArgumentRole, ArgumentShape, FailedOperation, OperationArgument};
+use crate::property::{get_graphblas_property, Operand, OperatorProperty};
+
+use super::Context;
+
+/// The kind of a wrapped GraphBLAS operation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OperationKind {
+    MatrixMultiplication,
+    MatrixVectorMultiplication,
+    VectorMatrixMultiplication,
+    ElementWiseAddition,
+    ElementWiseMultiplication,
+    ElementWiseUnion,
+    KroneckerProduct,
+    Apply,
+    Select,
+    Reduce,
+    Transpose,
+    Extract,
+    Assign,
+    Build,
+    Serialize,
+}
+
+/// The operator applied by an operation.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum OperationOperator {
+    UnaryOperator(GrB_UnaryOp),
+    BinaryOperator(GrB_BinaryOp),
+    IndexUnaryOperator(GrB_IndexUnaryOp),
+    Monoid(GrB_Monoid),
+    Semiring(GrB_Semiring),
+}
+
+impl OperationOperator {
+    // Failing to retrieve a name must not hide the outcome of the operation.
+    pub(crate) fn name(&self, context: &Context) -> Option<String> {
+        let name = match self {
+            OperationOperator::UnaryOperator(operator) => {
+                get_graphblas_property(context, *operator, OperatorProperty::Name)
+            }
+            OperationOperator::BinaryOperator(operator) => {
+                get_graphblas_property(context, *operator, OperatorProperty::Name)
+            }
+            OperationOperator::IndexUnaryOperator(operator) => {
+                get_graphblas_property(context, *operator, OperatorProperty::Name)
+            }
+            OperationOperator::Monoid(operator) => {
+                get_graphblas_property(context, *operator, OperatorProperty::Name)
+            }
+            OperationOperator::Semiring(operator) => {
+                get_graphblas_property(context, *operator, OperatorProperty::Name)
+            }
+        };
+        name.ok().filter(|name| !name.is_empty())
+    }
+}
+
+/// Identifies a wrapped GraphBLAS call in diagnostics.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Operation {
+    kind: OperationKind,
+    graphblas_function: &'static str,
+    operator: Option<OperationOperator>,
+}
+
+impl Operation {
+    pub(crate) fn new(kind: OperationKind, graphblas_function: &'static str) -> Self {
+        Self {
+            kind,
+            graphblas_function,
+            operator: None,
+        }
+    }
+
+    pub(crate) fn with_unary_operator(mut self, operator: GrB_UnaryOp) -> Self {
+        self.operator = Some(OperationOperator::UnaryOperator(operator));
+        self
+    }
+
+    pub(crate) fn with_binary_operator(mut self, operator: GrB_BinaryOp) -> Self {
+        self.operator = Some(OperationOperator::BinaryOperator(operator));
+        self
+    }
+
+    pub(crate) fn with_index_unary_operator(mut self, operator: GrB_IndexUnaryOp) -> Self {
+        self.operator = Some(OperationOperator::IndexUnaryOperator(operator));
+        self
+    }
+
+    pub(crate) fn with_monoid(mut self, operator: GrB_Monoid) -> Self {
+        self.operator = Some(OperationOperator::Monoid(operator));
+        self
+    }
+
+    pub(crate) fn with_semiring(mut self, operator: GrB_Semiring) -> Self {
+        self.operator = Some(OperationOperator::Semiring(operator));
+        self
+    }
+
+    pub(crate) fn failed_operation(
+        &self,
+        context: &Context,
+        output: Option<Operand>,
+        inputs: &[Operand],
+    ) -> FailedOperation {
+        let output = output.map(|output| (ArgumentRole::Output, output));
+        let inputs = inputs
+            .iter()
+            .enumerate()
+            .map(|(index, input)| (ArgumentRole::Input(index), *input));
+        let arguments = output
+            .into_iter()
+            .chain(inputs)
+            .map(|(role, operand)| {
+                OperationArgument::new(role, operand.name(context), operand.shape())
+            })
+            .collect();
+        FailedOperation::new(
+            self.kind,
+            self.graphblas_function,
+            self.operator.and_then(|operator| operator.name(context)),
+            arguments,
+        )
+    }
+
+    pub(crate) fn kind(&self) -> OperationKind {
+        self.kind
+    }
+
+    pub(crate) fn graphblas_function(&self) -> &'static str {
+        self.graphblas_function
+    }
+
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    pub(crate) fn operator(&self) -> Option<OperationOperator> {
+        self.operator
+    }
+}
+
+// Measuring must not change the outcome of an operation, so failures are returned as None.
+// Retrieving the number of stored elements completes pending work, like GrB_wait.
+impl Operand {
+    /// Vectors have a single column, scalars are 1x1.
+    pub(crate) fn size(&self) -> Option<(u64, u64)> {
+        match self {
+            Operand::Matrix(matrix) => {
+                let mut number_of_rows: GrB_Index = 0;
+                let mut number_of_columns: GrB_Index = 0;
+                let is_success = unsafe {
+                    GrB_Matrix_nrows(&mut number_of_rows, *matrix) == GrB_Info_GrB_SUCCESS
+                        && GrB_Matrix_ncols(&mut number_of_columns, *matrix) == GrB_Info_GrB_SUCCESS
+                };
+                is_success.then_some((number_of_rows, number_of_columns))
+            }
+            Operand::Vector(vector) => {
+                let mut length: GrB_Index = 0;
+                let is_success =
+                    unsafe { GrB_Vector_size(&mut length, *vector) == GrB_Info_GrB_SUCCESS };
+                is_success.then_some((length, 1))
+            }
+            Operand::Scalar(_) => Some((1, 1)),
+        }
+    }
+
+    pub(crate) fn shape(&self) -> Option<ArgumentShape> {
+        let (number_of_rows, number_of_columns) = self.size()?;
+        let number_of_rows = usize::try_from(number_of_rows).ok()?;
+        let number_of_columns = usize::try_from(number_of_columns).ok()?;
+        Some(match self {
+            Operand::Matrix(_) => {
+                ArgumentShape::Matrix(Size::new(number_of_rows, number_of_columns))
+            }
+            Operand::Vector(_) => ArgumentShape::Vector(number_of_rows),
+            Operand::Scalar(_) => ArgumentShape::Scalar,
+        })
+    }
+
+    pub(crate) fn number_of_stored_elements(&self) -> Option<u64> {
+        let mut number_of_stored_elements: GrB_Index = 0;
+        let info = unsafe {
+            match self {
+                Operand::Matrix(matrix) => {
+                    GrB_Matrix_nvals(&mut number_of_stored_elements, *matrix)
+                }
+                Operand::Vector(vector) => {
+                    GrB_Vector_nvals(&mut number_of_stored_elements, *vector)
+                }
+                Operand::Scalar(scalar) => {
+                    GrB_Scalar_nvals(&mut number_of_stored_elements, *scalar)
+                }
+            }
+        };
+        (info == GrB_Info_GrB_SUCCESS).then_some(number_of_stored_elements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::error::Error;
+
+    use crate::collections::sparse_matrix::SparseMatrix;
+    use crate::error::GraphblasError;
+    use crate::operators::binary_operator::{Assignment, Plus};
+    use crate::operators::element_wise_addition::{
+        ApplyElementWiseMatrixAdditionBinaryOperator, ElementWiseMatrixAdditionBinaryOperator,
+    };
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+
+    #[test]
+    fn describe_failed_operation() {
+        let context = Context::init_default().unwrap();
+
+        let left_argument = SparseMatrix::<i32>::new(context.clone(), (2, 3).into()).unwrap();
+        let right_argument = SparseMatrix::<i32>::new(context.clone(), (3, 2).into()).unwrap();
+        let mut product = SparseMatrix::<i32>::new(context.clone(), (2, 3).into()).unwrap();
+
+        let error = ElementWiseMatrixAdditionBinaryOperator::new()
+            .apply(
+                &left_argument,
+                &Plus::<i32>::new(),
+                &right_argument,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap_err();
+
+        let graphblas_error = error
+            .source()
+            .and_then(|logic_error| logic_error.source())
+            .and_then(|graphblas_error| graphblas_error.downcast_ref::<GraphblasError>())
+            .unwrap();
+        assert!(graphblas_error.source().is_none());
+        assert_eq!(
+            graphblas_error.graphblas_info(),
+            Some(suitesparse_graphblas_sys::GrB_Info_GrB_DIMENSION_MISMATCH)
+        );
+
+        let failed_operation = graphblas_error.failed_operation().unwrap();
+        assert_eq!(failed_operation.kind(), OperationKind::ElementWiseAddition);
+        let shapes: Vec<(ArgumentRole, Option<ArgumentShape>)> = failed_operation
+            .arguments()
+            .iter()
+            .map(|argument| (argument.role(), argument.shape()))
+            .collect();
+        assert_eq!(
+            shapes,
+            vec![
+                (
+                    ArgumentRole::Output,
+                    Some(ArgumentShape::Matrix((2, 3).into()))
+                ),
+                (
+                    ArgumentRole::Input(0),
+                    Some(ArgumentShape::Matrix((2, 3).into()))
+                ),
+                (
+                    ArgumentRole::Input(1),
+                    Some(ArgumentShape::Matrix((3, 2).into()))
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,390 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::error::{SparseLinearAlgebraError, SystemError};
+use crate::property::Operand;
+
+use super::{Context, Operation, OperationKind};
+
+/// Accumulated over all calls of one kind of operation.
+/// The numbers of stored elements and floating point operations are estimates of the work done,
+/// not measurements of the work GraphBLAS actually did.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct OperationStatistics {
+    number_of_calls: u64,
+    number_of_failed_calls: u64,
+    cumulative_time: Duration,
+    number_of_stored_input_elements: u64,
+    number_of_stored_output_elements: u64,
+    estimated_number_of_floating_point_operations: f64,
+}
+
+impl OperationStatistics {
+    pub fn number_of_calls(&self) -> u64 {
+        self.number_of_calls
+    }
+
+    pub fn number_of_failed_calls(&self) -> u64 {
+        self.number_of_failed_calls
+    }
+
+    pub fn cumulative_time(&self) -> Duration {
+        self.cumulative_time
+    }
+
+    pub fn number_of_stored_input_elements(&self) -> u64 {
+        self.number_of_stored_input_elements
+    }
+
+    pub fn number_of_stored_output_elements(&self) -> u64 {
+        self.number_of_stored_output_elements
+    }
+
+    /// Multiplications assume stored elements to be spread uniformly.
+    /// Operations that only move elements, like extract, assign, transpose, build and serialize, are estimated at zero.
+    pub fn estimated_number_of_floating_point_operations(&self) -> f64 {
+        self.estimated_number_of_floating_point_operations
+    }
+
+    fn add(&mut self, other: &OperationStatistics) {
+        self.number_of_calls += other.number_of_calls;
+        self.number_of_failed_calls += other.number_of_failed_calls;
+        self.cumulative_time += other.cumulative_time;
+        self.number_of_stored_input_elements += other.number_of_stored_input_elements;
+        self.number_of_stored_output_elements += other.number_of_stored_output_elements;
+        self.estimated_number_of_floating_point_operations +=
+            other.estimated_number_of_floating_point_operations;
+    }
+}
+
+/// The operation statistics of a Context at one point in time.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OperationStatisticsSnapshot {
+    statistics: BTreeMap<OperationKind, OperationStatistics>,
+}
+
+impl OperationStatisticsSnapshot {
+    /// None if no operation of the kind was called.
+    pub fn statistics(&self, kind: OperationKind) -> Option<&OperationStatistics> {
+        self.statistics.get(&kind)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&OperationKind, &OperationStatistics)> {
+        self.statistics.iter()
+    }
+
+    pub fn total(&self) -> OperationStatistics {
+        let mut total = OperationStatistics::default();
+        for statistics in self.statistics.values() {
+            total.add(statistics);
+        }
+        total
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.statistics.is_empty()
+    }
+}
+
+impl fmt::Display for OperationStatisticsSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<28} {:>10} {:>8} {:>14} {:>14} {:>14} {:>14}",
+            "Operation", "Calls", "Failed", "Time (us)", "Input nnz", "Output nnz", "Flops"
+        )?;
+        for (kind, statistics) in self.statistics.iter() {
+            writeln!(
+                f,
+                "{:<28} {:>10} {:>8} {:>14} {:>14} {:>14} {:>14.0}",
+                format!("{:?}", kind),
+                statistics.number_of_calls,
+                statistics.number_of_failed_calls,
+                statistics.cumulative_time.as_micros(),
+                statistics.number_of_stored_input_elements,
+                statistics.number_of_stored_output_elements,
+                statistics.estimated_number_of_floating_point_operations
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct OperationStatisticsCollector {
+    is_enabled: AtomicBool,
+    statistics: Mutex<BTreeMap<OperationKind, OperationStatistics>>,
+}
+
+impl OperationStatisticsCollector {
+    /// None if collection is disabled.
+    /// The inputs are measured before the operation runs, because the output may be one of its inputs.
+    pub(crate) fn start_measurement(
+        &self,
+        operation: &Operation,
+        inputs: &[Operand],
+    ) -> Option<OperationMeasurement> {
+        if !self.is_enabled.load(Ordering::Relaxed) {
+            return None;
+        }
+        let input_measurements: Vec<(Option<(u64, u64)>, u64)> = inputs
+            .iter()
+            .map(|input| (input.size(), input.number_of_stored_elements().unwrap_or(0)))
+            .collect();
+        Some(OperationMeasurement {
+            kind: operation.kind(),
+            number_of_stored_input_elements: input_measurements
+                .iter()
+                .map(|(_, number_of_stored_elements)| number_of_stored_elements)
+                .sum(),
+            estimated_number_of_floating_point_operations: estimate_floating_point_operations(
+                operation.kind(),
+                &input_measurements,
+            ),
+            start: Instant::now(),
+        })
+    }
+
+    // Statistics are best-effort, a poisoned lock must not fail the operation.
+    pub(crate) fn finish_measurement(
+        &self,
+        measurement: OperationMeasurement,
+        output: Option<Operand>,
+        is_success: bool,
+    ) {
+        let elapsed = measurement.start.elapsed();
+        let number_of_stored_output_elements = output
+            .and_then(|output| output.number_of_stored_elements())
+            .unwrap_or(0);
+        if let Ok(mut statistics) = self.statistics.lock() {
+            statistics
+                .entry(measurement.kind)
+                .or_default()
+                .add(&OperationStatistics {
+                    number_of_calls: 1,
+                    number_of_failed_calls: u64::from(!is_success),
+                    cumulative_time: elapsed,
+                    number_of_stored_input_elements: measurement.number_of_stored_input_elements,
+                    number_of_stored_output_elements,
+                    estimated_number_of_floating_point_operations: measurement
+                        .estimated_number_of_floating_point_operations,
+                });
+        }
+    }
+}
+
+pub(crate) struct OperationMeasurement {
+    kind: OperationKind,
+    number_of_stored_input_elements: u64,
+    estimated_number_of_floating_point_operations: f64,
+    start: Instant,
+}
+
+// A multiplication with a shared dimension of length k does 2 * nnz(a) * nnz(b) / k floating point operations
+// if stored elements are spread uniformly. Transposed arguments are matched by trying all combinations of dimensions.
+fn estimate_floating_point_operations(
+    kind: OperationKind,
+    input_measurements: &[(Option<(u64, u64)>, u64)],
+) -> f64 {
+    match kind {
+        OperationKind::MatrixMultiplication
+        | OperationKind::MatrixVectorMultiplication
+        | OperationKind::VectorMatrixMultiplication => match input_measurements {
+            [(Some(multiplier_size), multiplier_nnz), (Some(multiplicant_size), multiplicant_nnz)] =>
+            {
+                let shared_dimension = [
+                    (multiplier_size.1, multiplicant_size.0),
+                    (multiplier_size.0, multiplicant_size.0),
+                    (multiplier_size.1, multiplicant_size.1),
+                    (multiplier_size.0, multiplicant_size.1),
+                ]
+                .into_iter()
+                .find(|(multiplier_dimension, multiplicant_dimension)| {
+                    multiplier_dimension == multiplicant_dimension
+                })
+                .map(|(dimension, _)| dimension)
+                .unwrap_or(multiplier_size.1)
+                .max(1);
+                2.0 * (*multiplier_nnz as f64) * (*multiplicant_nnz as f64)
+                    / (shared_dimension as f64)
+            }
+            _ => 0.0,
+        },
+        OperationKind::KroneckerProduct => input_measurements
+            .iter()
+            .map(|(_, number_of_stored_elements)| *number_of_stored_elements as f64)
+            .product(),
+        OperationKind::ElementWiseAddition
+        | OperationKind::ElementWiseMultiplication
+        | OperationKind::ElementWiseUnion
+        | OperationKind::Apply
+        | OperationKind::Select
+        | OperationKind::Reduce => input_measurements
+            .iter()
+            .map(|(_, number_of_stored_elements)| *number_of_stored_elements as f64)
+            .sum(),
+        OperationKind::Transpose
+        | OperationKind::Extract
+        | OperationKind::Assign
+        | OperationKind::Build
+        | OperationKind::Serialize => 0.0,
+    }
+}
+
+impl Context {
+    pub fn is_operation_statistics_enabled(&self) -> bool {
+        self.operation_statistics.is_enabled.load(Ordering::Relaxed)
+    }
+
+    /// Collects statistics of all operations on collections of this Context, from all threads.
+    /// Collecting completes pending work of the arguments of each operation, like GrB_wait, to count their stored elements.
+    pub fn set_operation_statistics(&self, enabled: bool) {
+        self.operation_statistics
+            .is_enabled
+            .store(enabled, Ordering::Relaxed)
+    }
+
+    pub fn operation_statistics(
+        &self,
+    ) -> Result<OperationStatisticsSnapshot, SparseLinearAlgebraError> {
+        let statistics = self
+            .operation_statistics
+            .statistics
+            .lock()
+            .map_err(SystemError::from)?;
+        Ok(OperationStatisticsSnapshot {
+            statistics: statistics.clone(),
+        })
+    }
+
+    pub fn reset_operation_statistics(&self) -> Result<(), SparseLinearAlgebraError> {
+        self.operation_statistics
+            .statistics
+            .lock()
+            .map_err(SystemError::from)?
+            .clear();
+        Ok(())
+    }
+
+    /// Returns the statistics and resets them at once, so that no operation is missed between pipeline stages.
+    pub fn take_operation_statistics(
+        &self,
+    ) -> Result<OperationStatisticsSnapshot, SparseLinearAlgebraError> {
+        let mut statistics = self
+            .operation_statistics
+            .statistics
+            .lock()
+            .map_err(SystemError::from)?;
+        Ok(OperationStatisticsSnapshot {
+            statistics: std::mem::take(&mut *statistics),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::multiplication::{MatrixMultiplicationOperator, MultiplyMatrices};
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+    use crate::operators::semiring::PlusTimes;
+
+    #[test]
+    fn collect_operation_statistics() {
+        let context = Context::init_default().unwrap();
+        context.set_operation_statistics(true);
+        context.reset_operation_statistics().unwrap();
+
+        let matrix_size: Size = (2, 2).into();
+        let matrix = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            matrix_size,
+            MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 0, 1.0).into(),
+                (1, 1, 2.0).into(),
+            ]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<f32>::new(context.clone(), matrix_size).unwrap();
+        for _ in 0..2 {
+            MatrixMultiplicationOperator::new()
+                .apply(
+                    &matrix,
+                    &PlusTimes::<f32>::new(),
+                    &matrix,
+                    &Assignment::new(),
+                    &mut product,
+                    &SelectEntireMatrix::new(context.clone()),
+                    &OptionsForOperatorWithMatrixArguments::new_default(),
+                )
+                .unwrap();
+        }
+
+        let snapshot = context.take_operation_statistics().unwrap();
+        let multiplication = snapshot
+            .statistics(OperationKind::MatrixMultiplication)
+            .unwrap();
+        assert_eq!(multiplication.number_of_calls(), 2);
+        assert_eq!(multiplication.number_of_failed_calls(), 0);
+        assert_eq!(multiplication.number_of_stored_input_elements(), 8);
+        assert_eq!(multiplication.number_of_stored_output_elements(), 4);
+        assert_eq!(
+            multiplication.estimated_number_of_floating_point_operations(),
+            8.0
+        );
+        assert_eq!(
+            snapshot
+                .statistics(OperationKind::Build)
+                .unwrap()
+                .number_of_calls(),
+            1
+        );
+        assert_eq!(snapshot.total().number_of_calls(), 3);
+
+        assert!(context.operation_statistics().unwrap().is_empty());
+
+        context.set_operation_statistics(false);
+        MatrixMultiplicationOperator::new()
+            .apply(
+                &matrix,
+                &PlusTimes::<f32>::new(),
+                &matrix,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+        assert!(context.operation_statistics().unwrap().is_empty());
+    }
+
+    #[test]
+    fn estimate_multiplication_with_transposed_arguments() {
+        assert_eq!(
+            estimate_floating_point_operations(
+                OperationKind::MatrixMultiplication,
+                &[(Some((4, 2)), 4), (Some((3, 4)), 6)]
+            ),
+            12.0
+        );
+        assert_eq!(
+            estimate_floating_point_operations(
+                OperationKind::VectorMatrixMultiplication,
+                &[(Some((4, 1)), 2), (Some((4, 3)), 6)]
+            ),
+            6.0
+        );
+        assert_eq!(
+            estimate_floating_point_operations(OperationKind::Extract, &[(Some((4, 4)), 16)]),
+            0.0
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use std::time::Duration;
+
+use tracing::field::Empty;
+use tracing::{debug, info_span, warn, Span};
+
+use crate::property::Operand;
+
+use super::{Context, Operation, Status};
+
+// Spans and events use the target "graphblas", like the burble output.
+
+/// Shapes and numbers of stored elements are only retrieved when a subscriber is interested in the span.
+/// Retrieving the number of stored elements completes pending work, like GrB_wait.
+pub(super) fn operation_span(context: &Context, operation: &Operation, inputs: &[Operand]) -> Span {
+    let span = info_span!(
+        target: "graphblas",
+        "graphblas_operation",
+        operation = ?operation.kind(),
+        function = operation.graphblas_function(),
+        operator = Empty,
+        inputs = Empty,
+        output_number_of_stored_elements = Empty,
+        elapsed_microseconds = Empty,
+    );
+    if !span.is_disabled() {
+        if let Some(operator_name) = operation
+            .operator()
+            .and_then(|operator| operator.name(context))
+        {
+            span.record("operator", operator_name.as_str());
+        }
+        let inputs = inputs
+            .iter()
+            .map(describe_shape)
+            .collect::<Vec<String>>()
+            .join(", ");
+        span.record("inputs", inputs.as_str());
+    }
+    span
+}
+
+pub(super) fn record_operation_outcome(span: &Span, output: Option<Operand>, elapsed: Duration) {
+    if span.is_disabled() {
+        return;
+    }
+    if let Some(number_of_stored_elements) =
+        output.and_then(|output| output.number_of_stored_elements())
+    {
+        span.record(
+            "output_number_of_stored_elements",
+            number_of_stored_elements,
+        );
+    }
+    span.record("elapsed_microseconds", elapsed.as_micros() as u64);
+}
+
+/// NoValue and IteratorExhausted are expected outcomes, not failures.
+pub(super) fn trace_unsuccessful_status(status: &Status, detailed_error_information: &str) {
+    match status {
+        Status::NoValue | Status::IteratorExhausted => debug!(
+            target: "graphblas",
+            status = ?status,
+            "{}",
+            detailed_error_information
+        ),
+        _ => warn!(
+            target: "graphblas",
+            status = ?status,
+            "{}",
+            detailed_error_information
+        ),
+    }
+}
+
+fn describe_shape(operand: &Operand) -> String {
+    let shape = match (operand, operand.size()) {
+        (Operand::Vector(_), Some((length, _))) => length.to_string(),
+        (_, Some((number_of_rows, number_of_columns))) => {
+            format!("{}x{}", number_of_rows, number_of_columns)
+        }
+        (_, None) => String::from("?"),
+    };
+    match operand.number_of_stored_elements() {
+        Some(number_of_stored_elements) => format!("{} (nnz {})", shape, number_of_stored_elements),
+        None => format!("{} (nnz ?)", shape),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::{Arc, Mutex};
+
+    use tracing::span::{Attributes, Record};
+    use tracing::subscriber::with_default;
+    use tracing::{Event, Id, Metadata, Subscriber};
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::multiplication::{MatrixMultiplicationOperator, MultiplyMatrices};
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+    use crate::operators::semiring::PlusTimes;
+
+    #[derive(Default)]
+    struct RecordedFields(Mutex<Vec<String>>);
+
+    impl tracing::field::Visit for &RecordedFields {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{}={:?}", field.name(), value));
+        }
+    }
+
+    struct RecordingSubscriber(Arc<RecordedFields>);
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            span.record(&mut self.0.as_ref());
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, values: &Record<'_>) {
+            values.record(&mut self.0.as_ref());
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut self.0.as_ref());
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn trace_matrix_multiplication() {
+        let context = Context::init_default().unwrap();
+
+        let matrix = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            (2, 3).into(),
+            MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 0, 1.0).into(),
+                (1, 2, 2.0).into(),
+            ]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<f32>::new(context.clone(), Size::new(2, 2)).unwrap();
+
+        let fields = Arc::new(RecordedFields::default());
+        let multiplication_result = with_default(RecordingSubscriber(fields.clone()), || {
+            MatrixMultiplicationOperator::new().apply(
+                &matrix,
+                &PlusTimes::<f32>::new(),
+                &matrix,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+        });
+        assert!(multiplication_result.is_err());
+
+        let fields = fields.0.lock().unwrap();
+        assert!(fields.contains(&String::from("operation=MatrixMultiplication")));
+        assert!(fields.contains(&String::from("function=\"GrB_mxm\"")));
+        assert!(fields.contains(&String::from("inputs=\"2x3 (nnz 2), 2x3 (nnz 2)\"")));
+        assert!(fields.contains(&String::from("status=DimensionMismatch")));
+        assert!(fields
+            .iter()
+            .any(|field| field.starts_with("elapsed_microseconds=")));
+    }
+}
@@ -1,14 +1,30 @@
 // Look here for an example on how to implement error types: https://doc.rust-lang.org/src/std/io/error.rs.html#42
 use std::error;
 use std::fmt;
+use std::str::Utf8Error;
 // use std::io;
 use std::error::Error;
 
+use suitesparse_graphblas_sys::GrB_Info;
+
+use crate::collections::sparse_matrix::{GetMatrixDimensions, Size};
+use crate::context::OperationKind;
+use crate::index::ElementIndex;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct GraphblasError {
     error_type: GraphblasErrorType,
     explanation: String,
-    // source: Option<Box<(dyn error::Error)>>,
+    graphblas_info: Option<GrB_Info>,
+    // Boxed to keep errors small, they are returned by most functions.
+    failed_operation: Option<Box<FailedOperation>>,
+    source: Option<GraphblasErrorSource>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphblasErrorSource {
+    /// GraphBLAS returned an error message that is not valid UTF-8
+    ErrorMessageEncoding(Utf8Error),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -38,33 +54,69 @@ impl GraphblasError {
         Self {
             error_type: error_type,
             explanation: explanation,
+            graphblas_info: None,
+            failed_operation: None,
+            source: None,
+        }
+    }
+
+    pub(crate) fn new_with_details(
+        error_type: GraphblasErrorType,
+        explanation: String,
+        graphblas_info: GrB_Info,
+        failed_operation: Option<FailedOperation>,
+        source: Option<GraphblasErrorSource>,
+    ) -> Self {
+        Self {
+            error_type,
+            explanation,
+            graphblas_info: Some(graphblas_info),
+            failed_operation: failed_operation.map(Box::new),
+            source,
         }
     }
 
+    pub(crate) fn with_failed_operation(mut self, failed_operation: FailedOperation) -> Self {
+        self.failed_operation = Some(Box::new(failed_operation));
+        self
+    }
+
     pub fn error_type(&self) -> GraphblasErrorType {
         self.error_type.clone()
     }
+    /// Includes the error message of GraphBLAS, if it has one.
     pub fn explanation(&self) -> String {
         self.explanation.clone()
     }
+    /// The GrB_Info returned by GraphBLAS. None if the error was not returned by GraphBLAS.
+    pub fn graphblas_info(&self) -> Option<GrB_Info> {
+        self.graphblas_info
+    }
+    /// None if the error did not occur in an operation on collections.
+    pub fn failed_operation(&self) -> Option<&FailedOperation> {
+        self.failed_operation.as_deref()
+    }
 }
 
 impl error::Error for GraphblasError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
-        match &self.error_type {
-            // ErrorType::IO(error) => Some(error),
-            _ => None,
+        match &self.source {
+            Some(GraphblasErrorSource::ErrorMessageEncoding(error)) => Some(error),
+            None => None,
         }
     }
 }
 
 impl fmt::Display for GraphblasError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // REVIEW: is this match useful?
-        match &self.error_type {
-            // ErrorType::IO(_err) => writeln!(f, "Context:\n{}", &self.context)?,
-            _ => writeln!(f, "Explanation:\n{}", &self.explanation)?,
-        };
+        writeln!(f, "Explanation:\n{}", &self.explanation)?;
+
+        if let Some(failed_operation) = &self.failed_operation {
+            writeln!(f, "Failed operation:\n{}", failed_operation)?;
+        }
+        if let Some(graphblas_info) = self.graphblas_info {
+            writeln!(f, "GrB_Info: {}", graphblas_info)?;
+        }
 
         match &self.source() {
             Some(err) => writeln!(f, "Source error:\n{}", err)?,
@@ -73,3 +125,139 @@ impl fmt::Display for GraphblasError {
         Ok(())
     }
 }
+
+/// The operation that failed to execute, with its arguments as they were when the operation failed.
+/// Operation builders report the operation they were about to execute when its dimensions do not match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailedOperation {
+    kind: OperationKind,
+    graphblas_function: &'static str,
+    operator_name: Option<String>,
+    arguments: Vec<OperationArgument>,
+}
+
+impl FailedOperation {
+    pub(crate) fn new(
+        kind: OperationKind,
+        graphblas_function: &'static str,
+        operator_name: Option<String>,
+        arguments: Vec<OperationArgument>,
+    ) -> Self {
+        Self {
+            kind,
+            graphblas_function,
+            operator_name,
+            arguments,
+        }
+    }
+
+    pub fn kind(&self) -> OperationKind {
+        self.kind
+    }
+    pub fn graphblas_function(&self) -> &'static str {
+        self.graphblas_function
+    }
+    /// None if the operation has no operator, or the operator has no name.
+    pub fn operator_name(&self) -> Option<&str> {
+        self.operator_name.as_deref()
+    }
+    /// The output comes first, followed by the inputs in the order of the operation.
+    /// Masks and accumulators are not included.
+    pub fn arguments(&self) -> &[OperationArgument] {
+        &self.arguments
+    }
+    pub fn argument(&self, role: ArgumentRole) -> Option<&OperationArgument> {
+        self.arguments.iter().find(|argument| argument.role == role)
+    }
+}
+
+impl fmt::Display for FailedOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({:?})", self.graphblas_function, self.kind)?;
+        if let Some(operator_name) = &self.operator_name {
+            write!(f, " with operator \"{}\"", operator_name)?;
+        }
+        let output = self.argument(ArgumentRole::Output);
+        if let Some(output) = output {
+            write!(f, "\nOutput: {}", output)?;
+        }
+        let inputs: Vec<String> = self
+            .arguments
+            .iter()
+            .filter(|argument| argument.role != ArgumentRole::Output)
+            .map(|argument| argument.to_string())
+            .collect();
+        if !inputs.is_empty() {
+            write!(f, "\nInputs: {}", inputs.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArgumentRole {
+    Output,
+    /// Counted from zero, in the order of the arguments of the operation
+    Input(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationArgument {
+    role: ArgumentRole,
+    name: Option<String>,
+    shape: Option<ArgumentShape>,
+}
+
+impl OperationArgument {
+    pub(crate) fn new(
+        role: ArgumentRole,
+        name: Option<String>,
+        shape: Option<ArgumentShape>,
+    ) -> Self {
+        Self { role, name, shape }
+    }
+
+    pub fn role(&self) -> ArgumentRole {
+        self.role
+    }
+    /// None if the collection has no name.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    /// None if the shape could not be retrieved.
+    pub fn shape(&self) -> Option<ArgumentShape> {
+        self.shape
+    }
+}
+
+impl fmt::Display for OperationArgument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "\"{}\"", name)?,
+            None => write!(f, "<unnamed>")?,
+        }
+        match &self.shape {
+            Some(shape) => write!(f, " ({})", shape),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArgumentShape {
+    Matrix(Size),
+    Vector(ElementIndex),
+    Scalar,
+}
+
+impl fmt::Display for ArgumentShape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgumentShape::Matrix(size) => {
+                write!(f, "{}x{} matrix", size.row_height(), size.column_width())
+            }
+            ArgumentShape::Vector(length) => write!(f, "vector of length {}", length),
+            ArgumentShape::Scalar => write!(f, "scalar"),
+        }
+    }
+}
@@ -4,7 +4,10 @@ mod other_error;
 mod sparse_linear_algebra_error;
 pub(crate) mod system_error;
 
-pub use graphblas_error::{GraphblasError, GraphblasErrorType};
+pub use graphblas_error::{
+    ArgumentRole, ArgumentShape, FailedOperation, GraphblasError, GraphblasErrorSource,
+    GraphblasErrorType, OperationArgument,
+};
 pub use logic_error::{LogicError, LogicErrorType};
 pub use other_error::{OtherError, OtherErrorType};
 pub use sparse_linear_algebra_error::{SparseLinearAlgebraError, SparseLinearAlgebraErrorType};
@@ -49,6 +49,18 @@ impl SparseLinearAlgebraError {
             }
         }
     }
+
+    /// The GraphblasError that caused this error, found by following the source chain.
+    pub fn graphblas_error(&self) -> Option<&GraphblasError> {
+        let mut source = self.source();
+        while let Some(error) = source {
+            if let Some(graphblas_error) = error.downcast_ref::<GraphblasError>() {
+                return Some(graphblas_error);
+            }
+            source = error.source();
+        }
+        None
+    }
 }
 
 impl fmt::Display for SparseLinearAlgebraError {
@@ -4,6 +4,7 @@ pub mod error;
 pub mod graphblas_bindings;
 pub mod index;
 pub mod operators;
+pub mod property;
 pub mod value_type;
 
 #[doc = include_str!("../README.md")]
@@ -25,7 +25,7 @@ use suitesparse_graphblas_sys::{
 
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::{MatrixMask, VectorMask};
@@ -33,6 +33,7 @@ use crate::operators::options::{
     GetOperatorOptions, GetOptionsForOperatorWithMatrixAsFirstArgument,
     GetOptionsForOperatorWithMatrixAsSecondArgument,
 };
+use crate::property::Operand;
 
 use crate::operators::binary_operator::BinaryOperator;
 use crate::value_type::utilities_to_implement_traits_for_all_value_types::implement_1_type_macro_for_all_value_types_and_4_typed_graphblas_functions_with_implementation_type;
@@ -118,7 +119,7 @@ macro_rules! implement_apply_binary_operator {
                 let context = product.context_ref();
                 let second_argument = second_argument.to_type()?;
 
-                context.call(
+                context.call_operation(
                     || unsafe {
                         $graphblas_function_1(
                             product.graphblas_vector_ptr(),
@@ -130,7 +131,10 @@ macro_rules! implement_apply_binary_operator {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Apply, stringify!($graphblas_function_1))
+                        .with_binary_operator(operator.graphblas_type()),
                     unsafe { &product.graphblas_vector_ptr() },
+                    &[Operand::vector(first_argument)],
                 )?;
 
                 Ok(())
@@ -149,7 +153,7 @@ macro_rules! implement_apply_binary_operator {
                 let context = product.context_ref();
                 let first_argument = first_argument.to_type()?;
 
-                context.call(
+                context.call_operation(
                     || unsafe {
                         $graphblas_function_2(
                             product.graphblas_vector_ptr(),
@@ -161,7 +165,10 @@ macro_rules! implement_apply_binary_operator {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Apply, stringify!($graphblas_function_2))
+                        .with_binary_operator(operator.graphblas_type()),
                     unsafe { &product.graphblas_vector_ptr() },
+                    &[Operand::vector(second_argument)],
                 )?;
 
                 Ok(())
@@ -180,7 +187,7 @@ macro_rules! implement_apply_binary_operator {
                 let context = product.context_ref();
                 let second_argument = second_argument.to_type()?;
 
-                context.call(
+                context.call_operation(
                     || unsafe {
                         $graphblas_function_3(
                             product.graphblas_matrix_ptr(),
@@ -192,7 +199,10 @@ macro_rules! implement_apply_binary_operator {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Apply, stringify!($graphblas_function_3))
+                        .with_binary_operator(operator.graphblas_type()),
                     unsafe { product.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(first_argument)],
                 )?;
 
                 Ok(())
@@ -211,7 +221,7 @@ macro_rules! implement_apply_binary_operator {
                 let context = product.context_ref();
                 let first_argument = first_argument.to_type()?;
 
-                context.call(
+                context.call_operation(
                     || unsafe {
                         $graphblas_function_4(
                             product.graphblas_matrix_ptr(),
@@ -223,7 +233,10 @@ macro_rules! implement_apply_binary_operator {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Apply, stringify!($graphblas_function_4))
+                        .with_binary_operator(operator.graphblas_type()),
                     unsafe { product.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(second_argument)],
                 )?;
 
                 Ok(())
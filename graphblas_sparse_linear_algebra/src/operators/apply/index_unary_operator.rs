@@ -12,12 +12,13 @@ use suitesparse_graphblas_sys::{
 
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::index_unary_operator::IndexUnaryOperator;
 use crate::operators::mask::{MatrixMask, VectorMask};
 use crate::operators::options::{GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument};
+use crate::property::Operand;
 
 use crate::value_type::utilities_to_implement_traits_for_all_value_types::implement_1_type_macro_for_all_value_types_and_2_typed_graphblas_functions_with_implementation_type;
 use crate::value_type::{ConvertScalar, ValueType};
@@ -80,7 +81,7 @@ macro_rules! implement_apply_index_binary_operator {
                 let context = product.context_ref();
                 let argument = argument.clone().to_type()?;
 
-                context.call(
+                context.call_operation(
                     || unsafe {
                         $graphblas_function_1(
                             product.graphblas_vector_ptr(),
@@ -92,7 +93,10 @@ macro_rules! implement_apply_index_binary_operator {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Apply, stringify!($graphblas_function_1))
+                        .with_index_unary_operator(operator.graphblas_type()),
                     unsafe { &product.graphblas_vector_ptr() },
+                    &[Operand::vector(vector)],
                 )?;
 
                 Ok(())
@@ -111,7 +115,7 @@ macro_rules! implement_apply_index_binary_operator {
                 let context = product.context_ref();
                 let argument = argument.clone().to_type()?;
 
-                context.call(
+                context.call_operation(
                     || unsafe {
                         $graphblas_function_2(
                             product.graphblas_matrix_ptr(),
@@ -123,7 +127,10 @@ macro_rules! implement_apply_index_binary_operator {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Apply, stringify!($graphblas_function_2))
+                        .with_index_unary_operator(operator.graphblas_type()),
                     unsafe { product.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix)],
                 )?;
 
                 Ok(())
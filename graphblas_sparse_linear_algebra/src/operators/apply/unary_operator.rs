@@ -1,11 +1,12 @@
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::{MatrixMask, VectorMask};
 use crate::operators::options::{GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument};
 use crate::operators::unary_operator::UnaryOperator;
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::{GrB_Matrix_apply, GrB_Vector_apply};
@@ -62,7 +63,7 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperator<EvaluationDomain> for Unary
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = argument.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Vector_apply(
                     product.graphblas_vector_ptr(),
@@ -73,7 +74,10 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperator<EvaluationDomain> for Unary
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::Apply, "GrB_Vector_apply")
+                .with_unary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_vector_ptr() },
+            &[Operand::vector(argument)],
         )?;
 
         Ok(())
@@ -90,7 +94,7 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperator<EvaluationDomain> for Unary
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = argument.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_apply(
                     product.graphblas_matrix_ptr(),
@@ -101,7 +105,10 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperator<EvaluationDomain> for Unary
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::Apply, "GrB_Matrix_apply")
+                .with_unary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_matrix_ptr() },
+            &[Operand::matrix(argument)],
         )?;
 
         Ok(())
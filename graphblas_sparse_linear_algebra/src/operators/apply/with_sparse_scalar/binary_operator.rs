@@ -6,7 +6,7 @@ use suitesparse_graphblas_sys::{
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_scalar::GetGraphblasSparseScalar;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::apply::BinaryOperatorApplier;
 use crate::operators::binary_operator::{AccumulatorBinaryOperator, BinaryOperator};
@@ -15,6 +15,7 @@ use crate::operators::options::{
     GetOperatorOptions, GetOptionsForOperatorWithMatrixAsFirstArgument,
     GetOptionsForOperatorWithMatrixAsSecondArgument,
 };
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 pub trait ApplyBinaryOperatorWithSparseScalar<EvaluationDomain>
@@ -81,7 +82,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Vector_apply_BinaryOp2nd_Scalar(
                     product.graphblas_vector_ptr(),
@@ -93,7 +94,13 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::Apply, "GrB_Vector_apply_BinaryOp2nd_Scalar")
+                .with_binary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_vector_ptr() },
+            &[
+                Operand::vector(first_argument),
+                Operand::scalar(second_argument),
+            ],
         )?;
 
         Ok(())
@@ -111,7 +118,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Vector_apply_BinaryOp1st_Scalar(
                     product.graphblas_vector_ptr(),
@@ -123,7 +130,13 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::Apply, "GrB_Vector_apply_BinaryOp1st_Scalar")
+                .with_binary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_vector_ptr() },
+            &[
+                Operand::scalar(first_argument),
+                Operand::vector(second_argument),
+            ],
         )?;
 
         Ok(())
@@ -141,7 +154,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_apply_BinaryOp2nd_Scalar(
                     product.graphblas_matrix_ptr(),
@@ -153,7 +166,13 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::Apply, "GrB_Matrix_apply_BinaryOp2nd_Scalar")
+                .with_binary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_matrix_ptr() },
+            &[
+                Operand::matrix(first_argument),
+                Operand::scalar(second_argument),
+            ],
         )?;
 
         Ok(())
@@ -171,7 +190,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_apply_BinaryOp1st_Scalar(
                     product.graphblas_matrix_ptr(),
@@ -183,7 +202,13 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::Apply, "GrB_Matrix_apply_BinaryOp1st_Scalar")
+                .with_binary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_matrix_ptr() },
+            &[
+                Operand::scalar(first_argument),
+                Operand::matrix(second_argument),
+            ],
         )?;
 
         Ok(())
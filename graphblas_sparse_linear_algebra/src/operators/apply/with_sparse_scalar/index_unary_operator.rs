@@ -1,13 +1,14 @@
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_scalar::GetGraphblasSparseScalar;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::apply::IndexUnaryOperatorApplier;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::index_unary_operator::IndexUnaryOperator;
 use crate::operators::mask::{MatrixMask, VectorMask};
 use crate::operators::options::GetOperatorOptions;
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::{GrB_Matrix_apply_IndexOp_Scalar, GrB_Vector_apply_IndexOp_Scalar};
@@ -54,7 +55,7 @@ impl<EvaluationDomain: ValueType> ApplyIndexUnaryOperatorWithSparseScalar<Evalua
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = argument.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Vector_apply_IndexOp_Scalar(
                     product.graphblas_vector_ptr(),
@@ -66,7 +67,10 @@ impl<EvaluationDomain: ValueType> ApplyIndexUnaryOperatorWithSparseScalar<Evalua
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::Apply, "GrB_Vector_apply_IndexOp_Scalar")
+                .with_index_unary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_vector_ptr() },
+            &[Operand::vector(vector), Operand::scalar(argument)],
         )?;
 
         Ok(())
@@ -84,7 +88,7 @@ impl<EvaluationDomain: ValueType> ApplyIndexUnaryOperatorWithSparseScalar<Evalua
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = argument.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_apply_IndexOp_Scalar(
                     product.graphblas_matrix_ptr(),
@@ -96,7 +100,10 @@ impl<EvaluationDomain: ValueType> ApplyIndexUnaryOperatorWithSparseScalar<Evalua
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::Apply, "GrB_Matrix_apply_IndexOp_Scalar")
+                .with_index_unary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_matrix_ptr() },
+            &[Operand::matrix(matrix), Operand::scalar(argument)],
         )?;
 
         Ok(())
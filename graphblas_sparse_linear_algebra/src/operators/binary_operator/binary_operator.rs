@@ -8,7 +8,7 @@ use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
     implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types,
     implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types,
 };
-use crate::value_type::ValueType;
+use crate::value_type::{FloatingPoint, GraphBlasIndexInteger, Integer, ValueType};
 
 pub trait AccumulatorBinaryOperator<T>
 where
@@ -24,15 +24,30 @@ where
     fn graphblas_type(&self) -> GrB_BinaryOp;
 }
 
+impl<T: ValueType, O: AccumulatorBinaryOperator<T> + ?Sized> AccumulatorBinaryOperator<T> for &O {
+    fn accumulator_graphblas_type(&self) -> GrB_BinaryOp {
+        (**self).accumulator_graphblas_type()
+    }
+}
+
+impl<T: ValueType, O: BinaryOperator<T> + ?Sized> BinaryOperator<T> for &O {
+    fn graphblas_type(&self) -> GrB_BinaryOp {
+        (**self).graphblas_type()
+    }
+}
+
 pub trait ReturnsBool {}
 
 macro_rules! implement_binary_operator {
     ($operator_name:ident, $graphblas_operator_trait_name:ident) => {
-        pub trait $graphblas_operator_trait_name<T: ValueType> {
+        implement_binary_operator!($operator_name, $graphblas_operator_trait_name, ValueType);
+    };
+    ($operator_name:ident, $graphblas_operator_trait_name:ident, $evaluation_domain_bound:ident) => {
+        pub trait $graphblas_operator_trait_name<T: $evaluation_domain_bound> {
             fn graphblas_type() -> GrB_BinaryOp;
         }
 
-        impl<T: ValueType + $graphblas_operator_trait_name<T>> BinaryOperator<T>
+        impl<T: $evaluation_domain_bound + $graphblas_operator_trait_name<T>> BinaryOperator<T>
             for $operator_name<T>
         {
             fn graphblas_type(&self) -> GrB_BinaryOp {
@@ -40,15 +55,15 @@ macro_rules! implement_binary_operator {
             }
         }
 
-        impl<T: ValueType + $graphblas_operator_trait_name<T>> AccumulatorBinaryOperator<T>
-            for $operator_name<T>
+        impl<T: $evaluation_domain_bound + $graphblas_operator_trait_name<T>>
+            AccumulatorBinaryOperator<T> for $operator_name<T>
         {
             fn accumulator_graphblas_type(&self) -> GrB_BinaryOp {
                 T::graphblas_type()
             }
         }
 
-        impl<T: ValueType> $operator_name<T> {
+        impl<T: $evaluation_domain_bound> $operator_name<T> {
             pub fn new() -> Self {
                 Self {
                     _evaluation_domain: PhantomData,
@@ -179,10 +194,13 @@ macro_rules! implement_binary_operator_for_boolean {
 
 macro_rules! define_binary_operator {
     ($identifier: ident) => {
+        define_binary_operator!($identifier, ValueType);
+    };
+    ($identifier: ident, $evaluation_domain_bound:ident) => {
         #[derive(Debug, Clone)]
         pub struct $identifier<T>
         where
-            T: ValueType,
+            T: $evaluation_domain_bound,
         {
             _evaluation_domain: PhantomData<T>,
         }
@@ -519,10 +537,11 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
 );
 
 // z = tan^{-1}(y/x)
-define_binary_operator!(FloatingPointFourQuadrantArcTangent);
+define_binary_operator!(FloatingPointFourQuadrantArcTangent, FloatingPoint);
 implement_binary_operator!(
     FloatingPointFourQuadrantArcTangent,
-    FloatingPointFourQuadrantArcTangentTyped
+    FloatingPointFourQuadrantArcTangentTyped,
+    FloatingPoint
 );
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_binary_operator,
@@ -531,8 +550,12 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
 );
 
 // z = sqrt(x^2 + y^2)
-define_binary_operator!(FloatingPointHypotenuse);
-implement_binary_operator!(FloatingPointHypotenuse, FloatingPointHypotenuseTyped);
+define_binary_operator!(FloatingPointHypotenuse, FloatingPoint);
+implement_binary_operator!(
+    FloatingPointHypotenuse,
+    FloatingPointHypotenuseTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_binary_operator,
     FloatingPointHypotenuseTyped,
@@ -541,8 +564,12 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
 
 // z = remainder(x,y)
 // Distance to multiple of y closest to x
-define_binary_operator!(FloatingPointRemainder);
-implement_binary_operator!(FloatingPointRemainder, FloatingPointRemainderTyped);
+define_binary_operator!(FloatingPointRemainder, FloatingPoint);
+implement_binary_operator!(
+    FloatingPointRemainder,
+    FloatingPointRemainderTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_binary_operator,
     FloatingPointRemainderTyped,
@@ -551,8 +578,12 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
 
 // z = fmod(x,y)
 // Distance to last full multiple of y smaller than or equal to x
-define_binary_operator!(FloatingPointModulus);
-implement_binary_operator!(FloatingPointModulus, FloatingPointModulusTyped);
+define_binary_operator!(FloatingPointModulus, FloatingPoint);
+implement_binary_operator!(
+    FloatingPointModulus,
+    FloatingPointModulusTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_binary_operator,
     FloatingPointModulusTyped,
@@ -561,8 +592,8 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
 
 // TODO: test for non-integer y
 // z = x*2^y
-define_binary_operator!(LDExp);
-implement_binary_operator!(LDExp, LDExpTyped);
+define_binary_operator!(LDExp, FloatingPoint);
+implement_binary_operator!(LDExp, LDExpTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_binary_operator,
     LDExpTyped,
@@ -570,10 +601,11 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
 );
 
 // z = copysign(x,y) => z~f(magnitude(x), sign(y))
-define_binary_operator!(FloatingPointFromMagnitudeAndSign);
+define_binary_operator!(FloatingPointFromMagnitudeAndSign, FloatingPoint);
 implement_binary_operator!(
     FloatingPointFromMagnitudeAndSign,
-    FloatingPointFromMagnitudeAndSignTyped
+    FloatingPointFromMagnitudeAndSignTyped,
+    FloatingPoint
 );
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_binary_operator,
@@ -583,26 +615,27 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
 
 // NOTE: bitwise operations on signed integers may behave differently between different compilers
 
-define_binary_operator!(BitWiseLogicalOr);
-implement_binary_operator!(BitWiseLogicalOr, BitWiseLogicalOrTyped);
+define_binary_operator!(BitWiseLogicalOr, Integer);
+implement_binary_operator!(BitWiseLogicalOr, BitWiseLogicalOrTyped, Integer);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types!(
     implement_typed_binary_operator,
     BitWiseLogicalOrTyped,
     GrB_BOR
 );
 
-define_binary_operator!(BitWiseLogicalAnd);
-implement_binary_operator!(BitWiseLogicalAnd, BitWiseLogicalAndTyped);
+define_binary_operator!(BitWiseLogicalAnd, Integer);
+implement_binary_operator!(BitWiseLogicalAnd, BitWiseLogicalAndTyped, Integer);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types!(
     implement_typed_binary_operator,
     BitWiseLogicalAndTyped,
     GrB_BAND
 );
 
-define_binary_operator!(BitWiseLogicalExclusiveNotOr);
+define_binary_operator!(BitWiseLogicalExclusiveNotOr, Integer);
 implement_binary_operator!(
     BitWiseLogicalExclusiveNotOr,
-    BitWiseLogicalExclusiveNotOrTyped
+    BitWiseLogicalExclusiveNotOrTyped,
+    Integer
 );
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types!(
     implement_typed_binary_operator,
@@ -610,32 +643,36 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_v
     GrB_BXNOR
 );
 
-define_binary_operator!(BitWiseLogicalExclusiveOr);
-implement_binary_operator!(BitWiseLogicalExclusiveOr, BitWiseLogicalExclusiveOrTyped);
+define_binary_operator!(BitWiseLogicalExclusiveOr, Integer);
+implement_binary_operator!(
+    BitWiseLogicalExclusiveOr,
+    BitWiseLogicalExclusiveOrTyped,
+    Integer
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types!(
     implement_typed_binary_operator,
     BitWiseLogicalExclusiveOrTyped,
     GrB_BXOR
 );
 
-define_binary_operator!(GetBit);
-implement_binary_operator!(GetBit, GetBitTyped);
+define_binary_operator!(GetBit, Integer);
+implement_binary_operator!(GetBit, GetBitTyped, Integer);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types!(
     implement_typed_binary_operator,
     GetBitTyped,
     GxB_BGET
 );
 
-define_binary_operator!(SetBit);
-implement_binary_operator!(SetBit, SetBitTyped);
+define_binary_operator!(SetBit, Integer);
+implement_binary_operator!(SetBit, SetBitTyped, Integer);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types!(
     implement_typed_binary_operator,
     SetBitTyped,
     GxB_BSET
 );
 
-define_binary_operator!(ClearBit);
-implement_binary_operator!(ClearBit, ClearBitTyped);
+define_binary_operator!(ClearBit, Integer);
+implement_binary_operator!(ClearBit, ClearBitTyped, Integer);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types!(
     implement_typed_binary_operator,
     ClearBitTyped,
@@ -643,46 +680,110 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_v
 );
 
 // TODO: consider restricting input to u8. This would improve performance and predictability
-define_binary_operator!(ShiftBit);
-implement_binary_operator!(ShiftBit, ShiftBitTyped);
+define_binary_operator!(ShiftBit, Integer);
+implement_binary_operator!(ShiftBit, ShiftBitTyped, Integer);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types!(
     implement_typed_binary_operator,
     ShiftBitTyped,
     GxB_BSHIFT
 );
 
-define_binary_operator!(RowIndexFirstArgument);
-implement_binary_operator!(RowIndexFirstArgument, RowIndexFirstArgumentTyped);
+define_binary_operator!(RowIndexFirstArgument, GraphBlasIndexInteger);
+implement_binary_operator!(
+    RowIndexFirstArgument,
+    RowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
     implement_typed_binary_operator,
     RowIndexFirstArgumentTyped,
     GxB_FIRSTI
 );
 
-define_binary_operator!(ColumnIndexFirstArgument);
-implement_binary_operator!(ColumnIndexFirstArgument, ColumnIndexFirstArgumentTyped);
+define_binary_operator!(ColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_binary_operator!(
+    ColumnIndexFirstArgument,
+    ColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
     implement_typed_binary_operator,
     ColumnIndexFirstArgumentTyped,
     GxB_FIRSTJ
 );
 
-define_binary_operator!(RowIndexSecondArgument);
-implement_binary_operator!(RowIndexSecondArgument, RowIndexSecondArgumentTyped);
+define_binary_operator!(RowIndexSecondArgument, GraphBlasIndexInteger);
+implement_binary_operator!(
+    RowIndexSecondArgument,
+    RowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
     implement_typed_binary_operator,
     RowIndexSecondArgumentTyped,
     GxB_SECONDI
 );
 
-define_binary_operator!(ColumnIndexSecondArgument);
-implement_binary_operator!(ColumnIndexSecondArgument, ColumnIndexSecondArgumentTyped);
+define_binary_operator!(ColumnIndexSecondArgument, GraphBlasIndexInteger);
+implement_binary_operator!(
+    ColumnIndexSecondArgument,
+    ColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
     implement_typed_binary_operator,
     ColumnIndexSecondArgumentTyped,
     GxB_SECONDJ
 );
 
+define_binary_operator!(OneBasedRowIndexFirstArgument, GraphBlasIndexInteger);
+implement_binary_operator!(
+    OneBasedRowIndexFirstArgument,
+    OneBasedRowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_binary_operator,
+    OneBasedRowIndexFirstArgumentTyped,
+    GxB_FIRSTI1
+);
+
+define_binary_operator!(OneBasedColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_binary_operator!(
+    OneBasedColumnIndexFirstArgument,
+    OneBasedColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_binary_operator,
+    OneBasedColumnIndexFirstArgumentTyped,
+    GxB_FIRSTJ1
+);
+
+define_binary_operator!(OneBasedRowIndexSecondArgument, GraphBlasIndexInteger);
+implement_binary_operator!(
+    OneBasedRowIndexSecondArgument,
+    OneBasedRowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_binary_operator,
+    OneBasedRowIndexSecondArgumentTyped,
+    GxB_SECONDI1
+);
+
+define_binary_operator!(OneBasedColumnIndexSecondArgument, GraphBlasIndexInteger);
+implement_binary_operator!(
+    OneBasedColumnIndexSecondArgument,
+    OneBasedColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_binary_operator,
+    OneBasedColumnIndexSecondArgumentTyped,
+    GxB_SECONDJ1
+);
+
 #[cfg(test)]
 mod tests {
     use crate::{
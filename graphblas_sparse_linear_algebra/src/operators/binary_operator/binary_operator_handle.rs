@@ -0,0 +1,42 @@
+use std::marker::PhantomData;
+
+use crate::graphblas_bindings::*;
+use crate::value_type::ValueType;
+
+use super::{AccumulatorBinaryOperator, BinaryOperator};
+
+/// A binary operator that is owned by another GraphBLAS object, e.g. the multiplication of a semiring.
+/// The handle borrows the owner, and is not freed when it is dropped.
+#[derive(Debug, Clone, Copy)]
+pub struct BinaryOperatorHandle<'a, T: ValueType> {
+    graphblas_operator: GrB_BinaryOp,
+    _value_type: PhantomData<T>,
+    _owner: PhantomData<&'a ()>,
+}
+
+// The handle does not provide mutable access to the GraphBLAS operator.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<'a, T: ValueType> Send for BinaryOperatorHandle<'a, T> {}
+unsafe impl<'a, T: ValueType> Sync for BinaryOperatorHandle<'a, T> {}
+
+impl<'a, T: ValueType> BinaryOperatorHandle<'a, T> {
+    pub(crate) fn new(graphblas_operator: GrB_BinaryOp) -> Self {
+        Self {
+            graphblas_operator,
+            _value_type: PhantomData,
+            _owner: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ValueType> BinaryOperator<T> for BinaryOperatorHandle<'a, T> {
+    fn graphblas_type(&self) -> GrB_BinaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<'a, T: ValueType> AccumulatorBinaryOperator<T> for BinaryOperatorHandle<'a, T> {
+    fn accumulator_graphblas_type(&self) -> GrB_BinaryOp {
+        self.graphblas_operator
+    }
+}
@@ -0,0 +1,83 @@
+use crate::context::{CallGraphBlasContext, Context};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::*;
+use crate::operators::user_defined_operator::from_c_char_buffer;
+use crate::value_type::ValueType;
+
+use super::BinaryOperator;
+
+/// Retrieves the names of the GraphBLAS types a binary operator z = f(x, y) is defined with.
+pub trait GetBinaryOperatorTypeNames<T: ValueType> {
+    fn first_input_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError>;
+    fn second_input_type_name(&self, context: &Context)
+        -> Result<String, SparseLinearAlgebraError>;
+    fn output_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType, O: BinaryOperator<T>> GetBinaryOperatorTypeNames<T> for O {
+    fn first_input_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError> {
+        type_name(context, self.graphblas_type(), GxB_BinaryOp_xtype_name)
+    }
+
+    fn second_input_type_name(
+        &self,
+        context: &Context,
+    ) -> Result<String, SparseLinearAlgebraError> {
+        type_name(context, self.graphblas_type(), GxB_BinaryOp_ytype_name)
+    }
+
+    fn output_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError> {
+        type_name(context, self.graphblas_type(), GxB_BinaryOp_ztype_name)
+    }
+}
+
+fn type_name(
+    context: &Context,
+    graphblas_operator: GrB_BinaryOp,
+    graphblas_function: unsafe extern "C" fn(*mut std::ffi::c_char, GrB_BinaryOp) -> GrB_Info,
+) -> Result<String, SparseLinearAlgebraError> {
+    let mut name = [0; GxB_MAX_NAME_LEN as usize];
+    context.call(
+        || unsafe { graphblas_function(name.as_mut_ptr(), graphblas_operator) },
+        &graphblas_operator,
+    )?;
+    from_c_char_buffer(&name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::binary_operator::{IsGreaterThan, Plus, UserDefinedBinaryOperator};
+
+    #[test]
+    fn type_names_of_built_in_binary_operators() {
+        let context = Context::init_default().unwrap();
+
+        let plus = Plus::<f64>::new();
+        assert_eq!(plus.first_input_type_name(&context).unwrap(), "double");
+        assert_eq!(plus.second_input_type_name(&context).unwrap(), "double");
+        assert_eq!(plus.output_type_name(&context).unwrap(), "double");
+
+        let is_greater_than = IsGreaterThan::<u32>::new();
+        assert_eq!(
+            is_greater_than.first_input_type_name(&context).unwrap(),
+            "uint32_t"
+        );
+        assert_eq!(is_greater_than.output_type_name(&context).unwrap(), "bool");
+    }
+
+    #[test]
+    fn type_names_of_user_defined_binary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let operator =
+            UserDefinedBinaryOperator::<f32>::from_function(context.clone(), |x: &f32, y: &f32| {
+                x.max(*y)
+            })
+            .unwrap();
+
+        assert_eq!(operator.first_input_type_name(&context).unwrap(), "float");
+        assert_eq!(operator.output_type_name(&context).unwrap(), "float");
+    }
+}
@@ -1,3 +1,9 @@
 mod binary_operator;
+mod binary_operator_handle;
+mod binary_operator_type_names;
+mod user_defined_binary_operator;
 
 pub use binary_operator::*;
+pub use binary_operator_handle::*;
+pub use binary_operator_type_names::*;
+pub use user_defined_binary_operator::*;
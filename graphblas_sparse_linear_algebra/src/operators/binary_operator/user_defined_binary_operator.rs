@@ -0,0 +1,346 @@
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{
+    GrB_BinaryOp, GrB_BinaryOp_free, GrB_BinaryOp_new, GxB_BinaryOp_new,
+};
+use crate::operators::user_defined_operator::{
+    check_function_is_zero_sized, to_c_string, zero_sized_function_instance,
+};
+use crate::value_type::ValueType;
+
+use super::{AccumulatorBinaryOperator, BinaryOperator, ReturnsBool};
+
+/// z = f(x,y), with f a user-defined function.
+/// The operator is freed when it is dropped.
+#[derive(Debug)]
+pub struct UserDefinedBinaryOperator<
+    EvaluationDomain: ValueType,
+    Output: ValueType = EvaluationDomain,
+> {
+    context: Arc<Context>,
+    graphblas_operator: GrB_BinaryOp,
+    _evaluation_domain: PhantomData<EvaluationDomain>,
+    _output: PhantomData<Output>,
+}
+
+// Implemented methods do not provide mutable access to the GraphBLAS operator.
+// Code review must consider that no mutable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<EvaluationDomain: ValueType, Output: ValueType> Send
+    for UserDefinedBinaryOperator<EvaluationDomain, Output>
+{
+}
+unsafe impl<EvaluationDomain: ValueType, Output: ValueType> Sync
+    for UserDefinedBinaryOperator<EvaluationDomain, Output>
+{
+}
+
+unsafe extern "C" fn binary_function_trampoline<X, Z, F>(
+    z: *mut c_void,
+    x: *const c_void,
+    y: *const c_void,
+) where
+    F: Fn(&X, &X) -> Z,
+{
+    let function = unsafe { zero_sized_function_instance::<F>() };
+    let x = unsafe { (x as *const X).read_unaligned() };
+    let y = unsafe { (y as *const X).read_unaligned() };
+    unsafe { (z as *mut Z).write_unaligned(function(&x, &y)) };
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType>
+    UserDefinedBinaryOperator<EvaluationDomain, Output>
+{
+    /// The function must be a function item or a closure that does not capture its environment.
+    pub fn from_function<F>(
+        context: Arc<Context>,
+        _function: F,
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        F: Fn(&EvaluationDomain, &EvaluationDomain) -> Output + Copy + 'static,
+    {
+        check_function_is_zero_sized::<F>()?;
+        unsafe {
+            Self::from_graphblas_function(
+                context,
+                binary_function_trampoline::<EvaluationDomain, Output, F>,
+            )
+        }
+    }
+
+    /// The function must be a function item or a closure that does not capture its environment.
+    /// The JIT compiles the C definition under the given name instead of calling the function.
+    pub fn from_function_with_definition<F>(
+        context: Arc<Context>,
+        _function: F,
+        name: &str,
+        definition: &str,
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        F: Fn(&EvaluationDomain, &EvaluationDomain) -> Output + Copy + 'static,
+    {
+        check_function_is_zero_sized::<F>()?;
+        unsafe {
+            Self::from_graphblas_function_with_definition(
+                context,
+                binary_function_trampoline::<EvaluationDomain, Output, F>,
+                name,
+                definition,
+            )
+        }
+    }
+
+    /// # Safety
+    /// The function must read values of type EvaluationDomain from x and y, and write a value of type Output to z.
+    pub unsafe fn from_graphblas_function(
+        context: Arc<Context>,
+        function: unsafe extern "C" fn(z: *mut c_void, x: *const c_void, y: *const c_void),
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_operator: MaybeUninit<GrB_BinaryOp> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GrB_BinaryOp_new(
+                graphblas_operator.as_mut_ptr(),
+                Some(function),
+                Output::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            _evaluation_domain: PhantomData,
+            _output: PhantomData,
+        })
+    }
+
+    /// # Safety
+    /// The function must read values of type EvaluationDomain from x and y, and write a value of type Output to z.
+    /// The C definition must be equivalent to the function.
+    pub unsafe fn from_graphblas_function_with_definition(
+        context: Arc<Context>,
+        function: unsafe extern "C" fn(z: *mut c_void, x: *const c_void, y: *const c_void),
+        name: &str,
+        definition: &str,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let name = to_c_string(name)?;
+        let definition = to_c_string(definition)?;
+        let mut graphblas_operator: MaybeUninit<GrB_BinaryOp> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GxB_BinaryOp_new(
+                graphblas_operator.as_mut_ptr(),
+                Some(function),
+                Output::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+                name.as_ptr(),
+                definition.as_ptr(),
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            _evaluation_domain: PhantomData,
+            _output: PhantomData,
+        })
+    }
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType> BinaryOperator<EvaluationDomain>
+    for UserDefinedBinaryOperator<EvaluationDomain, Output>
+{
+    fn graphblas_type(&self) -> GrB_BinaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType> AccumulatorBinaryOperator<EvaluationDomain>
+    for UserDefinedBinaryOperator<EvaluationDomain, Output>
+{
+    fn accumulator_graphblas_type(&self) -> GrB_BinaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<EvaluationDomain: ValueType> ReturnsBool
+    for UserDefinedBinaryOperator<EvaluationDomain, bool>
+{
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType> GetContext
+    for UserDefinedBinaryOperator<EvaluationDomain, Output>
+{
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType> Drop
+    for UserDefinedBinaryOperator<EvaluationDomain, Output>
+{
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_BinaryOp_free(&mut self.graphblas_operator)
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue, SortSparseVector,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::collections::Collection;
+    use crate::operators::element_wise_addition::{
+        ApplyElementWiseVectorAdditionBinaryOperator, ElementWiseVectorAdditionBinaryOperator,
+    };
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+
+    use crate::operators::binary_operator::{Assignment, First};
+
+    fn absolute_difference(x: &i32, y: &i32) -> i32 {
+        (x - y).abs()
+    }
+
+    unsafe extern "C" fn larger_magnitude(z: *mut c_void, x: *const c_void, y: *const c_void) {
+        let x = unsafe { *(x as *const f64) };
+        let y = unsafe { *(y as *const f64) };
+        unsafe { *(z as *mut f64) = if x.abs() >= y.abs() { x } else { y } };
+    }
+
+    #[test]
+    fn element_wise_addition_with_user_defined_binary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let length = 10;
+        let multiplier = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            length,
+            VectorElementList::from_element_vector(vec![(1, 5).into(), (2, 3).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            length,
+            VectorElementList::from_element_vector(vec![(1, 8).into(), (3, 4).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<i32>::new(context.clone(), length).unwrap();
+
+        let operator =
+            UserDefinedBinaryOperator::<i32>::from_function(context.clone(), absolute_difference)
+                .unwrap();
+
+        ElementWiseVectorAdditionBinaryOperator::new()
+            .apply(
+                &multiplier,
+                &operator,
+                &multiplicant,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.element_value_or_default(1).unwrap(), 3);
+        assert_eq!(product.element_value_or_default(2).unwrap(), 3);
+        assert_eq!(product.element_value_or_default(3).unwrap(), 4);
+
+        // use the operator as accumulator
+        ElementWiseVectorAdditionBinaryOperator::new()
+            .apply(
+                &multiplier,
+                &First::<i32>::new(),
+                &multiplicant,
+                &operator,
+                &mut product,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value_or_default(1).unwrap(), 2);
+        assert_eq!(product.element_value_or_default(2).unwrap(), 0);
+        assert_eq!(product.element_value_or_default(3).unwrap(), 0);
+    }
+
+    #[test]
+    fn reduce_duplicates_with_user_defined_binary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let operator = unsafe {
+            UserDefinedBinaryOperator::<f64>::from_graphblas_function(
+                context.clone(),
+                larger_magnitude,
+            )
+        }
+        .unwrap();
+
+        let vector = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            10,
+            VectorElementList::from_element_vector(vec![
+                (1, 2.0).into(),
+                (1, -3.0).into(),
+                (1, 1.0).into(),
+            ]),
+            &operator,
+        )
+        .unwrap();
+
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 1);
+        assert_eq!(vector.element_value_or_default(1).unwrap(), -3.0);
+    }
+
+    #[test]
+    fn sort_with_user_defined_comparator() {
+        let context = Context::init_default().unwrap();
+
+        let mut vector = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            10,
+            VectorElementList::from_element_vector(vec![
+                (1, -5).into(),
+                (2, 3).into(),
+                (4, 1).into(),
+            ]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        let has_smaller_magnitude = UserDefinedBinaryOperator::<i32, bool>::from_function(
+            context.clone(),
+            |x: &i32, y: &i32| x.abs() < y.abs(),
+        )
+        .unwrap();
+
+        vector.sort(&has_smaller_magnitude).unwrap();
+
+        assert_eq!(vector.element_value_or_default(0).unwrap(), 1);
+        assert_eq!(vector.element_value_or_default(1).unwrap(), 3);
+        assert_eq!(vector.element_value_or_default(2).unwrap(), -5);
+    }
+}
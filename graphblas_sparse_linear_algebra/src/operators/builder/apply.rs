@@ -0,0 +1,150 @@
+use crate::collections::sparse_matrix::operations::{sparse_matrix_size, GetSparseMatrixSize};
+use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
+use crate::collections::sparse_vector::operations::{sparse_vector_length, GetSparseVectorLength};
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{GetContext, Operation, OperationKind};
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{ApplyUnaryOperator, UnaryOperatorApplier};
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, Assignment};
+use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+use crate::operators::unary_operator::UnaryOperator;
+use crate::property::Operand;
+use crate::value_type::ValueType;
+
+use super::{
+    check_length, check_matrix_mask, check_size, check_vector_mask, transposed_if,
+    CheckedOperation, GetMatrixMaskSize, GetVectorMaskLength, OperationBuilder,
+};
+
+#[derive(Debug, Clone)]
+pub struct MatrixUnaryOperation<'a, T: ValueType, U> {
+    operator: U,
+    argument: &'a SparseMatrix<T>,
+    transpose_argument: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct VectorUnaryOperation<'a, T: ValueType, U> {
+    operator: U,
+    argument: &'a SparseVector<T>,
+}
+
+impl<T: ValueType> SparseMatrix<T> {
+    /// Applies a unary operator to each stored element.
+    pub fn apply<U: UnaryOperator<T>>(
+        &self,
+        operator: U,
+    ) -> OperationBuilder<MatrixUnaryOperation<'_, T, U>, SelectEntireMatrix, Assignment<T>> {
+        OperationBuilder::new(
+            MatrixUnaryOperation {
+                operator,
+                argument: self,
+                transpose_argument: false,
+            },
+            SelectEntireMatrix::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<T: ValueType> SparseVector<T> {
+    /// Applies a unary operator to each stored element.
+    pub fn apply<U: UnaryOperator<T>>(
+        &self,
+        operator: U,
+    ) -> OperationBuilder<VectorUnaryOperation<'_, T, U>, SelectEntireVector, Assignment<T>> {
+        OperationBuilder::new(
+            VectorUnaryOperation {
+                operator,
+                argument: self,
+            },
+            SelectEntireVector::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<'a, T: ValueType, U: UnaryOperator<T>, M, A>
+    OperationBuilder<MatrixUnaryOperation<'a, T, U>, M, A>
+{
+    pub fn transpose(mut self) -> Self {
+        self.operation.transpose_argument = true;
+        self
+    }
+
+    pub fn into(
+        self,
+        product: &mut impl GetGraphblasSparseMatrix,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        M: GetMatrixMaskSize,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.argument.context_ref(),
+            Operation::new(OperationKind::Apply, "GrB_Matrix_apply")
+                .with_unary_operator(operation.operator.graphblas_type()),
+            Operand::matrix(product),
+            vec![Operand::matrix(operation.argument)],
+        );
+        let argument_size = transposed_if(operation.argument.size()?, operation.transpose_argument);
+        check_size(
+            &checked_operation,
+            "product",
+            argument_size,
+            sparse_matrix_size(product)?,
+        )?;
+        check_matrix_mask(&checked_operation, &self.mask, argument_size)?;
+
+        ApplyUnaryOperator::<T>::apply_to_matrix(
+            &UnaryOperatorApplier::new(),
+            &operation.operator,
+            operation.argument,
+            &self.accumulator,
+            product,
+            &self.mask,
+            &self.options_for_operator_with_matrix_argument(operation.transpose_argument),
+        )
+    }
+}
+
+impl<'a, T: ValueType, U: UnaryOperator<T>, M, A>
+    OperationBuilder<VectorUnaryOperation<'a, T, U>, M, A>
+{
+    pub fn into(
+        self,
+        product: &mut impl GetGraphblasSparseVector,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        M: GetVectorMaskLength,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.argument.context_ref(),
+            Operation::new(OperationKind::Apply, "GrB_Vector_apply")
+                .with_unary_operator(operation.operator.graphblas_type()),
+            Operand::vector(product),
+            vec![Operand::vector(operation.argument)],
+        );
+        let length = operation.argument.length()?;
+        check_length(
+            &checked_operation,
+            "product",
+            length,
+            sparse_vector_length(product)?,
+        )?;
+        check_vector_mask(&checked_operation, &self.mask, length)?;
+
+        ApplyUnaryOperator::<T>::apply_to_vector(
+            &UnaryOperatorApplier::new(),
+            &operation.operator,
+            operation.argument,
+            &self.accumulator,
+            product,
+            &self.mask,
+            &self.operator_options(),
+        )
+    }
+}
@@ -0,0 +1,216 @@
+use crate::collections::sparse_matrix::operations::{sparse_matrix_size, GetSparseMatrixSize};
+use crate::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, GetMatrixDimensions, Size, SparseMatrix,
+};
+use crate::collections::sparse_vector::operations::{sparse_vector_length, GetSparseVectorLength};
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{GetContext, Operation, OperationKind};
+use crate::error::SparseLinearAlgebraError;
+use crate::index::ElementIndexSelector;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, Assignment};
+use crate::operators::insert::{
+    InsertMatrixIntoMatrix, InsertMatrixIntoMatrixOperator, InsertVectorIntoVector,
+    InsertVectorIntoVectorOperator,
+};
+use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+use crate::property::Operand;
+use crate::value_type::ValueType;
+
+use super::{
+    check_length, check_matrix_mask, check_size, check_vector_mask, CheckedOperation,
+    GetMatrixMaskSize, GetVectorMaskLength, OperationBuilder,
+};
+
+#[derive(Debug, Clone)]
+pub struct MatrixAssignment<'a, T: ValueType> {
+    matrix_to_insert: &'a SparseMatrix<T>,
+    rows_to_insert_into: &'a ElementIndexSelector<'a>,
+    columns_to_insert_into: &'a ElementIndexSelector<'a>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VectorAssignment<'a, T: ValueType> {
+    vector_to_insert: &'a SparseVector<T>,
+    indices_to_insert_into: &'a ElementIndexSelector<'a>,
+}
+
+impl<T: ValueType> SparseMatrix<T> {
+    /// Assigns this matrix to the selected rows and columns of the matrix passed to into().
+    /// The mask applies to the entire matrix that is assigned to.
+    pub fn assign<'a>(
+        &'a self,
+        rows_to_insert_into: &'a ElementIndexSelector<'a>,
+        columns_to_insert_into: &'a ElementIndexSelector<'a>,
+    ) -> OperationBuilder<MatrixAssignment<'a, T>, SelectEntireMatrix, Assignment<T>> {
+        OperationBuilder::new(
+            MatrixAssignment {
+                matrix_to_insert: self,
+                rows_to_insert_into,
+                columns_to_insert_into,
+            },
+            SelectEntireMatrix::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<T: ValueType> SparseVector<T> {
+    /// Assigns this vector to the selected elements of the vector passed to into().
+    /// The mask applies to the entire vector that is assigned to.
+    pub fn assign<'a>(
+        &'a self,
+        indices_to_insert_into: &'a ElementIndexSelector<'a>,
+    ) -> OperationBuilder<VectorAssignment<'a, T>, SelectEntireVector, Assignment<T>> {
+        OperationBuilder::new(
+            VectorAssignment {
+                vector_to_insert: self,
+                indices_to_insert_into,
+            },
+            SelectEntireVector::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<'a, T: ValueType, M, A> OperationBuilder<MatrixAssignment<'a, T>, M, A> {
+    pub fn into(
+        self,
+        matrix_to_insert_into: &mut impl GetGraphblasSparseMatrix,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        M: GetMatrixMaskSize,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.matrix_to_insert.context_ref(),
+            Operation::new(OperationKind::Assign, "GrB_Matrix_assign"),
+            Operand::matrix(matrix_to_insert_into),
+            vec![Operand::matrix(operation.matrix_to_insert)],
+        );
+        let target_size = sparse_matrix_size(matrix_to_insert_into)?;
+        let selection_size = Size::new(
+            operation
+                .rows_to_insert_into
+                .number_of_selected_elements(target_size.row_height())?,
+            operation
+                .columns_to_insert_into
+                .number_of_selected_elements(target_size.column_width())?,
+        );
+        check_size(
+            &checked_operation,
+            "matrix to insert",
+            selection_size,
+            operation.matrix_to_insert.size()?,
+        )?;
+        check_matrix_mask(&checked_operation, &self.mask, target_size)?;
+
+        InsertMatrixIntoMatrix::<T>::apply(
+            &InsertMatrixIntoMatrixOperator::new(),
+            matrix_to_insert_into,
+            operation.rows_to_insert_into,
+            operation.columns_to_insert_into,
+            operation.matrix_to_insert,
+            &self.accumulator,
+            &self.mask,
+            &self.options_for_operator_with_matrix_arguments(false, false),
+        )
+    }
+}
+
+impl<'a, T: ValueType, M, A> OperationBuilder<VectorAssignment<'a, T>, M, A> {
+    pub fn into(
+        self,
+        vector_to_insert_into: &mut impl GetGraphblasSparseVector,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        M: GetVectorMaskLength,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.vector_to_insert.context_ref(),
+            Operation::new(OperationKind::Assign, "GrB_Vector_assign"),
+            Operand::vector(vector_to_insert_into),
+            vec![Operand::vector(operation.vector_to_insert)],
+        );
+        let target_length = sparse_vector_length(vector_to_insert_into)?;
+        check_length(
+            &checked_operation,
+            "vector to insert",
+            operation
+                .indices_to_insert_into
+                .number_of_selected_elements(target_length)?,
+            operation.vector_to_insert.length()?,
+        )?;
+        check_vector_mask(&checked_operation, &self.mask, target_length)?;
+
+        InsertVectorIntoVector::<T>::apply(
+            &InsertVectorIntoVectorOperator::new(),
+            vector_to_insert_into,
+            operation.indices_to_insert_into,
+            operation.vector_to_insert,
+            &self.accumulator,
+            &self.mask,
+            &self.operator_options(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{First, Plus};
+
+    #[test]
+    fn extract_and_assign_with_builder() {
+        let context = Context::init_default().unwrap();
+        let a = SparseMatrix::<f64>::from_element_list(
+            context.clone(),
+            (3, 3).into(),
+            MatrixElementList::<f64>::from_element_vector(vec![
+                (0, 0, 1.0).into(),
+                (1, 2, 2.0).into(),
+                (2, 1, 3.0).into(),
+            ]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+
+        let rows = vec![1, 2];
+        let rows = ElementIndexSelector::Index(&rows);
+        let mut sub_matrix = SparseMatrix::<f64>::new(context.clone(), Size::new(2, 3)).unwrap();
+        a.extract(&rows, &ElementIndexSelector::All)
+            .into(&mut sub_matrix)
+            .unwrap();
+        assert_eq!(sub_matrix.element_value(0, 2).unwrap(), Some(2.0));
+        assert_eq!(sub_matrix.element_value(1, 1).unwrap(), Some(3.0));
+
+        let mut too_small = SparseMatrix::<f64>::new(context.clone(), Size::new(2, 2)).unwrap();
+        assert!(a
+            .extract(&rows, &ElementIndexSelector::All)
+            .into(&mut too_small)
+            .is_err());
+
+        let mut b = a.clone();
+        sub_matrix
+            .assign(&rows, &ElementIndexSelector::All)
+            .accumulate(&Plus::<f64>::new())
+            .into(&mut b)
+            .unwrap();
+        assert_eq!(b.element_value(0, 0).unwrap(), Some(1.0));
+        assert_eq!(b.element_value(1, 2).unwrap(), Some(4.0));
+        assert_eq!(b.element_value(2, 1).unwrap(), Some(6.0));
+
+        assert!(sub_matrix
+            .assign(&ElementIndexSelector::All, &ElementIndexSelector::All)
+            .into(&mut b)
+            .is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::collections::sparse_matrix::operations::sparse_matrix_size;
+use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, GetMatrixDimensions, Size};
+use crate::collections::sparse_vector::operations::sparse_vector_length;
+use crate::collections::sparse_vector::GetGraphblasSparseVector;
+use crate::context::{Context, Operation};
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::index::ElementIndex;
+use crate::operators::mask::{MatrixMask, SelectEntireMatrix, SelectEntireVector, VectorMask};
+use crate::property::Operand;
+
+/// Size of a mask, None if the mask selects the entire output.
+pub trait GetMatrixMaskSize: MatrixMask {
+    fn mask_size(&self) -> Result<Option<Size>, SparseLinearAlgebraError>;
+}
+
+impl GetMatrixMaskSize for SelectEntireMatrix {
+    fn mask_size(&self) -> Result<Option<Size>, SparseLinearAlgebraError> {
+        Ok(None)
+    }
+}
+
+impl<M: MatrixMask + GetGraphblasSparseMatrix> GetMatrixMaskSize for &M {
+    fn mask_size(&self) -> Result<Option<Size>, SparseLinearAlgebraError> {
+        Ok(Some(sparse_matrix_size(*self)?))
+    }
+}
+
+/// Length of a mask, None if the mask selects the entire output.
+pub trait GetVectorMaskLength: VectorMask {
+    fn mask_length(&self) -> Result<Option<ElementIndex>, SparseLinearAlgebraError>;
+}
+
+impl GetVectorMaskLength for SelectEntireVector {
+    fn mask_length(&self) -> Result<Option<ElementIndex>, SparseLinearAlgebraError> {
+        Ok(None)
+    }
+}
+
+impl<M: VectorMask + GetGraphblasSparseVector> GetVectorMaskLength for &M {
+    fn mask_length(&self) -> Result<Option<ElementIndex>, SparseLinearAlgebraError> {
+        Ok(Some(sparse_vector_length(*self)?))
+    }
+}
+
+pub(super) fn transposed_if(size: Size, transpose: bool) -> Size {
+    if transpose {
+        Size::new(size.column_width(), size.row_height())
+    } else {
+        size
+    }
+}
+
+/// The operation a builder is about to execute, to describe it like a failed GraphBLAS operation
+/// when the dimensions of its arguments do not match.
+pub(super) struct CheckedOperation<'c> {
+    context: &'c Context,
+    operation: Operation,
+    output: Operand,
+    inputs: Vec<Operand>,
+}
+
+impl<'c> CheckedOperation<'c> {
+    pub(super) fn new(
+        context: &'c Context,
+        operation: Operation,
+        output: Operand,
+        inputs: Vec<Operand>,
+    ) -> Self {
+        Self {
+            context,
+            operation,
+            output,
+            inputs,
+        }
+    }
+
+    fn dimension_mismatch(&self, explanation: String) -> SparseLinearAlgebraError {
+        GraphblasError::new(
+            GraphblasErrorType::DimensionMismatch,
+            format!("{}: {}", self.operation.graphblas_function(), explanation),
+        )
+        .with_failed_operation(self.operation.failed_operation(
+            self.context,
+            Some(self.output),
+            &self.inputs,
+        ))
+        .into()
+    }
+}
+
+pub(super) fn check_size(
+    operation: &CheckedOperation,
+    argument: &str,
+    expected: Size,
+    found: Size,
+) -> Result<(), SparseLinearAlgebraError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(operation.dimension_mismatch(format!(
+            "{} must have size {}x{}, found {}x{}",
+            argument,
+            expected.row_height(),
+            expected.column_width(),
+            found.row_height(),
+            found.column_width()
+        )))
+    }
+}
+
+pub(super) fn check_length(
+    operation: &CheckedOperation,
+    argument: &str,
+    expected: ElementIndex,
+    found: ElementIndex,
+) -> Result<(), SparseLinearAlgebraError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(operation.dimension_mismatch(format!(
+            "{} must have length {}, found {}",
+            argument, expected, found
+        )))
+    }
+}
+
+pub(super) fn check_matrix_mask(
+    operation: &CheckedOperation,
+    mask: &impl GetMatrixMaskSize,
+    output_size: Size,
+) -> Result<(), SparseLinearAlgebraError> {
+    match mask.mask_size()? {
+        Some(mask_size) => check_size(operation, "mask", output_size, mask_size),
+        None => Ok(()),
+    }
+}
+
+pub(super) fn check_vector_mask(
+    operation: &CheckedOperation,
+    mask: &impl GetVectorMaskLength,
+    output_length: ElementIndex,
+) -> Result<(), SparseLinearAlgebraError> {
+    match mask.mask_length()? {
+        Some(mask_length) => check_length(operation, "mask", output_length, mask_length),
+        None => Ok(()),
+    }
+}
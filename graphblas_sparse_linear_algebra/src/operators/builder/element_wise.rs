@@ -0,0 +1,416 @@
+use crate::collections::sparse_matrix::operations::{sparse_matrix_size, GetSparseMatrixSize};
+use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
+use crate::collections::sparse_vector::operations::{sparse_vector_length, GetSparseVectorLength};
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{GetContext, Operation, OperationKind};
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{
+    AccumulatorBinaryOperator, Assignment, BinaryOperator, Plus, Times,
+};
+use crate::operators::element_wise_addition::{
+    ApplyElementWiseMatrixAdditionBinaryOperator, ApplyElementWiseVectorAdditionBinaryOperator,
+    ElementWiseMatrixAdditionBinaryOperator, ElementWiseVectorAdditionBinaryOperator,
+};
+use crate::operators::element_wise_multiplication::{
+    ApplyElementWiseMatrixMultiplicationBinaryOperator,
+    ApplyElementWiseVectorMultiplicationBinaryOperator,
+    ElementWiseMatrixMultiplicationBinaryOperator, ElementWiseVectorMultiplicationBinaryOperator,
+};
+use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+use crate::property::Operand;
+use crate::value_type::ValueType;
+
+use super::{
+    check_length, check_matrix_mask, check_size, check_vector_mask, transposed_if,
+    CheckedOperation, GetMatrixMaskSize, GetVectorMaskLength, OperationBuilder,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ElementWiseOperationType {
+    /// Applies the operator on the union of the stored elements
+    Addition,
+    /// Applies the operator on the intersection of the stored elements
+    Multiplication,
+}
+
+impl ElementWiseOperationType {
+    fn matrix_operation(&self) -> Operation {
+        match self {
+            ElementWiseOperationType::Addition => Operation::new(
+                OperationKind::ElementWiseAddition,
+                "GrB_Matrix_eWiseAdd_BinaryOp",
+            ),
+            ElementWiseOperationType::Multiplication => Operation::new(
+                OperationKind::ElementWiseMultiplication,
+                "GrB_Matrix_eWiseMult_BinaryOp",
+            ),
+        }
+    }
+
+    fn vector_operation(&self) -> Operation {
+        match self {
+            ElementWiseOperationType::Addition => Operation::new(
+                OperationKind::ElementWiseAddition,
+                "GrB_Vector_eWiseAdd_BinaryOp",
+            ),
+            ElementWiseOperationType::Multiplication => Operation::new(
+                OperationKind::ElementWiseMultiplication,
+                "GrB_Vector_eWiseMult_BinaryOp",
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ElementWiseMatrixOperation<'a, T: ValueType, O> {
+    operation_type: ElementWiseOperationType,
+    left_argument: &'a SparseMatrix<T>,
+    operator: O,
+    right_argument: &'a SparseMatrix<T>,
+    transpose_left_argument: bool,
+    transpose_right_argument: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ElementWiseVectorOperation<'a, T: ValueType, O> {
+    operation_type: ElementWiseOperationType,
+    left_argument: &'a SparseVector<T>,
+    operator: O,
+    right_argument: &'a SparseVector<T>,
+}
+
+impl<T: ValueType> SparseMatrix<T> {
+    /// Element-wise operation on the union of the stored elements, with Plus unless another operator is set.
+    pub fn ewise_add<'a>(
+        &'a self,
+        right_argument: &'a SparseMatrix<T>,
+    ) -> OperationBuilder<
+        ElementWiseMatrixOperation<'a, T, Plus<T>>,
+        SelectEntireMatrix,
+        Assignment<T>,
+    > {
+        self.element_wise(
+            ElementWiseOperationType::Addition,
+            Plus::<T>::new(),
+            right_argument,
+        )
+    }
+
+    /// Element-wise operation on the intersection of the stored elements, with Times unless another operator is set.
+    pub fn ewise_mult<'a>(
+        &'a self,
+        right_argument: &'a SparseMatrix<T>,
+    ) -> OperationBuilder<
+        ElementWiseMatrixOperation<'a, T, Times<T>>,
+        SelectEntireMatrix,
+        Assignment<T>,
+    > {
+        self.element_wise(
+            ElementWiseOperationType::Multiplication,
+            Times::<T>::new(),
+            right_argument,
+        )
+    }
+
+    fn element_wise<'a, O>(
+        &'a self,
+        operation_type: ElementWiseOperationType,
+        operator: O,
+        right_argument: &'a SparseMatrix<T>,
+    ) -> OperationBuilder<ElementWiseMatrixOperation<'a, T, O>, SelectEntireMatrix, Assignment<T>>
+    {
+        OperationBuilder::new(
+            ElementWiseMatrixOperation {
+                operation_type,
+                left_argument: self,
+                operator,
+                right_argument,
+                transpose_left_argument: false,
+                transpose_right_argument: false,
+            },
+            SelectEntireMatrix::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<T: ValueType> SparseVector<T> {
+    /// Element-wise operation on the union of the stored elements, with Plus unless another operator is set.
+    pub fn ewise_add<'a>(
+        &'a self,
+        right_argument: &'a SparseVector<T>,
+    ) -> OperationBuilder<
+        ElementWiseVectorOperation<'a, T, Plus<T>>,
+        SelectEntireVector,
+        Assignment<T>,
+    > {
+        self.element_wise(
+            ElementWiseOperationType::Addition,
+            Plus::<T>::new(),
+            right_argument,
+        )
+    }
+
+    /// Element-wise operation on the intersection of the stored elements, with Times unless another operator is set.
+    pub fn ewise_mult<'a>(
+        &'a self,
+        right_argument: &'a SparseVector<T>,
+    ) -> OperationBuilder<
+        ElementWiseVectorOperation<'a, T, Times<T>>,
+        SelectEntireVector,
+        Assignment<T>,
+    > {
+        self.element_wise(
+            ElementWiseOperationType::Multiplication,
+            Times::<T>::new(),
+            right_argument,
+        )
+    }
+
+    fn element_wise<'a, O>(
+        &'a self,
+        operation_type: ElementWiseOperationType,
+        operator: O,
+        right_argument: &'a SparseVector<T>,
+    ) -> OperationBuilder<ElementWiseVectorOperation<'a, T, O>, SelectEntireVector, Assignment<T>>
+    {
+        OperationBuilder::new(
+            ElementWiseVectorOperation {
+                operation_type,
+                left_argument: self,
+                operator,
+                right_argument,
+            },
+            SelectEntireVector::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<'a, T: ValueType, O, M, A> OperationBuilder<ElementWiseMatrixOperation<'a, T, O>, M, A> {
+    pub fn operator<P: BinaryOperator<T>>(
+        self,
+        operator: P,
+    ) -> OperationBuilder<ElementWiseMatrixOperation<'a, T, P>, M, A> {
+        self.map_operation(|operation| ElementWiseMatrixOperation {
+            operation_type: operation.operation_type,
+            left_argument: operation.left_argument,
+            operator,
+            right_argument: operation.right_argument,
+            transpose_left_argument: operation.transpose_left_argument,
+            transpose_right_argument: operation.transpose_right_argument,
+        })
+    }
+
+    pub fn transpose_first(mut self) -> Self {
+        self.operation.transpose_left_argument = true;
+        self
+    }
+
+    pub fn transpose_second(mut self) -> Self {
+        self.operation.transpose_right_argument = true;
+        self
+    }
+
+    pub fn into(
+        self,
+        product: &mut impl GetGraphblasSparseMatrix,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        O: BinaryOperator<T>,
+        M: GetMatrixMaskSize,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.left_argument.context_ref(),
+            operation
+                .operation_type
+                .matrix_operation()
+                .with_binary_operator(operation.operator.graphblas_type()),
+            Operand::matrix(product),
+            vec![
+                Operand::matrix(operation.left_argument),
+                Operand::matrix(operation.right_argument),
+            ],
+        );
+        let left_argument_size = transposed_if(
+            operation.left_argument.size()?,
+            operation.transpose_left_argument,
+        );
+        let right_argument_size = transposed_if(
+            operation.right_argument.size()?,
+            operation.transpose_right_argument,
+        );
+        check_size(
+            &checked_operation,
+            "right argument",
+            left_argument_size,
+            right_argument_size,
+        )?;
+        check_size(
+            &checked_operation,
+            "product",
+            left_argument_size,
+            sparse_matrix_size(product)?,
+        )?;
+        check_matrix_mask(&checked_operation, &self.mask, left_argument_size)?;
+
+        let options = self.options_for_operator_with_matrix_arguments(
+            operation.transpose_left_argument,
+            operation.transpose_right_argument,
+        );
+        match operation.operation_type {
+            ElementWiseOperationType::Addition => {
+                ApplyElementWiseMatrixAdditionBinaryOperator::<T>::apply(
+                    &ElementWiseMatrixAdditionBinaryOperator::new(),
+                    operation.left_argument,
+                    &operation.operator,
+                    operation.right_argument,
+                    &self.accumulator,
+                    product,
+                    &self.mask,
+                    &options,
+                )
+            }
+            ElementWiseOperationType::Multiplication => {
+                ApplyElementWiseMatrixMultiplicationBinaryOperator::<T>::apply(
+                    &ElementWiseMatrixMultiplicationBinaryOperator::new(),
+                    operation.left_argument,
+                    &operation.operator,
+                    operation.right_argument,
+                    &self.accumulator,
+                    product,
+                    &self.mask,
+                    &options,
+                )
+            }
+        }
+    }
+}
+
+impl<'a, T: ValueType, O, M, A> OperationBuilder<ElementWiseVectorOperation<'a, T, O>, M, A> {
+    pub fn operator<P: BinaryOperator<T>>(
+        self,
+        operator: P,
+    ) -> OperationBuilder<ElementWiseVectorOperation<'a, T, P>, M, A> {
+        self.map_operation(|operation| ElementWiseVectorOperation {
+            operation_type: operation.operation_type,
+            left_argument: operation.left_argument,
+            operator,
+            right_argument: operation.right_argument,
+        })
+    }
+
+    pub fn into(
+        self,
+        product: &mut impl GetGraphblasSparseVector,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        O: BinaryOperator<T>,
+        M: GetVectorMaskLength,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.left_argument.context_ref(),
+            operation
+                .operation_type
+                .vector_operation()
+                .with_binary_operator(operation.operator.graphblas_type()),
+            Operand::vector(product),
+            vec![
+                Operand::vector(operation.left_argument),
+                Operand::vector(operation.right_argument),
+            ],
+        );
+        let length = operation.left_argument.length()?;
+        check_length(
+            &checked_operation,
+            "right argument",
+            length,
+            operation.right_argument.length()?,
+        )?;
+        check_length(
+            &checked_operation,
+            "product",
+            length,
+            sparse_vector_length(product)?,
+        )?;
+        check_vector_mask(&checked_operation, &self.mask, length)?;
+
+        let options = self.operator_options();
+        match operation.operation_type {
+            ElementWiseOperationType::Addition => {
+                ApplyElementWiseVectorAdditionBinaryOperator::<T>::apply(
+                    &ElementWiseVectorAdditionBinaryOperator::new(),
+                    operation.left_argument,
+                    &operation.operator,
+                    operation.right_argument,
+                    &self.accumulator,
+                    product,
+                    &self.mask,
+                    &options,
+                )
+            }
+            ElementWiseOperationType::Multiplication => {
+                ApplyElementWiseVectorMultiplicationBinaryOperator::<T>::apply(
+                    &ElementWiseVectorMultiplicationBinaryOperator::new(),
+                    operation.left_argument,
+                    &operation.operator,
+                    operation.right_argument,
+                    &self.accumulator,
+                    product,
+                    &self.mask,
+                    &options,
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{First, Max};
+
+    #[test]
+    fn element_wise_with_builder() {
+        let context = Context::init_default().unwrap();
+        let u = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::<u8>::from_element_vector(vec![(0, 1).into(), (2, 5).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let v = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::<u8>::from_element_vector(vec![(1, 2).into(), (2, 3).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let mut w = SparseVector::<u8>::new(context.clone(), 3).unwrap();
+
+        u.ewise_add(&v)
+            .operator(&Max::<u8>::new())
+            .into(&mut w)
+            .unwrap();
+        assert_eq!(w.element_value(1).unwrap(), Some(2));
+        assert_eq!(w.element_value(2).unwrap(), Some(5));
+
+        u.ewise_mult(&v).replace().into(&mut w).unwrap();
+        assert_eq!(w.element_value(1).unwrap(), None);
+        assert_eq!(w.element_value(2).unwrap(), Some(15));
+
+        let mut short = SparseVector::<u8>::new(context.clone(), 2).unwrap();
+        assert!(u.ewise_add(&v).into(&mut short).is_err());
+    }
+}
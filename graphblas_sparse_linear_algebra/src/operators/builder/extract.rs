@@ -0,0 +1,162 @@
+use crate::collections::sparse_matrix::operations::{sparse_matrix_size, GetSparseMatrixSize};
+use crate::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, GetMatrixDimensions, Size, SparseMatrix,
+};
+use crate::collections::sparse_vector::operations::{sparse_vector_length, GetSparseVectorLength};
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{GetContext, Operation, OperationKind};
+use crate::error::SparseLinearAlgebraError;
+use crate::index::ElementIndexSelector;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, Assignment};
+use crate::operators::extract::{
+    ExtractSubMatrix, ExtractSubVector, SubMatrixExtractor, SubVectorExtractor,
+};
+use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+use crate::property::Operand;
+use crate::value_type::ValueType;
+
+use super::{
+    check_length, check_matrix_mask, check_size, check_vector_mask, transposed_if,
+    CheckedOperation, GetMatrixMaskSize, GetVectorMaskLength, OperationBuilder,
+};
+
+#[derive(Debug, Clone)]
+pub struct SubMatrixExtraction<'a, T: ValueType> {
+    matrix: &'a SparseMatrix<T>,
+    rows_to_extract: &'a ElementIndexSelector<'a>,
+    columns_to_extract: &'a ElementIndexSelector<'a>,
+    transpose_matrix: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubVectorExtraction<'a, T: ValueType> {
+    vector: &'a SparseVector<T>,
+    indices_to_extract: &'a ElementIndexSelector<'a>,
+}
+
+impl<T: ValueType> SparseMatrix<T> {
+    /// Extracts the selected rows and columns into a sub-matrix.
+    pub fn extract<'a>(
+        &'a self,
+        rows_to_extract: &'a ElementIndexSelector<'a>,
+        columns_to_extract: &'a ElementIndexSelector<'a>,
+    ) -> OperationBuilder<SubMatrixExtraction<'a, T>, SelectEntireMatrix, Assignment<T>> {
+        OperationBuilder::new(
+            SubMatrixExtraction {
+                matrix: self,
+                rows_to_extract,
+                columns_to_extract,
+                transpose_matrix: false,
+            },
+            SelectEntireMatrix::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<T: ValueType> SparseVector<T> {
+    /// Extracts the selected elements into a sub-vector.
+    pub fn extract<'a>(
+        &'a self,
+        indices_to_extract: &'a ElementIndexSelector<'a>,
+    ) -> OperationBuilder<SubVectorExtraction<'a, T>, SelectEntireVector, Assignment<T>> {
+        OperationBuilder::new(
+            SubVectorExtraction {
+                vector: self,
+                indices_to_extract,
+            },
+            SelectEntireVector::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<'a, T: ValueType, M, A> OperationBuilder<SubMatrixExtraction<'a, T>, M, A> {
+    pub fn transpose(mut self) -> Self {
+        self.operation.transpose_matrix = true;
+        self
+    }
+
+    pub fn into(
+        self,
+        sub_matrix: &mut impl GetGraphblasSparseMatrix,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        M: GetMatrixMaskSize,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.matrix.context_ref(),
+            Operation::new(OperationKind::Extract, "GrB_Matrix_extract"),
+            Operand::matrix(sub_matrix),
+            vec![Operand::matrix(operation.matrix)],
+        );
+        let matrix_size = transposed_if(operation.matrix.size()?, operation.transpose_matrix);
+        let sub_matrix_size = Size::new(
+            operation
+                .rows_to_extract
+                .number_of_selected_elements(matrix_size.row_height())?,
+            operation
+                .columns_to_extract
+                .number_of_selected_elements(matrix_size.column_width())?,
+        );
+        check_size(
+            &checked_operation,
+            "sub-matrix",
+            sub_matrix_size,
+            sparse_matrix_size(sub_matrix)?,
+        )?;
+        check_matrix_mask(&checked_operation, &self.mask, sub_matrix_size)?;
+
+        ExtractSubMatrix::<T>::apply(
+            &SubMatrixExtractor::new(),
+            operation.matrix,
+            operation.rows_to_extract,
+            operation.columns_to_extract,
+            &self.accumulator,
+            sub_matrix,
+            &self.mask,
+            &self.options_for_operator_with_matrix_argument(operation.transpose_matrix),
+        )
+    }
+}
+
+impl<'a, T: ValueType, M, A> OperationBuilder<SubVectorExtraction<'a, T>, M, A> {
+    pub fn into(
+        self,
+        sub_vector: &mut impl GetGraphblasSparseVector,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        M: GetVectorMaskLength,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.vector.context_ref(),
+            Operation::new(OperationKind::Extract, "GrB_Vector_extract"),
+            Operand::vector(sub_vector),
+            vec![Operand::vector(operation.vector)],
+        );
+        let sub_vector_length = operation
+            .indices_to_extract
+            .number_of_selected_elements(operation.vector.length()?)?;
+        check_length(
+            &checked_operation,
+            "sub-vector",
+            sub_vector_length,
+            sparse_vector_length(sub_vector)?,
+        )?;
+        check_vector_mask(&checked_operation, &self.mask, sub_vector_length)?;
+
+        ExtractSubVector::<T>::apply(
+            &SubVectorExtractor::new(),
+            operation.vector,
+            operation.indices_to_extract,
+            &self.accumulator,
+            sub_vector,
+            &self.mask,
+            &self.operator_options(),
+        )
+    }
+}
@@ -0,0 +1,172 @@
+// Fluent builders for operations on sparse matrices and vectors,
+// e.g. a.mxm(&b).semiring(&MinPlus::new()).mask(&m).complement().into(&mut c).
+// Unset operators default to the conventional choice, the mask defaults to selecting the entire output,
+// the accumulator defaults to Assignment, and dimensions are validated before GraphBLAS is called.
+
+mod apply;
+mod assign;
+mod dimensions;
+mod element_wise;
+mod extract;
+mod multiplication;
+mod reduce;
+mod select;
+
+pub use apply::*;
+pub use assign::*;
+pub use dimensions::*;
+pub use element_wise::*;
+pub use extract::*;
+pub use multiplication::*;
+pub use reduce::*;
+pub use select::*;
+
+use crate::operators::options::{
+    OperatorOptions, OptionsForOperatorWithMatrixArgument, OptionsForOperatorWithMatrixArguments,
+    OptionsForOperatorWithMatrixAsFirstArgument, OptionsForOperatorWithMatrixAsSecondArgument,
+};
+
+/// Collects the arguments of an operation, the mask, the accumulator and the options.
+/// The operation is executed by calling into() on the builder.
+#[derive(Debug, Clone)]
+pub struct OperationBuilder<Operation, Mask, Accumulator> {
+    operation: Operation,
+    mask: Mask,
+    accumulator: Accumulator,
+    clear_output_before_use: bool,
+    use_mask_structure_of_stored_values_as_mask: bool,
+    use_mask_complement: bool,
+}
+
+impl<Operation, Mask, Accumulator> OperationBuilder<Operation, Mask, Accumulator> {
+    fn new(operation: Operation, mask: Mask, accumulator: Accumulator) -> Self {
+        Self {
+            operation,
+            mask,
+            accumulator,
+            clear_output_before_use: false,
+            use_mask_structure_of_stored_values_as_mask: false,
+            use_mask_complement: false,
+        }
+    }
+
+    /// Only elements of the output selected by the mask are written.
+    pub fn mask<NewMask>(self, mask: NewMask) -> OperationBuilder<Operation, NewMask, Accumulator> {
+        OperationBuilder {
+            operation: self.operation,
+            mask,
+            accumulator: self.accumulator,
+            clear_output_before_use: self.clear_output_before_use,
+            use_mask_structure_of_stored_values_as_mask: self
+                .use_mask_structure_of_stored_values_as_mask,
+            use_mask_complement: self.use_mask_complement,
+        }
+    }
+
+    /// Use the complement of the mask.
+    pub fn complement(mut self) -> Self {
+        self.use_mask_complement = true;
+        self
+    }
+
+    /// Use the structure of the stored elements of the mask, ignoring their values.
+    pub fn structure(mut self) -> Self {
+        self.use_mask_structure_of_stored_values_as_mask = true;
+        self
+    }
+
+    /// Combine the result with the existing elements of the output.
+    pub fn accumulate<NewAccumulator>(
+        self,
+        accumulator: NewAccumulator,
+    ) -> OperationBuilder<Operation, Mask, NewAccumulator> {
+        OperationBuilder {
+            operation: self.operation,
+            mask: self.mask,
+            accumulator,
+            clear_output_before_use: self.clear_output_before_use,
+            use_mask_structure_of_stored_values_as_mask: self
+                .use_mask_structure_of_stored_values_as_mask,
+            use_mask_complement: self.use_mask_complement,
+        }
+    }
+
+    /// Clear elements of the output that are not selected by the mask.
+    pub fn replace(mut self) -> Self {
+        self.clear_output_before_use = true;
+        self
+    }
+
+    fn map_operation<NewOperation>(
+        self,
+        map: impl FnOnce(Operation) -> NewOperation,
+    ) -> OperationBuilder<NewOperation, Mask, Accumulator> {
+        OperationBuilder {
+            operation: map(self.operation),
+            mask: self.mask,
+            accumulator: self.accumulator,
+            clear_output_before_use: self.clear_output_before_use,
+            use_mask_structure_of_stored_values_as_mask: self
+                .use_mask_structure_of_stored_values_as_mask,
+            use_mask_complement: self.use_mask_complement,
+        }
+    }
+
+    fn operator_options(&self) -> OperatorOptions {
+        OperatorOptions::new(
+            self.clear_output_before_use,
+            self.use_mask_structure_of_stored_values_as_mask,
+            self.use_mask_complement,
+        )
+    }
+
+    fn options_for_operator_with_matrix_argument(
+        &self,
+        transpose_matrix_argument: bool,
+    ) -> OptionsForOperatorWithMatrixArgument {
+        OptionsForOperatorWithMatrixArgument::new(
+            self.clear_output_before_use,
+            self.use_mask_structure_of_stored_values_as_mask,
+            self.use_mask_complement,
+            transpose_matrix_argument,
+        )
+    }
+
+    fn options_for_operator_with_matrix_arguments(
+        &self,
+        transpose_first_argument: bool,
+        transpose_second_argument: bool,
+    ) -> OptionsForOperatorWithMatrixArguments {
+        OptionsForOperatorWithMatrixArguments::new(
+            self.clear_output_before_use,
+            self.use_mask_structure_of_stored_values_as_mask,
+            self.use_mask_complement,
+            transpose_first_argument,
+            transpose_second_argument,
+        )
+    }
+
+    fn options_for_operator_with_matrix_as_first_argument(
+        &self,
+        transpose_matrix_argument: bool,
+    ) -> OptionsForOperatorWithMatrixAsFirstArgument {
+        OptionsForOperatorWithMatrixAsFirstArgument::new(
+            self.clear_output_before_use,
+            self.use_mask_structure_of_stored_values_as_mask,
+            self.use_mask_complement,
+            transpose_matrix_argument,
+        )
+    }
+
+    fn options_for_operator_with_matrix_as_second_argument(
+        &self,
+        transpose_matrix_argument: bool,
+    ) -> OptionsForOperatorWithMatrixAsSecondArgument {
+        OptionsForOperatorWithMatrixAsSecondArgument::new(
+            self.clear_output_before_use,
+            self.use_mask_structure_of_stored_values_as_mask,
+            self.use_mask_complement,
+            transpose_matrix_argument,
+        )
+    }
+}
@@ -0,0 +1,466 @@
+use crate::collections::sparse_matrix::operations::{sparse_matrix_size, GetSparseMatrixSize};
+use crate::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, GetMatrixDimensions, Size, SparseMatrix,
+};
+use crate::collections::sparse_vector::operations::{sparse_vector_length, GetSparseVectorLength};
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{GetContext, Operation, OperationKind};
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, Assignment};
+use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+use crate::operators::multiplication::{
+    MatrixMultiplicationOperator, MatrixVectorMultiplicationOperator, MultiplyMatrices,
+    MultiplyMatrixByVector, MultiplyVectorByMatrix, VectorMatrixMultiplicationOperator,
+};
+use crate::operators::semiring::{PlusTimes, Semiring};
+use crate::property::Operand;
+use crate::value_type::ValueType;
+
+use super::{
+    check_length, check_matrix_mask, check_size, check_vector_mask, transposed_if,
+    CheckedOperation, GetMatrixMaskSize, GetVectorMaskLength, OperationBuilder,
+};
+
+#[derive(Debug, Clone)]
+pub struct MatrixMultiplication<'a, T: ValueType, S> {
+    multiplier: &'a SparseMatrix<T>,
+    semiring: S,
+    multiplicant: &'a SparseMatrix<T>,
+    transpose_multiplier: bool,
+    transpose_multiplicant: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatrixVectorMultiplication<'a, T: ValueType, S> {
+    multiplier: &'a SparseMatrix<T>,
+    semiring: S,
+    multiplicant: &'a SparseVector<T>,
+    transpose_multiplier: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct VectorMatrixMultiplication<'a, T: ValueType, S> {
+    multiplier: &'a SparseVector<T>,
+    semiring: S,
+    multiplicant: &'a SparseMatrix<T>,
+    transpose_multiplicant: bool,
+}
+
+impl<T: ValueType> SparseMatrix<T> {
+    /// Matrix multiplication, with the PlusTimes semiring unless another semiring is set.
+    pub fn mxm<'a>(
+        &'a self,
+        multiplicant: &'a SparseMatrix<T>,
+    ) -> OperationBuilder<
+        MatrixMultiplication<'a, T, PlusTimes<T>>,
+        SelectEntireMatrix,
+        Assignment<T>,
+    > {
+        OperationBuilder::new(
+            MatrixMultiplication {
+                multiplier: self,
+                semiring: PlusTimes::<T>::new(),
+                multiplicant,
+                transpose_multiplier: false,
+                transpose_multiplicant: false,
+            },
+            SelectEntireMatrix::new(self.context()),
+            Assignment::new(),
+        )
+    }
+
+    /// Matrix-vector multiplication, with the PlusTimes semiring unless another semiring is set.
+    pub fn mxv<'a>(
+        &'a self,
+        multiplicant: &'a SparseVector<T>,
+    ) -> OperationBuilder<
+        MatrixVectorMultiplication<'a, T, PlusTimes<T>>,
+        SelectEntireVector,
+        Assignment<T>,
+    > {
+        OperationBuilder::new(
+            MatrixVectorMultiplication {
+                multiplier: self,
+                semiring: PlusTimes::<T>::new(),
+                multiplicant,
+                transpose_multiplier: false,
+            },
+            SelectEntireVector::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<T: ValueType> SparseVector<T> {
+    /// Vector-matrix multiplication, with the PlusTimes semiring unless another semiring is set.
+    pub fn vxm<'a>(
+        &'a self,
+        multiplicant: &'a SparseMatrix<T>,
+    ) -> OperationBuilder<
+        VectorMatrixMultiplication<'a, T, PlusTimes<T>>,
+        SelectEntireVector,
+        Assignment<T>,
+    > {
+        OperationBuilder::new(
+            VectorMatrixMultiplication {
+                multiplier: self,
+                semiring: PlusTimes::<T>::new(),
+                multiplicant,
+                transpose_multiplicant: false,
+            },
+            SelectEntireVector::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<'a, T: ValueType, S, M, A> OperationBuilder<MatrixMultiplication<'a, T, S>, M, A> {
+    pub fn semiring<R: Semiring<T>>(
+        self,
+        semiring: R,
+    ) -> OperationBuilder<MatrixMultiplication<'a, T, R>, M, A> {
+        self.map_operation(|operation| MatrixMultiplication {
+            multiplier: operation.multiplier,
+            semiring,
+            multiplicant: operation.multiplicant,
+            transpose_multiplier: operation.transpose_multiplier,
+            transpose_multiplicant: operation.transpose_multiplicant,
+        })
+    }
+
+    pub fn transpose_first(mut self) -> Self {
+        self.operation.transpose_multiplier = true;
+        self
+    }
+
+    pub fn transpose_second(mut self) -> Self {
+        self.operation.transpose_multiplicant = true;
+        self
+    }
+
+    pub fn into(
+        self,
+        product: &mut impl GetGraphblasSparseMatrix,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        S: Semiring<T>,
+        M: GetMatrixMaskSize,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.multiplier.context_ref(),
+            Operation::new(OperationKind::MatrixMultiplication, "GrB_mxm")
+                .with_semiring(operation.semiring.graphblas_type()),
+            Operand::matrix(product),
+            vec![
+                Operand::matrix(operation.multiplier),
+                Operand::matrix(operation.multiplicant),
+            ],
+        );
+        let multiplier_size =
+            transposed_if(operation.multiplier.size()?, operation.transpose_multiplier);
+        let multiplicant_size = transposed_if(
+            operation.multiplicant.size()?,
+            operation.transpose_multiplicant,
+        );
+        check_length(
+            &checked_operation,
+            "row height of the multiplicant",
+            multiplier_size.column_width(),
+            multiplicant_size.row_height(),
+        )?;
+        let product_size = Size::new(
+            multiplier_size.row_height(),
+            multiplicant_size.column_width(),
+        );
+        check_size(
+            &checked_operation,
+            "product",
+            product_size,
+            sparse_matrix_size(product)?,
+        )?;
+        check_matrix_mask(&checked_operation, &self.mask, product_size)?;
+
+        MultiplyMatrices::<T>::apply(
+            &MatrixMultiplicationOperator::new(),
+            operation.multiplier,
+            &operation.semiring,
+            operation.multiplicant,
+            &self.accumulator,
+            product,
+            &self.mask,
+            &self.options_for_operator_with_matrix_arguments(
+                operation.transpose_multiplier,
+                operation.transpose_multiplicant,
+            ),
+        )
+    }
+}
+
+impl<'a, T: ValueType, S, M, A> OperationBuilder<MatrixVectorMultiplication<'a, T, S>, M, A> {
+    pub fn semiring<R: Semiring<T>>(
+        self,
+        semiring: R,
+    ) -> OperationBuilder<MatrixVectorMultiplication<'a, T, R>, M, A> {
+        self.map_operation(|operation| MatrixVectorMultiplication {
+            multiplier: operation.multiplier,
+            semiring,
+            multiplicant: operation.multiplicant,
+            transpose_multiplier: operation.transpose_multiplier,
+        })
+    }
+
+    pub fn transpose(mut self) -> Self {
+        self.operation.transpose_multiplier = true;
+        self
+    }
+
+    pub fn into(
+        self,
+        product: &mut impl GetGraphblasSparseVector,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        S: Semiring<T>,
+        M: GetVectorMaskLength,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.multiplier.context_ref(),
+            Operation::new(OperationKind::MatrixVectorMultiplication, "GrB_mxv")
+                .with_semiring(operation.semiring.graphblas_type()),
+            Operand::vector(product),
+            vec![
+                Operand::matrix(operation.multiplier),
+                Operand::vector(operation.multiplicant),
+            ],
+        );
+        let multiplier_size =
+            transposed_if(operation.multiplier.size()?, operation.transpose_multiplier);
+        check_length(
+            &checked_operation,
+            "multiplicant",
+            multiplier_size.column_width(),
+            operation.multiplicant.length()?,
+        )?;
+        check_length(
+            &checked_operation,
+            "product",
+            multiplier_size.row_height(),
+            sparse_vector_length(product)?,
+        )?;
+        check_vector_mask(&checked_operation, &self.mask, multiplier_size.row_height())?;
+
+        MultiplyMatrixByVector::<T>::apply(
+            &MatrixVectorMultiplicationOperator::new(),
+            operation.multiplier,
+            &operation.semiring,
+            operation.multiplicant,
+            &self.accumulator,
+            product,
+            &self.mask,
+            &self
+                .options_for_operator_with_matrix_as_first_argument(operation.transpose_multiplier),
+        )
+    }
+}
+
+impl<'a, T: ValueType, S, M, A> OperationBuilder<VectorMatrixMultiplication<'a, T, S>, M, A> {
+    pub fn semiring<R: Semiring<T>>(
+        self,
+        semiring: R,
+    ) -> OperationBuilder<VectorMatrixMultiplication<'a, T, R>, M, A> {
+        self.map_operation(|operation| VectorMatrixMultiplication {
+            multiplier: operation.multiplier,
+            semiring,
+            multiplicant: operation.multiplicant,
+            transpose_multiplicant: operation.transpose_multiplicant,
+        })
+    }
+
+    pub fn transpose(mut self) -> Self {
+        self.operation.transpose_multiplicant = true;
+        self
+    }
+
+    pub fn into(
+        self,
+        product: &mut impl GetGraphblasSparseVector,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        S: Semiring<T>,
+        M: GetVectorMaskLength,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.multiplicant.context_ref(),
+            Operation::new(OperationKind::VectorMatrixMultiplication, "GrB_vxm")
+                .with_semiring(operation.semiring.graphblas_type()),
+            Operand::vector(product),
+            vec![
+                Operand::vector(operation.multiplier),
+                Operand::matrix(operation.multiplicant),
+            ],
+        );
+        let multiplicant_size = transposed_if(
+            operation.multiplicant.size()?,
+            operation.transpose_multiplicant,
+        );
+        check_length(
+            &checked_operation,
+            "multiplier",
+            multiplicant_size.row_height(),
+            operation.multiplier.length()?,
+        )?;
+        check_length(
+            &checked_operation,
+            "product",
+            multiplicant_size.column_width(),
+            sparse_vector_length(product)?,
+        )?;
+        check_vector_mask(
+            &checked_operation,
+            &self.mask,
+            multiplicant_size.column_width(),
+        )?;
+
+        MultiplyVectorByMatrix::<T>::apply(
+            &VectorMatrixMultiplicationOperator::new(),
+            operation.multiplier,
+            &operation.semiring,
+            operation.multiplicant,
+            &self.accumulator,
+            product,
+            &self.mask,
+            &self.options_for_operator_with_matrix_as_second_argument(
+                operation.transpose_multiplicant,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::error::{
+        ArgumentRole, ArgumentShape, GraphblasErrorType, LogicErrorType,
+        SparseLinearAlgebraErrorType,
+    };
+    use crate::operators::binary_operator::{First, Min};
+    use crate::operators::semiring::MinPlus;
+
+    #[test]
+    fn multiply_with_builder() {
+        let context = Context::init_default().unwrap();
+        let a = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            (2, 2).into(),
+            MatrixElementList::<i32>::from_element_vector(vec![
+                (0, 0, 1).into(),
+                (0, 1, 4).into(),
+                (1, 1, 2).into(),
+            ]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let mask = SparseMatrix::<bool>::from_element_list(
+            context.clone(),
+            (2, 2).into(),
+            MatrixElementList::<bool>::from_element_vector(vec![(1, 0, true).into()]),
+            &First::<bool>::new(),
+        )
+        .unwrap();
+        let mut c = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            (2, 2).into(),
+            MatrixElementList::<i32>::from_element_vector(vec![(0, 1, 3).into(), (1, 0, 7).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        a.mxm(&a)
+            .semiring(&MinPlus::new())
+            .mask(&mask)
+            .complement()
+            .accumulate(&Min::new())
+            .replace()
+            .into(&mut c)
+            .unwrap();
+
+        assert_eq!(c.element_value(0, 0).unwrap(), Some(2));
+        assert_eq!(c.element_value(0, 1).unwrap(), Some(3));
+        assert_eq!(c.element_value(1, 1).unwrap(), Some(4));
+        assert_eq!(c.element_value(1, 0).unwrap(), None);
+
+        let v = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            2,
+            VectorElementList::<i32>::from_element_vector(vec![(1, 1).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let mut w = SparseVector::<i32>::new(context.clone(), 2).unwrap();
+        a.mxv(&v).into(&mut w).unwrap();
+        assert_eq!(w.element_value_or_default(0).unwrap(), 4);
+
+        v.vxm(&a).transpose().into(&mut w).unwrap();
+        assert_eq!(w.element_value_or_default(0).unwrap(), 4);
+        assert_eq!(w.element_value_or_default(1).unwrap(), 2);
+    }
+
+    #[test]
+    fn reject_mismatching_dimensions() {
+        let context = Context::init_default().unwrap();
+        let a = SparseMatrix::<f32>::new(context.clone(), Size::new(2, 3)).unwrap();
+        let mut product = SparseMatrix::<f32>::new(context.clone(), Size::new(2, 2)).unwrap();
+
+        let error = a.mxm(&a).into(&mut product).unwrap_err();
+        assert_eq!(
+            error.error_type(),
+            SparseLinearAlgebraErrorType::LogicErrorType(LogicErrorType::GraphBlas(
+                GraphblasErrorType::DimensionMismatch
+            ))
+        );
+        let failed_operation = error.graphblas_error().unwrap().failed_operation().unwrap();
+        assert_eq!(failed_operation.kind(), OperationKind::MatrixMultiplication);
+        assert_eq!(
+            failed_operation
+                .argument(ArgumentRole::Output)
+                .unwrap()
+                .shape(),
+            Some(ArgumentShape::Matrix(Size::new(2, 2)))
+        );
+        assert_eq!(
+            failed_operation
+                .argument(ArgumentRole::Input(1))
+                .unwrap()
+                .shape(),
+            Some(ArgumentShape::Matrix(Size::new(2, 3)))
+        );
+
+        a.mxm(&a).transpose_second().into(&mut product).unwrap();
+
+        let mut product = SparseMatrix::<f32>::new(context.clone(), Size::new(3, 3)).unwrap();
+        a.mxm(&a).transpose_first().into(&mut product).unwrap();
+
+        let mask = SparseMatrix::<bool>::new(context.clone(), Size::new(2, 2)).unwrap();
+        assert!(a
+            .mxm(&a)
+            .transpose_first()
+            .mask(&mask)
+            .into(&mut product)
+            .is_err());
+    }
+}
@@ -0,0 +1,219 @@
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::{GetMatrixDimensions, SparseMatrix};
+use crate::collections::sparse_vector::operations::sparse_vector_length;
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{GetContext, Operation, OperationKind};
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, Assignment};
+use crate::operators::mask::SelectEntireVector;
+use crate::operators::monoid::{self, Monoid};
+use crate::operators::reduce::{MonoidReducer, MonoidScalarReducer, MonoidVectorReducer};
+use crate::property::Operand;
+use crate::value_type::ValueType;
+
+use super::{
+    check_length, check_vector_mask, transposed_if, CheckedOperation, GetVectorMaskLength,
+    OperationBuilder,
+};
+
+#[derive(Debug, Clone)]
+pub struct MatrixReduction<'a, T: ValueType, R> {
+    monoid: R,
+    argument: &'a SparseMatrix<T>,
+    transpose_argument: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct VectorReduction<'a, T: ValueType, R> {
+    monoid: R,
+    argument: &'a SparseVector<T>,
+}
+
+impl<T: ValueType> SparseMatrix<T> {
+    /// Reduces each row, or the entire matrix, with the Plus monoid unless another monoid is set.
+    pub fn reduce(
+        &self,
+    ) -> OperationBuilder<MatrixReduction<'_, T, monoid::Plus<T>>, SelectEntireVector, Assignment<T>>
+    {
+        OperationBuilder::new(
+            MatrixReduction {
+                monoid: monoid::Plus::<T>::new(),
+                argument: self,
+                transpose_argument: false,
+            },
+            SelectEntireVector::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<T: ValueType> SparseVector<T> {
+    /// Reduces the vector to a scalar, with the Plus monoid unless another monoid is set.
+    pub fn reduce(
+        &self,
+    ) -> OperationBuilder<VectorReduction<'_, T, monoid::Plus<T>>, SelectEntireVector, Assignment<T>>
+    {
+        OperationBuilder::new(
+            VectorReduction {
+                monoid: monoid::Plus::<T>::new(),
+                argument: self,
+            },
+            SelectEntireVector::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<'a, T: ValueType, R, M, A> OperationBuilder<MatrixReduction<'a, T, R>, M, A> {
+    pub fn monoid<N: Monoid<T>>(
+        self,
+        monoid: N,
+    ) -> OperationBuilder<MatrixReduction<'a, T, N>, M, A> {
+        self.map_operation(|operation| MatrixReduction {
+            monoid,
+            argument: operation.argument,
+            transpose_argument: operation.transpose_argument,
+        })
+    }
+
+    /// Reduce each column instead of each row.
+    pub fn transpose(mut self) -> Self {
+        self.operation.transpose_argument = true;
+        self
+    }
+
+    pub fn into(
+        self,
+        product: &mut impl GetGraphblasSparseVector,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        R: Monoid<T>,
+        M: GetVectorMaskLength,
+        A: AccumulatorBinaryOperator<T>,
+    {
+        let operation = &self.operation;
+        let checked_operation = CheckedOperation::new(
+            operation.argument.context_ref(),
+            Operation::new(OperationKind::Reduce, "GrB_Matrix_reduce_Monoid")
+                .with_monoid(operation.monoid.graphblas_type()),
+            Operand::vector(product),
+            vec![Operand::matrix(operation.argument)],
+        );
+        let argument_size = transposed_if(operation.argument.size()?, operation.transpose_argument);
+        check_length(
+            &checked_operation,
+            "product",
+            argument_size.row_height(),
+            sparse_vector_length(product)?,
+        )?;
+        check_vector_mask(&checked_operation, &self.mask, argument_size.row_height())?;
+
+        MonoidVectorReducer::<T>::to_column_vector(
+            &MonoidReducer::new(),
+            &operation.monoid,
+            operation.argument,
+            &self.accumulator,
+            product,
+            &self.mask,
+            &self.options_for_operator_with_matrix_argument(operation.transpose_argument),
+        )
+    }
+}
+
+// GraphBLAS does not accept a mask when reducing to a scalar
+
+impl<'a, T: ValueType, R: Monoid<T>, A: AccumulatorBinaryOperator<T>>
+    OperationBuilder<MatrixReduction<'a, T, R>, SelectEntireVector, A>
+where
+    MonoidReducer: MonoidScalarReducer<T>,
+{
+    pub fn into_scalar(self, product: &mut T) -> Result<(), SparseLinearAlgebraError> {
+        MonoidScalarReducer::<T>::matrix_to_scalar(
+            &MonoidReducer::new(),
+            &self.operation.monoid,
+            self.operation.argument,
+            &self.accumulator,
+            product,
+            &self.options_for_operator_with_matrix_argument(false),
+        )
+    }
+}
+
+impl<'a, T: ValueType, R, A> OperationBuilder<VectorReduction<'a, T, R>, SelectEntireVector, A> {
+    pub fn monoid<N: Monoid<T>>(
+        self,
+        monoid: N,
+    ) -> OperationBuilder<VectorReduction<'a, T, N>, SelectEntireVector, A> {
+        self.map_operation(|operation| VectorReduction {
+            monoid,
+            argument: operation.argument,
+        })
+    }
+
+    pub fn into(self, product: &mut T) -> Result<(), SparseLinearAlgebraError>
+    where
+        R: Monoid<T>,
+        A: AccumulatorBinaryOperator<T>,
+        MonoidReducer: MonoidScalarReducer<T>,
+    {
+        MonoidScalarReducer::<T>::vector_to_scalar(
+            &MonoidReducer::new(),
+            &self.operation.monoid,
+            self.operation.argument,
+            &self.accumulator,
+            product,
+            &self.operator_options(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::collections::sparse_vector::operations::GetSparseVectorElementValue;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn reduce_with_builder() {
+        let context = Context::init_default().unwrap();
+        let a = SparseMatrix::<i64>::from_element_list(
+            context.clone(),
+            (2, 3).into(),
+            MatrixElementList::<i64>::from_element_vector(vec![
+                (0, 0, 1).into(),
+                (0, 2, 2).into(),
+                (1, 2, 4).into(),
+            ]),
+            &First::<i64>::new(),
+        )
+        .unwrap();
+
+        let mut row_sums = SparseVector::<i64>::new(context.clone(), 2).unwrap();
+        a.reduce().into(&mut row_sums).unwrap();
+        assert_eq!(row_sums.element_value_or_default(0).unwrap(), 3);
+        assert_eq!(row_sums.element_value_or_default(1).unwrap(), 4);
+
+        let mut column_maxima = SparseVector::<i64>::new(context.clone(), 3).unwrap();
+        a.reduce()
+            .monoid(&monoid::Max::<i64>::new())
+            .transpose()
+            .into(&mut column_maxima)
+            .unwrap();
+        assert_eq!(column_maxima.element_value(1).unwrap(), None);
+        assert_eq!(column_maxima.element_value_or_default(2).unwrap(), 4);
+
+        assert!(a.reduce().into(&mut column_maxima).is_err());
+
+        let mut total = 0;
+        a.reduce().into_scalar(&mut total).unwrap();
+        assert_eq!(total, 7);
+
+        let mut total = 0;
+        row_sums.reduce().into(&mut total).unwrap();
+        assert_eq!(total, 7);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::collections::sparse_matrix::operations::{sparse_matrix_size, GetSparseMatrixSize};
+use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
+use crate::collections::sparse_vector::operations::{sparse_vector_length, GetSparseVectorLength};
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{GetContext, Operation, OperationKind};
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, Assignment};
+use crate::operators::index_unary_operator::IndexUnaryOperator;
+use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+use crate::operators::select::{
+    MatrixSelector, SelectFromMatrix, SelectFromVector, VectorSelector,
+};
+use crate::property::Operand;
+use crate::value_type::ValueType;
+
+use super::{
+    check_length, check_matrix_mask, check_size, check_vector_mask, transposed_if,
+    CheckedOperation, GetMatrixMaskSize, GetVectorMaskLength, OperationBuilder,
+};
+
+#[derive(Debug, Clone)]
+pub struct MatrixSelection<'a, T: ValueType, I> {
+    selector: I,
+    selector_argument: T,
+    argument: &'a SparseMatrix<T>,
+    transpose_argument: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct VectorSelection<'a, T: ValueType, I> {
+    selector: I,
+    selector_argument: T,
+    argument: &'a SparseVector<T>,
+}
+
+impl<T: ValueType> SparseMatrix<T> {
+    /// Keeps the stored elements for which the selector returns true.
+    pub fn select<I: IndexUnaryOperator<T>>(
+        &self,
+        selector: I,
+        selector_argument: T,
+    ) -> OperationBuilder<MatrixSelection<'_, T, I>, SelectEntireMatrix, Assignment<T>> {
+        OperationBuilder::new(
+            MatrixSelection {
+                selector,
+                selector_argument,
+                argument: self,
+                transpose_argument: false,
+            },
+            SelectEntireMatrix::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<T: ValueType> SparseVector<T> {
+    /// Keeps the stored elements for which the selector returns true.
+    pub fn select<I: IndexUnaryOperator<T>>(
+        &self,
+        selector: I,
+        selector_argument: T,
+    ) -> OperationBuilder<VectorSelection<'_, T, I>, SelectEntireVector, Assignment<T>> {
+        OperationBuilder::new(
+            VectorSelection {
+                selector,
+                selector_argument,
+                argument: self,
+            },
+            SelectEntireVector::new(self.context()),
+            Assignment::new(),
+        )
+    }
+}
+
+impl<'a, T: ValueType, I: IndexUnaryOperator<T>, M, A>
+    OperationBuilder<MatrixSelection<'a, T, I>, M, A>
+{
+    pub fn transpose(mut self) -> Self {
+        self.operation.transpose_argument = true;
+        self
+    }
+
+    pub fn into(
+        self,
+        product: &mut impl GetGraphblasSparseMatrix,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        M: GetMatrixMaskSize,
+        A: AccumulatorBinaryOperator<T>,
+        MatrixSelector: SelectFromMatrix<T>,
+    {
+        let checked_operation = CheckedOperation::new(
+            self.operation.argument.context_ref(),
+            Operation::new(OperationKind::Select, "GrB_Matrix_select")
+                .with_index_unary_operator(self.operation.selector.graphblas_type()),
+            Operand::matrix(product),
+            vec![Operand::matrix(self.operation.argument)],
+        );
+        let argument_size = transposed_if(
+            self.operation.argument.size()?,
+            self.operation.transpose_argument,
+        );
+        check_size(
+            &checked_operation,
+            "product",
+            argument_size,
+            sparse_matrix_size(product)?,
+        )?;
+        check_matrix_mask(&checked_operation, &self.mask, argument_size)?;
+
+        let options =
+            self.options_for_operator_with_matrix_argument(self.operation.transpose_argument);
+        let operation = self.operation;
+        SelectFromMatrix::<T>::apply(
+            &MatrixSelector::new(),
+            &operation.selector,
+            operation.selector_argument,
+            operation.argument,
+            &self.accumulator,
+            product,
+            &self.mask,
+            &options,
+        )
+    }
+}
+
+impl<'a, T: ValueType, I: IndexUnaryOperator<T>, M, A>
+    OperationBuilder<VectorSelection<'a, T, I>, M, A>
+{
+    pub fn into(
+        self,
+        product: &mut impl GetGraphblasSparseVector,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        M: GetVectorMaskLength,
+        A: AccumulatorBinaryOperator<T>,
+        VectorSelector: SelectFromVector<T>,
+    {
+        let checked_operation = CheckedOperation::new(
+            self.operation.argument.context_ref(),
+            Operation::new(OperationKind::Select, "GrB_Vector_select")
+                .with_index_unary_operator(self.operation.selector.graphblas_type()),
+            Operand::vector(product),
+            vec![Operand::vector(self.operation.argument)],
+        );
+        let length = self.operation.argument.length()?;
+        check_length(
+            &checked_operation,
+            "product",
+            length,
+            sparse_vector_length(product)?,
+        )?;
+        check_vector_mask(&checked_operation, &self.mask, length)?;
+
+        let options = self.operator_options();
+        let operation = self.operation;
+        SelectFromVector::<T>::apply(
+            &VectorSelector::new(),
+            &operation.selector,
+            operation.selector_argument,
+            operation.argument,
+            &self.accumulator,
+            product,
+            &self.mask,
+            &options,
+        )
+    }
+}
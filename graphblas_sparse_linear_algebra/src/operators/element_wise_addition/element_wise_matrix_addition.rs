@@ -1,10 +1,11 @@
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::MatrixMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArguments;
 use crate::operators::{binary_operator::BinaryOperator, monoid::Monoid, semiring::Semiring};
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::{
@@ -54,7 +55,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionSemiring<Evaluat
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_eWiseAdd_Semiring(
                     product.graphblas_matrix_ptr(),
@@ -66,7 +67,13 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionSemiring<Evaluat
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseAddition,
+                "GrB_Matrix_eWiseAdd_Semiring",
+            )
+            .with_semiring(operator.graphblas_type()),
             unsafe { &product.graphblas_matrix_ptr() },
+            &[Operand::matrix(multiplier), Operand::matrix(multiplicant)],
         )?;
         Ok(())
     }
@@ -115,7 +122,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionMonoidOperator<E
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_eWiseAdd_Monoid(
                     product.graphblas_matrix_ptr(),
@@ -127,7 +134,13 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionMonoidOperator<E
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseAddition,
+                "GrB_Matrix_eWiseAdd_Monoid",
+            )
+            .with_monoid(operator.graphblas_type()),
             unsafe { &product.graphblas_matrix_ptr() },
+            &[Operand::matrix(multiplier), Operand::matrix(multiplicant)],
         )?;
 
         Ok(())
@@ -177,7 +190,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionBinaryOperator<E
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_eWiseAdd_BinaryOp(
                     product.graphblas_matrix_ptr(),
@@ -189,7 +202,13 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionBinaryOperator<E
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseAddition,
+                "GrB_Matrix_eWiseAdd_BinaryOp",
+            )
+            .with_binary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_matrix_ptr() },
+            &[Operand::matrix(multiplier), Operand::matrix(multiplicant)],
         )?;
 
         Ok(())
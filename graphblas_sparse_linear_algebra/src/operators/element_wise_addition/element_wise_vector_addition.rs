@@ -1,10 +1,11 @@
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOperatorOptions;
 use crate::operators::{binary_operator::BinaryOperator, monoid::Monoid, semiring::Semiring};
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::{
@@ -54,7 +55,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionSemiringOperator
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Vector_eWiseAdd_Semiring(
                     product.graphblas_vector_ptr(),
@@ -66,7 +67,13 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionSemiringOperator
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseAddition,
+                "GrB_Vector_eWiseAdd_Semiring",
+            )
+            .with_semiring(operator.graphblas_type()),
             unsafe { &product.graphblas_vector_ptr() },
+            &[Operand::vector(multiplier), Operand::vector(multiplicant)],
         )?;
 
         Ok(())
@@ -116,7 +123,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionMonoidOperator<E
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Vector_eWiseAdd_Monoid(
                     product.graphblas_vector_ptr(),
@@ -128,7 +135,13 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionMonoidOperator<E
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseAddition,
+                "GrB_Vector_eWiseAdd_Monoid",
+            )
+            .with_monoid(operator.graphblas_type()),
             unsafe { &product.graphblas_vector_ptr() },
+            &[Operand::vector(multiplier), Operand::vector(multiplicant)],
         )?;
 
         Ok(())
@@ -178,7 +191,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionBinaryOperator<E
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Vector_eWiseAdd_BinaryOp(
                     product.graphblas_vector_ptr(),
@@ -190,7 +203,13 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionBinaryOperator<E
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseAddition,
+                "GrB_Vector_eWiseAdd_BinaryOp",
+            )
+            .with_binary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_vector_ptr() },
+            &[Operand::vector(multiplier), Operand::vector(multiplicant)],
         )?;
 
         Ok(())
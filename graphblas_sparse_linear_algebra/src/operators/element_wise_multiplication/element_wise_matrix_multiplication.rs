@@ -1,10 +1,11 @@
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::MatrixMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArguments;
 use crate::operators::{binary_operator::BinaryOperator, monoid::Monoid, semiring::Semiring};
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::{
@@ -54,7 +55,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixMultiplicationSemiring<E
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_eWiseMult_Semiring(
                     product.graphblas_matrix_ptr(),
@@ -66,7 +67,13 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixMultiplicationSemiring<E
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseMultiplication,
+                "GrB_Matrix_eWiseMult_Semiring",
+            )
+            .with_semiring(operator.graphblas_type()),
             unsafe { &product.graphblas_matrix_ptr() },
+            &[Operand::matrix(multiplier), Operand::matrix(multiplicant)],
         )?;
         Ok(())
     }
@@ -116,7 +123,7 @@ impl<EvaluationDomain: ValueType>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_eWiseMult_Monoid(
                     product.graphblas_matrix_ptr(),
@@ -128,7 +135,13 @@ impl<EvaluationDomain: ValueType>
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseMultiplication,
+                "GrB_Matrix_eWiseMult_Monoid",
+            )
+            .with_monoid(operator.graphblas_type()),
             unsafe { &product.graphblas_matrix_ptr() },
+            &[Operand::matrix(multiplier), Operand::matrix(multiplicant)],
         )?;
 
         Ok(())
@@ -179,7 +192,7 @@ impl<EvaluationDomain: ValueType>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_eWiseMult_BinaryOp(
                     product.graphblas_matrix_ptr(),
@@ -191,7 +204,13 @@ impl<EvaluationDomain: ValueType>
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseMultiplication,
+                "GrB_Matrix_eWiseMult_BinaryOp",
+            )
+            .with_binary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_matrix_ptr() },
+            &[Operand::matrix(multiplier), Operand::matrix(multiplicant)],
         )?;
 
         Ok(())
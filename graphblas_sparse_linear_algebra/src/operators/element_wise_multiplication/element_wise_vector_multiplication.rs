@@ -1,10 +1,11 @@
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOperatorOptions;
 use crate::operators::{binary_operator::BinaryOperator, monoid::Monoid, semiring::Semiring};
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::{
@@ -55,7 +56,7 @@ impl<EvaluationDomain: ValueType>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Vector_eWiseMult_Semiring(
                     product.graphblas_vector_ptr(),
@@ -67,7 +68,13 @@ impl<EvaluationDomain: ValueType>
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseMultiplication,
+                "GrB_Vector_eWiseMult_Semiring",
+            )
+            .with_semiring(operator.graphblas_type()),
             unsafe { &product.graphblas_vector_ptr() },
+            &[Operand::vector(multiplier), Operand::vector(multiplicant)],
         )?;
 
         Ok(())
@@ -118,7 +125,7 @@ impl<EvaluationDomain: ValueType>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Vector_eWiseMult_Monoid(
                     product.graphblas_vector_ptr(),
@@ -130,7 +137,13 @@ impl<EvaluationDomain: ValueType>
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseMultiplication,
+                "GrB_Vector_eWiseMult_Monoid",
+            )
+            .with_monoid(operator.graphblas_type()),
             unsafe { &product.graphblas_vector_ptr() },
+            &[Operand::vector(multiplier), Operand::vector(multiplicant)],
         )?;
 
         Ok(())
@@ -181,7 +194,7 @@ impl<EvaluationDomain: ValueType>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Vector_eWiseMult_BinaryOp(
                     product.graphblas_vector_ptr(),
@@ -193,7 +206,13 @@ impl<EvaluationDomain: ValueType>
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::ElementWiseMultiplication,
+                "GrB_Vector_eWiseMult_BinaryOp",
+            )
+            .with_binary_operator(operator.graphblas_type()),
             unsafe { &product.graphblas_vector_ptr() },
+            &[Operand::vector(multiplier), Operand::vector(multiplicant)],
         )?;
 
         Ok(())
@@ -0,0 +1,224 @@
+use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
+use crate::collections::sparse_scalar::operations::{SetScalarValue, SetScalarValueTyped};
+use crate::collections::sparse_scalar::{GetGraphblasSparseScalar, SparseScalar};
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, BinaryOperator};
+use crate::operators::mask::MatrixMask;
+use crate::operators::options::GetOptionsForOperatorWithMatrixArguments;
+use crate::property::Operand;
+use crate::value_type::ValueType;
+
+use crate::graphblas_bindings::GxB_Matrix_eWiseUnion;
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator {}
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator {}
+
+/// Applies the operator to the union of the stored elements of the multiplier and the multiplicant.
+/// Where only one of them stores an element, the fill value replaces the missing element of the other.
+#[derive(Debug, Clone)]
+pub struct ElementWiseMatrixUnionBinaryOperator {}
+
+impl ElementWiseMatrixUnionBinaryOperator {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for ElementWiseMatrixUnionBinaryOperator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait ApplyElementWiseMatrixUnionBinaryOperator<EvaluationDomain: ValueType> {
+    fn apply(
+        &self,
+        multiplier: &impl GetGraphblasSparseMatrix,
+        multiplier_fill_value: EvaluationDomain,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        multiplicant: &impl GetGraphblasSparseMatrix,
+        multiplicant_fill_value: EvaluationDomain,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseMatrix,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+pub trait ApplyElementWiseMatrixUnionBinaryOperatorWithSparseScalars<EvaluationDomain: ValueType> {
+    fn apply_with_sparse_scalars(
+        &self,
+        multiplier: &impl GetGraphblasSparseMatrix,
+        multiplier_fill_value: &impl GetGraphblasSparseScalar,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        multiplicant: &impl GetGraphblasSparseMatrix,
+        multiplicant_fill_value: &impl GetGraphblasSparseScalar,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseMatrix,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType + SetScalarValueTyped<EvaluationDomain>>
+    ApplyElementWiseMatrixUnionBinaryOperator<EvaluationDomain>
+    for ElementWiseMatrixUnionBinaryOperator
+{
+    fn apply(
+        &self,
+        multiplier: &impl GetGraphblasSparseMatrix,
+        multiplier_fill_value: EvaluationDomain,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        multiplicant: &impl GetGraphblasSparseMatrix,
+        multiplicant_fill_value: EvaluationDomain,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseMatrix,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let context = product.context_ref();
+        let mut multiplier_fill_scalar = SparseScalar::<EvaluationDomain>::new(context.clone())?;
+        multiplier_fill_scalar.set_value(multiplier_fill_value)?;
+        let mut multiplicant_fill_scalar = SparseScalar::<EvaluationDomain>::new(context.clone())?;
+        multiplicant_fill_scalar.set_value(multiplicant_fill_value)?;
+
+        self.apply_with_sparse_scalars(
+            multiplier,
+            &multiplier_fill_scalar,
+            operator,
+            multiplicant,
+            &multiplicant_fill_scalar,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+impl<EvaluationDomain: ValueType>
+    ApplyElementWiseMatrixUnionBinaryOperatorWithSparseScalars<EvaluationDomain>
+    for ElementWiseMatrixUnionBinaryOperator
+{
+    fn apply_with_sparse_scalars(
+        &self,
+        multiplier: &impl GetGraphblasSparseMatrix,
+        multiplier_fill_value: &impl GetGraphblasSparseScalar,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        multiplicant: &impl GetGraphblasSparseMatrix,
+        multiplicant_fill_value: &impl GetGraphblasSparseScalar,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseMatrix,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let context = product.context_ref();
+
+        context.call_operation(
+            || unsafe {
+                GxB_Matrix_eWiseUnion(
+                    product.graphblas_matrix_ptr(),
+                    mask.graphblas_matrix_ptr(),
+                    accumulator.accumulator_graphblas_type(),
+                    operator.graphblas_type(),
+                    multiplier.graphblas_matrix_ptr(),
+                    multiplier_fill_value.graphblas_scalar(),
+                    multiplicant.graphblas_matrix_ptr(),
+                    multiplicant_fill_value.graphblas_scalar(),
+                    options.graphblas_descriptor(),
+                )
+            },
+            Operation::new(OperationKind::ElementWiseUnion, "GxB_Matrix_eWiseUnion")
+                .with_binary_operator(operator.graphblas_type()),
+            unsafe { &product.graphblas_matrix_ptr() },
+            &[
+                Operand::matrix(multiplier),
+                Operand::scalar(multiplier_fill_value),
+                Operand::matrix(multiplicant),
+                Operand::scalar(multiplicant_fill_value),
+            ],
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First, Minus, Plus};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+
+    #[test]
+    fn test_element_wise_union_with_minus() {
+        let context = Context::init_default().unwrap();
+
+        let size: Size = (2, 2).into();
+        let multiplier = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![(0, 0, 5).into(), (0, 1, 3).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![(0, 0, 2).into(), (1, 1, 4).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<i32>::new(context.clone(), size).unwrap();
+
+        ElementWiseMatrixUnionBinaryOperator::new()
+            .apply(
+                &multiplier,
+                0,
+                &Minus::<i32>::new(),
+                &multiplicant,
+                0,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.element_value(0, 0).unwrap(), Some(3));
+        assert_eq!(product.element_value(0, 1).unwrap(), Some(3));
+        assert_eq!(product.element_value(1, 1).unwrap(), Some(-4));
+        assert_eq!(product.element_value(1, 0).unwrap(), None);
+
+        // test the use of an accumulator
+        ElementWiseMatrixUnionBinaryOperator::new()
+            .apply(
+                &multiplier,
+                10,
+                &Minus::<i32>::new(),
+                &multiplicant,
+                1,
+                &Plus::<i32>::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(0, 0).unwrap(), Some(3 + 3));
+        assert_eq!(product.element_value(0, 1).unwrap(), Some(3 + 2));
+        assert_eq!(product.element_value(1, 1).unwrap(), Some(-4 + 6));
+    }
+}
@@ -0,0 +1,208 @@
+use crate::collections::sparse_scalar::operations::{SetScalarValue, SetScalarValueTyped};
+use crate::collections::sparse_scalar::{GetGraphblasSparseScalar, SparseScalar};
+use crate::collections::sparse_vector::GetGraphblasSparseVector;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, BinaryOperator};
+use crate::operators::mask::VectorMask;
+use crate::operators::options::GetOperatorOptions;
+use crate::property::Operand;
+use crate::value_type::ValueType;
+
+use crate::graphblas_bindings::GxB_Vector_eWiseUnion;
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator {}
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator {}
+
+/// Applies the operator to the union of the stored elements of the multiplier and the multiplicant.
+/// Where only one of them stores an element, the fill value replaces the missing element of the other.
+#[derive(Debug, Clone)]
+pub struct ElementWiseVectorUnionBinaryOperator {}
+
+impl ElementWiseVectorUnionBinaryOperator {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for ElementWiseVectorUnionBinaryOperator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait ApplyElementWiseVectorUnionBinaryOperator<EvaluationDomain: ValueType> {
+    fn apply(
+        &self,
+        multiplier: &impl GetGraphblasSparseVector,
+        multiplier_fill_value: EvaluationDomain,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        multiplicant: &impl GetGraphblasSparseVector,
+        multiplicant_fill_value: EvaluationDomain,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseVector,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+pub trait ApplyElementWiseVectorUnionBinaryOperatorWithSparseScalars<EvaluationDomain: ValueType> {
+    fn apply_with_sparse_scalars(
+        &self,
+        multiplier: &impl GetGraphblasSparseVector,
+        multiplier_fill_value: &impl GetGraphblasSparseScalar,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        multiplicant: &impl GetGraphblasSparseVector,
+        multiplicant_fill_value: &impl GetGraphblasSparseScalar,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseVector,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType + SetScalarValueTyped<EvaluationDomain>>
+    ApplyElementWiseVectorUnionBinaryOperator<EvaluationDomain>
+    for ElementWiseVectorUnionBinaryOperator
+{
+    fn apply(
+        &self,
+        multiplier: &impl GetGraphblasSparseVector,
+        multiplier_fill_value: EvaluationDomain,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        multiplicant: &impl GetGraphblasSparseVector,
+        multiplicant_fill_value: EvaluationDomain,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseVector,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let context = product.context_ref();
+        let mut multiplier_fill_scalar = SparseScalar::<EvaluationDomain>::new(context.clone())?;
+        multiplier_fill_scalar.set_value(multiplier_fill_value)?;
+        let mut multiplicant_fill_scalar = SparseScalar::<EvaluationDomain>::new(context.clone())?;
+        multiplicant_fill_scalar.set_value(multiplicant_fill_value)?;
+
+        self.apply_with_sparse_scalars(
+            multiplier,
+            &multiplier_fill_scalar,
+            operator,
+            multiplicant,
+            &multiplicant_fill_scalar,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+impl<EvaluationDomain: ValueType>
+    ApplyElementWiseVectorUnionBinaryOperatorWithSparseScalars<EvaluationDomain>
+    for ElementWiseVectorUnionBinaryOperator
+{
+    fn apply_with_sparse_scalars(
+        &self,
+        multiplier: &impl GetGraphblasSparseVector,
+        multiplier_fill_value: &impl GetGraphblasSparseScalar,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        multiplicant: &impl GetGraphblasSparseVector,
+        multiplicant_fill_value: &impl GetGraphblasSparseScalar,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseVector,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let context = product.context_ref();
+
+        context.call_operation(
+            || unsafe {
+                GxB_Vector_eWiseUnion(
+                    product.graphblas_vector_ptr(),
+                    mask.graphblas_vector_ptr(),
+                    accumulator.accumulator_graphblas_type(),
+                    operator.graphblas_type(),
+                    multiplier.graphblas_vector_ptr(),
+                    multiplier_fill_value.graphblas_scalar(),
+                    multiplicant.graphblas_vector_ptr(),
+                    multiplicant_fill_value.graphblas_scalar(),
+                    options.graphblas_descriptor(),
+                )
+            },
+            Operation::new(OperationKind::ElementWiseUnion, "GxB_Vector_eWiseUnion")
+                .with_binary_operator(operator.graphblas_type()),
+            unsafe { &product.graphblas_vector_ptr() },
+            &[
+                Operand::vector(multiplier),
+                Operand::scalar(multiplier_fill_value),
+                Operand::vector(multiplicant),
+                Operand::scalar(multiplicant_fill_value),
+            ],
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, Divide, First};
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+
+    #[test]
+    fn test_element_wise_union_with_sparse_scalars() {
+        let context = Context::init_default().unwrap();
+
+        let length = 5;
+        let multiplier = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            length,
+            VectorElementList::from_element_vector(vec![(0, 8.0).into(), (1, 3.0).into()]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            length,
+            VectorElementList::from_element_vector(vec![(0, 2.0).into(), (3, 4.0).into()]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<f64>::new(context.clone(), length).unwrap();
+
+        let multiplier_fill_value = SparseScalar::<f64>::from_value(context.clone(), 1.0).unwrap();
+        let multiplicant_fill_value =
+            SparseScalar::<f64>::from_value(context.clone(), 1.0).unwrap();
+
+        ElementWiseVectorUnionBinaryOperator::new()
+            .apply_with_sparse_scalars(
+                &multiplier,
+                &multiplier_fill_value,
+                &Divide::<f64>::new(),
+                &multiplicant,
+                &multiplicant_fill_value,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.element_value(0).unwrap(), Some(4.0));
+        assert_eq!(product.element_value(1).unwrap(), Some(3.0));
+        assert_eq!(product.element_value(3).unwrap(), Some(0.25));
+    }
+}
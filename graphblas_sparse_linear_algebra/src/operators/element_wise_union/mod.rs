@@ -0,0 +1,13 @@
+mod element_wise_matrix_union;
+mod element_wise_vector_union;
+
+pub use element_wise_matrix_union::{
+    ApplyElementWiseMatrixUnionBinaryOperator,
+    ApplyElementWiseMatrixUnionBinaryOperatorWithSparseScalars,
+    ElementWiseMatrixUnionBinaryOperator,
+};
+pub use element_wise_vector_union::{
+    ApplyElementWiseVectorUnionBinaryOperator,
+    ApplyElementWiseVectorUnionBinaryOperatorWithSparseScalars,
+    ElementWiseVectorUnionBinaryOperator,
+};
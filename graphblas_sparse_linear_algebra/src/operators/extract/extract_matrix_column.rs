@@ -1,7 +1,7 @@
 use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::index::{
     ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
@@ -9,6 +9,7 @@ use crate::index::{
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArgument;
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::GrB_Col_extract;
@@ -69,7 +70,7 @@ impl<Column: ValueType> ExtractMatrixColumn<Column> for MatrixColumnExtractor {
 
         match indices_to_extract {
             ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Col_extract(
                             GetGraphblasSparseVector::graphblas_vector_ptr(column_vector),
@@ -82,11 +83,13 @@ impl<Column: ValueType> ExtractMatrixColumn<Column> for MatrixColumnExtractor {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Extract, "GrB_Col_extract"),
                     unsafe { column_vector.graphblas_vector_ptr_ref() },
+                    &[Operand::matrix(matrix_to_extract_from)],
                 )?;
             }
             ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Col_extract(
                             GetGraphblasSparseVector::graphblas_vector_ptr(column_vector),
@@ -99,7 +102,9 @@ impl<Column: ValueType> ExtractMatrixColumn<Column> for MatrixColumnExtractor {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Extract, "GrB_Col_extract"),
                     unsafe { column_vector.graphblas_vector_ptr_ref() },
+                    &[Operand::matrix(matrix_to_extract_from)],
                 )?;
             }
         }
@@ -1,6 +1,6 @@
 use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::index::{
     ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
@@ -8,6 +8,7 @@ use crate::index::{
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::MatrixMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArgument;
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::GrB_Matrix_extract;
@@ -79,7 +80,7 @@ impl<SubMatrix: ValueType> ExtractSubMatrix<SubMatrix> for SubMatrixExtractor {
                 ElementIndexSelectorGraphblasType::Index(row),
                 ElementIndexSelectorGraphblasType::Index(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Matrix_extract(
                             GetGraphblasSparseMatrix::graphblas_matrix_ptr(sub_matrix),
@@ -93,14 +94,16 @@ impl<SubMatrix: ValueType> ExtractSubMatrix<SubMatrix> for SubMatrixExtractor {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Extract, "GrB_Matrix_extract"),
                     unsafe { sub_matrix.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_extract_from)],
                 )?;
             }
             (
                 ElementIndexSelectorGraphblasType::All(row),
                 ElementIndexSelectorGraphblasType::Index(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Matrix_extract(
                             GetGraphblasSparseMatrix::graphblas_matrix_ptr(sub_matrix),
@@ -114,14 +117,16 @@ impl<SubMatrix: ValueType> ExtractSubMatrix<SubMatrix> for SubMatrixExtractor {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Extract, "GrB_Matrix_extract"),
                     unsafe { sub_matrix.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_extract_from)],
                 )?;
             }
             (
                 ElementIndexSelectorGraphblasType::Index(row),
                 ElementIndexSelectorGraphblasType::All(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Matrix_extract(
                             GetGraphblasSparseMatrix::graphblas_matrix_ptr(sub_matrix),
@@ -135,14 +140,16 @@ impl<SubMatrix: ValueType> ExtractSubMatrix<SubMatrix> for SubMatrixExtractor {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Extract, "GrB_Matrix_extract"),
                     unsafe { sub_matrix.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_extract_from)],
                 )?;
             }
             (
                 ElementIndexSelectorGraphblasType::All(row),
                 ElementIndexSelectorGraphblasType::All(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Matrix_extract(
                             GetGraphblasSparseMatrix::graphblas_matrix_ptr(sub_matrix),
@@ -156,7 +163,9 @@ impl<SubMatrix: ValueType> ExtractSubMatrix<SubMatrix> for SubMatrixExtractor {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Extract, "GrB_Matrix_extract"),
                     unsafe { sub_matrix.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_extract_from)],
                 )?;
             }
         }
@@ -1,6 +1,6 @@
 use crate::collections::sparse_vector::operations::GetSparseVectorLength;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::index::{
     ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
@@ -8,6 +8,7 @@ use crate::index::{
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOperatorOptions;
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::GrB_Vector_extract;
@@ -66,7 +67,7 @@ impl<SubVector: ValueType> ExtractSubVector<SubVector> for SubVectorExtractor {
 
         match indices_to_extract {
             ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Vector_extract(
                             GetGraphblasSparseVector::graphblas_vector_ptr(sub_vector),
@@ -78,11 +79,13 @@ impl<SubVector: ValueType> ExtractSubVector<SubVector> for SubVectorExtractor {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Extract, "GrB_Vector_extract"),
                     unsafe { sub_vector.graphblas_vector_ptr_ref() },
+                    &[Operand::vector(vector_to_extract_from)],
                 )?;
             }
             ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Vector_extract(
                             GetGraphblasSparseVector::graphblas_vector_ptr(sub_vector),
@@ -94,7 +97,9 @@ impl<SubVector: ValueType> ExtractSubVector<SubVector> for SubVectorExtractor {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Extract, "GrB_Vector_extract"),
                     unsafe { sub_vector.graphblas_vector_ptr_ref() },
+                    &[Operand::vector(vector_to_extract_from)],
                 )?;
             }
         }
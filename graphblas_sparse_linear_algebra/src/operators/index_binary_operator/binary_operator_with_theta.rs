@@ -0,0 +1,338 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+use crate::collections::sparse_scalar::{GetGraphblasSparseScalar, SparseScalar};
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{GrB_BinaryOp, GrB_BinaryOp_free, GxB_BinaryOp_new_IndexOp};
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, BinaryOperator, ReturnsBool};
+use crate::value_type::ValueType;
+
+use super::IndexBinaryOperator;
+
+/// A binary operator from an index binary operator and a fixed value of its argument theta.
+/// GraphBLAS refers to the index binary operator, so this operator borrows it.
+/// The operator is freed when it is dropped.
+///
+/// ```compile_fail
+/// use graphblas_sparse_linear_algebra::collections::sparse_matrix::{ColumnIndex, RowIndex};
+/// use graphblas_sparse_linear_algebra::collections::sparse_scalar::SparseScalar;
+/// use graphblas_sparse_linear_algebra::context::Context;
+/// use graphblas_sparse_linear_algebra::operators::index_binary_operator::{
+///     BinaryOperatorWithTheta, UserDefinedIndexBinaryOperator,
+/// };
+///
+/// let context = Context::init_default().unwrap();
+/// let index_binary_operator = UserDefinedIndexBinaryOperator::<u8>::from_function(
+///     context.clone(),
+///     |x: &u8, _: RowIndex, _: ColumnIndex, y: &u8, _: RowIndex, _: ColumnIndex, theta: &u8| {
+///         x + y + theta
+///     },
+/// )
+/// .unwrap();
+/// let theta = SparseScalar::<u8>::from_value(context.clone(), 1).unwrap();
+/// let operator = BinaryOperatorWithTheta::new(context, &index_binary_operator, &theta).unwrap();
+/// drop(index_binary_operator);
+/// drop(operator);
+/// ```
+#[derive(Debug)]
+pub struct BinaryOperatorWithTheta<
+    'a,
+    EvaluationDomain: ValueType,
+    Output: ValueType = EvaluationDomain,
+> {
+    context: Arc<Context>,
+    graphblas_operator: GrB_BinaryOp,
+    _evaluation_domain: PhantomData<EvaluationDomain>,
+    _output: PhantomData<Output>,
+    _index_binary_operator: PhantomData<&'a ()>,
+}
+
+// Implemented methods do not provide mutable access to the GraphBLAS operator.
+// Code review must consider that no mutable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<'a, EvaluationDomain: ValueType, Output: ValueType> Send
+    for BinaryOperatorWithTheta<'a, EvaluationDomain, Output>
+{
+}
+unsafe impl<'a, EvaluationDomain: ValueType, Output: ValueType> Sync
+    for BinaryOperatorWithTheta<'a, EvaluationDomain, Output>
+{
+}
+
+impl<'a, EvaluationDomain: ValueType, Output: ValueType>
+    BinaryOperatorWithTheta<'a, EvaluationDomain, Output>
+{
+    /// The value of theta is copied.
+    /// Theta must have the type of the argument theta of the index binary operator.
+    ///
+    /// ```compile_fail
+    /// use graphblas_sparse_linear_algebra::collections::sparse_matrix::{ColumnIndex, RowIndex};
+    /// use graphblas_sparse_linear_algebra::collections::sparse_scalar::SparseScalar;
+    /// use graphblas_sparse_linear_algebra::context::Context;
+    /// use graphblas_sparse_linear_algebra::operators::index_binary_operator::{
+    ///     BinaryOperatorWithTheta, UserDefinedIndexBinaryOperator,
+    /// };
+    ///
+    /// let context = Context::init_default().unwrap();
+    /// let index_binary_operator = UserDefinedIndexBinaryOperator::<u8>::from_function(
+    ///     context.clone(),
+    ///     |x: &u8, _: RowIndex, _: ColumnIndex, y: &u8, _: RowIndex, _: ColumnIndex, theta: &u8| {
+    ///         x + y + theta
+    ///     },
+    /// )
+    /// .unwrap();
+    /// let theta = SparseScalar::<i64>::from_value(context.clone(), 1).unwrap();
+    /// let operator = BinaryOperatorWithTheta::new(context, &index_binary_operator, &theta);
+    /// ```
+    pub fn new<Theta: ValueType>(
+        context: Arc<Context>,
+        index_binary_operator: &'a impl IndexBinaryOperator<
+            EvaluationDomain,
+            Output = Output,
+            Theta = Theta,
+        >,
+        theta: &SparseScalar<Theta>,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_operator: MaybeUninit<GrB_BinaryOp> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GxB_BinaryOp_new_IndexOp(
+                graphblas_operator.as_mut_ptr(),
+                index_binary_operator.graphblas_type(),
+                theta.graphblas_scalar(),
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            _evaluation_domain: PhantomData,
+            _output: PhantomData,
+            _index_binary_operator: PhantomData,
+        })
+    }
+}
+
+impl<'a, EvaluationDomain: ValueType, Output: ValueType> BinaryOperator<EvaluationDomain>
+    for BinaryOperatorWithTheta<'a, EvaluationDomain, Output>
+{
+    fn graphblas_type(&self) -> GrB_BinaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<'a, EvaluationDomain: ValueType, Output: ValueType> AccumulatorBinaryOperator<EvaluationDomain>
+    for BinaryOperatorWithTheta<'a, EvaluationDomain, Output>
+{
+    fn accumulator_graphblas_type(&self) -> GrB_BinaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<'a, EvaluationDomain: ValueType> ReturnsBool
+    for BinaryOperatorWithTheta<'a, EvaluationDomain, bool>
+{
+}
+
+impl<'a, EvaluationDomain: ValueType, Output: ValueType> GetContext
+    for BinaryOperatorWithTheta<'a, EvaluationDomain, Output>
+{
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<'a, EvaluationDomain: ValueType, Output: ValueType> Drop
+    for BinaryOperatorWithTheta<'a, EvaluationDomain, Output>
+{
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_BinaryOp_free(&mut self.graphblas_operator)
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{
+        ColumnIndex, MatrixElementList, RowIndex, Size, SparseMatrix,
+    };
+    use crate::collections::sparse_vector::operations::FromVectorElementList;
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::collections::Collection;
+    use crate::operators::apply::{ApplyBinaryOperatorWithSparseScalar, BinaryOperatorApplier};
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::index_binary_operator::UserDefinedIndexBinaryOperator;
+    use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+    use crate::operators::monoid::{Min, Plus};
+    use crate::operators::multiplication::{MatrixMultiplicationOperator, MultiplyMatrices};
+    use crate::operators::options::{OperatorOptions, OptionsForOperatorWithMatrixArguments};
+    use crate::operators::reduce::{MonoidReducer, MonoidScalarReducer};
+    use crate::operators::semiring::UserDefinedSemiring;
+
+    // The weight of a path i -> k -> j scales with the distance between its endpoints.
+    fn endpoint_distance_weight(
+        _x: &u64,
+        row_index_x: RowIndex,
+        _column_index_x: ColumnIndex,
+        _y: &u64,
+        _row_index_y: RowIndex,
+        column_index_y: ColumnIndex,
+        scale: &u64,
+    ) -> u64 {
+        row_index_x.abs_diff(column_index_y) as u64 * scale
+    }
+
+    #[test]
+    fn multiplication_with_index_binary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let index_binary_operator = UserDefinedIndexBinaryOperator::<u64>::from_function(
+            context.clone(),
+            endpoint_distance_weight,
+        )
+        .unwrap();
+        let multiply = BinaryOperatorWithTheta::new(
+            context.clone(),
+            &index_binary_operator,
+            &SparseScalar::<u64>::from_value(context.clone(), 10).unwrap(),
+        )
+        .unwrap();
+        let plus = Plus::<u64>::new();
+        let semiring = UserDefinedSemiring::<u64>::from_monoid_and_binary_operator(
+            context.clone(),
+            &plus,
+            &multiply,
+        )
+        .unwrap();
+
+        let size: Size = (3, 3).into();
+        let adjacency_matrix = SparseMatrix::<u64>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![
+                (0, 1, 1).into(),
+                (1, 2, 1).into(),
+                (2, 1, 1).into(),
+            ]),
+            &First::<u64>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<u64>::new(context.clone(), size).unwrap();
+
+        MatrixMultiplicationOperator::new()
+            .apply(
+                &adjacency_matrix,
+                &semiring,
+                &adjacency_matrix,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        // paths 0 -> 1 -> 2, 1 -> 2 -> 1, and 2 -> 1 -> 2
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.element_value(0, 2).unwrap(), Some(20));
+        assert_eq!(product.element_value(1, 1).unwrap(), Some(0));
+        assert_eq!(product.element_value(2, 2).unwrap(), Some(0));
+    }
+
+    // The index of a value equal to the minimum, or theta for any other value.
+    fn index_of_minimum(
+        x: &i32,
+        row_index_x: RowIndex,
+        _column_index_x: ColumnIndex,
+        minimum: &i32,
+        _row_index_y: RowIndex,
+        _column_index_y: ColumnIndex,
+        not_minimum: &u64,
+    ) -> u64 {
+        if x == minimum {
+            row_index_x as u64
+        } else {
+            *not_minimum
+        }
+    }
+
+    #[test]
+    fn argmin_with_index_binary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let length = 10;
+        let vector = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            length,
+            VectorElementList::from_element_vector(vec![
+                (1, 7).into(),
+                (3, -2).into(),
+                (4, 5).into(),
+                (6, -2).into(),
+            ]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        let mut minimum = 0;
+        MonoidReducer::new()
+            .vector_to_scalar(
+                &Min::<i32>::new(),
+                &vector,
+                &Assignment::new(),
+                &mut minimum,
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        let index_binary_operator = UserDefinedIndexBinaryOperator::<i32, u64, u64>::from_function(
+            context.clone(),
+            index_of_minimum,
+        )
+        .unwrap();
+        let not_minimum = SparseScalar::<u64>::from_value(context.clone(), u64::MAX).unwrap();
+        let operator =
+            BinaryOperatorWithTheta::new(context.clone(), &index_binary_operator, &not_minimum)
+                .unwrap();
+
+        let mut indices = SparseVector::<u64>::new(context.clone(), length).unwrap();
+        BinaryOperatorApplier::new()
+            .apply_with_vector_as_left_argument(
+                &vector,
+                &operator,
+                &SparseScalar::<i32>::from_value(context.clone(), minimum).unwrap(),
+                &Assignment::new(),
+                &mut indices,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        let mut argmin = 0;
+        MonoidReducer::new()
+            .vector_to_scalar(
+                &Min::<u64>::new(),
+                &indices,
+                &Assignment::new(),
+                &mut argmin,
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(minimum, -2);
+        assert_eq!(argmin, 3);
+    }
+}
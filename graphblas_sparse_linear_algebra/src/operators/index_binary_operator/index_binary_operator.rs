@@ -0,0 +1,14 @@
+use crate::graphblas_bindings::GxB_IndexBinaryOp;
+use crate::value_type::ValueType;
+
+/// A binary operator that also receives the positions of both arguments, and an argument theta.
+/// GraphBLAS operations apply it as a BinaryOperatorWithTheta.
+pub trait IndexBinaryOperator<EvaluationDomain>
+where
+    EvaluationDomain: ValueType,
+{
+    type Output: ValueType;
+    type Theta: ValueType;
+
+    fn graphblas_type(&self) -> GxB_IndexBinaryOp;
+}
@@ -0,0 +1,7 @@
+mod binary_operator_with_theta;
+mod index_binary_operator;
+mod user_defined_index_binary_operator;
+
+pub use binary_operator_with_theta::*;
+pub use index_binary_operator::*;
+pub use user_defined_index_binary_operator::*;
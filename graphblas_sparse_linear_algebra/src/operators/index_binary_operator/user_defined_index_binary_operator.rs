@@ -0,0 +1,326 @@
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::Arc;
+
+use crate::collections::sparse_matrix::{ColumnIndex, RowIndex};
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{
+    GrB_Index, GxB_IndexBinaryOp, GxB_IndexBinaryOp_free, GxB_IndexBinaryOp_new,
+};
+use crate::operators::user_defined_operator::{
+    check_function_is_zero_sized, to_c_string, zero_sized_function_instance,
+};
+use crate::value_type::ValueType;
+
+use super::IndexBinaryOperator;
+
+/// z = f(x, ix, jx, y, iy, jy, theta), with f a user-defined function of the values x and y,
+/// their row and column indices, and the operator argument theta.
+/// For vectors, the column indices are always zero.
+/// The operator is freed when it is dropped.
+#[derive(Debug)]
+pub struct UserDefinedIndexBinaryOperator<
+    EvaluationDomain: ValueType,
+    Output: ValueType = EvaluationDomain,
+    Theta: ValueType = EvaluationDomain,
+> {
+    context: Arc<Context>,
+    graphblas_operator: GxB_IndexBinaryOp,
+    _evaluation_domain: PhantomData<EvaluationDomain>,
+    _output: PhantomData<Output>,
+    _theta: PhantomData<Theta>,
+}
+
+// Implemented methods do not provide mutable access to the GraphBLAS operator.
+// Code review must consider that no mutable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<EvaluationDomain: ValueType, Output: ValueType, Theta: ValueType> Send
+    for UserDefinedIndexBinaryOperator<EvaluationDomain, Output, Theta>
+{
+}
+unsafe impl<EvaluationDomain: ValueType, Output: ValueType, Theta: ValueType> Sync
+    for UserDefinedIndexBinaryOperator<EvaluationDomain, Output, Theta>
+{
+}
+
+type IndexBinaryFunction = unsafe extern "C" fn(
+    z: *mut c_void,
+    x: *const c_void,
+    ix: GrB_Index,
+    jx: GrB_Index,
+    y: *const c_void,
+    iy: GrB_Index,
+    jy: GrB_Index,
+    theta: *const c_void,
+);
+
+unsafe extern "C" fn index_binary_function_trampoline<X, Z, Theta, F>(
+    z: *mut c_void,
+    x: *const c_void,
+    ix: GrB_Index,
+    jx: GrB_Index,
+    y: *const c_void,
+    iy: GrB_Index,
+    jy: GrB_Index,
+    theta: *const c_void,
+) where
+    F: Fn(&X, RowIndex, ColumnIndex, &X, RowIndex, ColumnIndex, &Theta) -> Z,
+{
+    let function = unsafe { zero_sized_function_instance::<F>() };
+    let x = unsafe { (x as *const X).read_unaligned() };
+    let y = unsafe { (y as *const X).read_unaligned() };
+    let theta = unsafe { (theta as *const Theta).read_unaligned() };
+    unsafe {
+        (z as *mut Z).write_unaligned(function(
+            &x,
+            ix as RowIndex,
+            jx as ColumnIndex,
+            &y,
+            iy as RowIndex,
+            jy as ColumnIndex,
+            &theta,
+        ))
+    };
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType, Theta: ValueType>
+    UserDefinedIndexBinaryOperator<EvaluationDomain, Output, Theta>
+{
+    /// The function must be a function item or a closure that does not capture its environment.
+    pub fn from_function<F>(
+        context: Arc<Context>,
+        _function: F,
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        F: Fn(
+                &EvaluationDomain,
+                RowIndex,
+                ColumnIndex,
+                &EvaluationDomain,
+                RowIndex,
+                ColumnIndex,
+                &Theta,
+            ) -> Output
+            + Copy
+            + 'static,
+    {
+        check_function_is_zero_sized::<F>()?;
+        unsafe {
+            Self::new(
+                context,
+                index_binary_function_trampoline::<EvaluationDomain, Output, Theta, F>,
+                None,
+            )
+        }
+    }
+
+    /// The function must be a function item or a closure that does not capture its environment.
+    /// The JIT compiles the C definition under the given name instead of calling the function.
+    pub fn from_function_with_definition<F>(
+        context: Arc<Context>,
+        _function: F,
+        name: &str,
+        definition: &str,
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        F: Fn(
+                &EvaluationDomain,
+                RowIndex,
+                ColumnIndex,
+                &EvaluationDomain,
+                RowIndex,
+                ColumnIndex,
+                &Theta,
+            ) -> Output
+            + Copy
+            + 'static,
+    {
+        check_function_is_zero_sized::<F>()?;
+        unsafe {
+            Self::new(
+                context,
+                index_binary_function_trampoline::<EvaluationDomain, Output, Theta, F>,
+                Some((name, definition)),
+            )
+        }
+    }
+
+    /// # Safety
+    /// The function must read values of type EvaluationDomain from x and y, a value of type Theta from theta,
+    /// and write a value of type Output to z.
+    pub unsafe fn from_graphblas_function(
+        context: Arc<Context>,
+        function: IndexBinaryFunction,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        unsafe { Self::new(context, function, None) }
+    }
+
+    /// # Safety
+    /// The function must read values of type EvaluationDomain from x and y, a value of type Theta from theta,
+    /// and write a value of type Output to z.
+    /// The C definition must be equivalent to the function.
+    pub unsafe fn from_graphblas_function_with_definition(
+        context: Arc<Context>,
+        function: IndexBinaryFunction,
+        name: &str,
+        definition: &str,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        unsafe { Self::new(context, function, Some((name, definition))) }
+    }
+
+    unsafe fn new(
+        context: Arc<Context>,
+        function: IndexBinaryFunction,
+        name_and_definition: Option<(&str, &str)>,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let name_and_definition = match name_and_definition {
+            Some((name, definition)) => Some((to_c_string(name)?, to_c_string(definition)?)),
+            None => None,
+        };
+        let (name, definition) = match &name_and_definition {
+            Some((name, definition)) => (name.as_ptr(), definition.as_ptr()),
+            None => (ptr::null(), ptr::null()),
+        };
+        let mut graphblas_operator: MaybeUninit<GxB_IndexBinaryOp> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GxB_IndexBinaryOp_new(
+                graphblas_operator.as_mut_ptr(),
+                Some(function),
+                Output::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+                Theta::to_graphblas_type(),
+                name,
+                definition,
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            _evaluation_domain: PhantomData,
+            _output: PhantomData,
+            _theta: PhantomData,
+        })
+    }
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType, Theta: ValueType>
+    IndexBinaryOperator<EvaluationDomain>
+    for UserDefinedIndexBinaryOperator<EvaluationDomain, Output, Theta>
+{
+    type Output = Output;
+    type Theta = Theta;
+
+    fn graphblas_type(&self) -> GxB_IndexBinaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType, Theta: ValueType> GetContext
+    for UserDefinedIndexBinaryOperator<EvaluationDomain, Output, Theta>
+{
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType, Theta: ValueType> Drop
+    for UserDefinedIndexBinaryOperator<EvaluationDomain, Output, Theta>
+{
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GxB_IndexBinaryOp_free(&mut self.graphblas_operator)
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_scalar::SparseScalar;
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::collections::Collection;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::element_wise_multiplication::{
+        ApplyElementWiseVectorMultiplicationBinaryOperator,
+        ElementWiseVectorMultiplicationBinaryOperator,
+    };
+    use crate::operators::index_binary_operator::BinaryOperatorWithTheta;
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+
+    #[test]
+    fn element_wise_multiplication_with_index_binary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let length = 10;
+        let multiplier = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            length,
+            VectorElementList::from_element_vector(vec![
+                (1, 2).into(),
+                (2, 3).into(),
+                (5, 4).into(),
+            ]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            length,
+            VectorElementList::from_element_vector(vec![(1, 5).into(), (5, -1).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<i32>::new(context.clone(), length).unwrap();
+
+        let index_binary_operator = UserDefinedIndexBinaryOperator::<i32, i32, i64>::from_function(
+            context.clone(),
+            |x: &i32,
+             row_index_x: RowIndex,
+             _column_index_x: ColumnIndex,
+             y: &i32,
+             _row_index_y: RowIndex,
+             _column_index_y: ColumnIndex,
+             offset: &i64| { x * y + (row_index_x as i64 * offset) as i32 },
+        )
+        .unwrap();
+        let operator = BinaryOperatorWithTheta::new(
+            context.clone(),
+            &index_binary_operator,
+            &SparseScalar::<i64>::from_value(context.clone(), 100).unwrap(),
+        )
+        .unwrap();
+
+        ElementWiseVectorMultiplicationBinaryOperator::new()
+            .apply(
+                &multiplier,
+                &operator,
+                &multiplicant,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.element_value(1).unwrap(), Some(110));
+        assert_eq!(product.element_value(5).unwrap(), Some(496));
+    }
+}
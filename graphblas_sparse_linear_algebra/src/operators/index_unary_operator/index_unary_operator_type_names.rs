@@ -0,0 +1,78 @@
+use crate::context::{CallGraphBlasContext, Context};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::*;
+use crate::operators::user_defined_operator::from_c_char_buffer;
+use crate::value_type::ValueType;
+
+use super::IndexUnaryOperator;
+
+/// Retrieves the names of the GraphBLAS types an index-unary operator z = f(x, i, j, y) is defined with.
+/// Operators that only depend on the indices of an element have no input type, and return an empty name.
+pub trait GetIndexUnaryOperatorTypeNames<T: ValueType> {
+    fn input_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError>;
+    fn argument_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError>;
+    fn output_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType, O: IndexUnaryOperator<T>> GetIndexUnaryOperatorTypeNames<T> for O {
+    fn input_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError> {
+        type_name(context, self.graphblas_type(), GxB_IndexUnaryOp_xtype_name)
+    }
+
+    fn argument_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError> {
+        type_name(context, self.graphblas_type(), GxB_IndexUnaryOp_ytype_name)
+    }
+
+    fn output_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError> {
+        type_name(context, self.graphblas_type(), GxB_IndexUnaryOp_ztype_name)
+    }
+}
+
+fn type_name(
+    context: &Context,
+    graphblas_operator: GrB_IndexUnaryOp,
+    graphblas_function: unsafe extern "C" fn(*mut std::ffi::c_char, GrB_IndexUnaryOp) -> GrB_Info,
+) -> Result<String, SparseLinearAlgebraError> {
+    let mut name = [0; GxB_MAX_NAME_LEN as usize];
+    context.call(
+        || unsafe { graphblas_function(name.as_mut_ptr(), graphblas_operator) },
+        &graphblas_operator,
+    )?;
+    from_c_char_buffer(&name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::index_unary_operator::{
+        IsValueGreaterThan, UserDefinedIndexUnaryOperator,
+    };
+
+    #[test]
+    fn type_names_of_index_unary_operators() {
+        let context = Context::init_default().unwrap();
+
+        let is_value_greater_than = IsValueGreaterThan::<u16>::new();
+        assert_eq!(
+            is_value_greater_than.input_type_name(&context).unwrap(),
+            "uint16_t"
+        );
+        assert_eq!(
+            is_value_greater_than.argument_type_name(&context).unwrap(),
+            "uint16_t"
+        );
+        assert_eq!(
+            is_value_greater_than.output_type_name(&context).unwrap(),
+            "bool"
+        );
+
+        let operator = UserDefinedIndexUnaryOperator::<i32, i64>::from_function(
+            context.clone(),
+            |x: &i32, i, _j, _y: &i32| *x as i64 + i as i64,
+        )
+        .unwrap();
+        assert_eq!(operator.input_type_name(&context).unwrap(), "int32_t");
+        assert_eq!(operator.output_type_name(&context).unwrap(), "int64_t");
+    }
+}
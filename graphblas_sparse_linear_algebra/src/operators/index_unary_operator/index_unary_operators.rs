@@ -5,7 +5,7 @@ use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
     implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types,
     implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types,
 };
-use crate::value_type::ValueType;
+use crate::value_type::{GraphBlasIndexInteger, ValueType};
 
 // TODO: review EvaluationDomain. Typecasting may not work as expected, e.g. for less-than ot greater-than operators.
 pub trait IndexUnaryOperator<EvaluationDomain>
@@ -15,13 +15,24 @@ where
     fn graphblas_type(&self) -> GrB_IndexUnaryOp;
 }
 
+impl<EvaluationDomain: ValueType, O: IndexUnaryOperator<EvaluationDomain> + ?Sized>
+    IndexUnaryOperator<EvaluationDomain> for &O
+{
+    fn graphblas_type(&self) -> GrB_IndexUnaryOp {
+        (**self).graphblas_type()
+    }
+}
+
 macro_rules! implement_index_unary_operator {
     ($operator_name:ident, $graphblas_operator_trait_name:ident) => {
-        pub trait $graphblas_operator_trait_name<T: ValueType> {
+        implement_index_unary_operator!($operator_name, $graphblas_operator_trait_name, ValueType);
+    };
+    ($operator_name:ident, $graphblas_operator_trait_name:ident, $evaluation_domain_bound:ident) => {
+        pub trait $graphblas_operator_trait_name<T: $evaluation_domain_bound> {
             fn graphblas_type() -> GrB_IndexUnaryOp;
         }
 
-        impl<T: ValueType + $graphblas_operator_trait_name<T>> IndexUnaryOperator<T>
+        impl<T: $evaluation_domain_bound + $graphblas_operator_trait_name<T>> IndexUnaryOperator<T>
             for $operator_name<T>
         {
             fn graphblas_type(&self) -> GrB_IndexUnaryOp {
@@ -29,7 +40,7 @@ macro_rules! implement_index_unary_operator {
             }
         }
 
-        impl<T: ValueType> $operator_name<T> {
+        impl<T: $evaluation_domain_bound> $operator_name<T> {
             pub fn new() -> Self {
                 Self {
                     _evaluation_domain: PhantomData,
@@ -76,16 +87,19 @@ macro_rules! implement_generic_index_unary_operator {
 
 macro_rules! define_index_unary_operator {
     ($identifier: ident) => {
+        define_index_unary_operator!($identifier, ValueType);
+    };
+    ($identifier: ident, $evaluation_domain_bound:ident) => {
         #[derive(Debug, Clone)]
-        pub struct $identifier<EvaluationDomain: ValueType> {
+        pub struct $identifier<EvaluationDomain: $evaluation_domain_bound> {
             _evaluation_domain: PhantomData<EvaluationDomain>,
         }
     };
 }
 
 // z = i + y
-define_index_unary_operator!(PlusRowIndex);
-implement_index_unary_operator!(PlusRowIndex, PlusRowIndexTyped);
+define_index_unary_operator!(PlusRowIndex, GraphBlasIndexInteger);
+implement_index_unary_operator!(PlusRowIndex, PlusRowIndexTyped, GraphBlasIndexInteger);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
     implement_typed_index_unary_operator,
     PlusRowIndexTyped,
@@ -93,8 +107,12 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas
 );
 
 // z = j - i + y
-define_index_unary_operator!(PlusDiagonalIndex);
-implement_index_unary_operator!(PlusDiagonalIndex, PlusDiagonalIndexTyped);
+define_index_unary_operator!(PlusDiagonalIndex, GraphBlasIndexInteger);
+implement_index_unary_operator!(
+    PlusDiagonalIndex,
+    PlusDiagonalIndexTyped,
+    GraphBlasIndexInteger
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
     implement_typed_index_unary_operator,
     PlusDiagonalIndexTyped,
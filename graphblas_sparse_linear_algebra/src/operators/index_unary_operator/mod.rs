@@ -1,3 +1,7 @@
+mod index_unary_operator_type_names;
 mod index_unary_operators;
+mod user_defined_index_unary_operator;
 
+pub use index_unary_operator_type_names::*;
 pub use index_unary_operators::*;
+pub use user_defined_index_unary_operator::*;
@@ -0,0 +1,325 @@
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+use crate::collections::sparse_matrix::{ColumnIndex, RowIndex};
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{
+    GrB_Index, GrB_IndexUnaryOp, GrB_IndexUnaryOp_free, GrB_IndexUnaryOp_new, GxB_IndexUnaryOp_new,
+};
+use crate::operators::user_defined_operator::{
+    check_function_is_zero_sized, to_c_string, zero_sized_function_instance,
+};
+use crate::value_type::ValueType;
+
+use super::IndexUnaryOperator;
+
+/// z = f(x, i, j, y), with f a user-defined function of the stored value x,
+/// its row index i, its column index j, and the operator argument y.
+/// For vectors, j is always zero.
+/// The Output defaults to bool, as required to select elements.
+/// The operator is freed when it is dropped.
+#[derive(Debug)]
+pub struct UserDefinedIndexUnaryOperator<EvaluationDomain: ValueType, Output: ValueType = bool> {
+    context: Arc<Context>,
+    graphblas_operator: GrB_IndexUnaryOp,
+    _evaluation_domain: PhantomData<EvaluationDomain>,
+    _output: PhantomData<Output>,
+}
+
+// Implemented methods do not provide mutable access to the GraphBLAS operator.
+// Code review must consider that no mutable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<EvaluationDomain: ValueType, Output: ValueType> Send
+    for UserDefinedIndexUnaryOperator<EvaluationDomain, Output>
+{
+}
+unsafe impl<EvaluationDomain: ValueType, Output: ValueType> Sync
+    for UserDefinedIndexUnaryOperator<EvaluationDomain, Output>
+{
+}
+
+unsafe extern "C" fn index_unary_function_trampoline<X, Z, F>(
+    z: *mut c_void,
+    x: *const c_void,
+    i: GrB_Index,
+    j: GrB_Index,
+    y: *const c_void,
+) where
+    F: Fn(&X, RowIndex, ColumnIndex, &X) -> Z,
+{
+    let function = unsafe { zero_sized_function_instance::<F>() };
+    let x = unsafe { (x as *const X).read_unaligned() };
+    let y = unsafe { (y as *const X).read_unaligned() };
+    unsafe { (z as *mut Z).write_unaligned(function(&x, i as RowIndex, j as ColumnIndex, &y)) };
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType>
+    UserDefinedIndexUnaryOperator<EvaluationDomain, Output>
+{
+    /// The function must be a function item or a closure that does not capture its environment.
+    pub fn from_function<F>(
+        context: Arc<Context>,
+        _function: F,
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        F: Fn(&EvaluationDomain, RowIndex, ColumnIndex, &EvaluationDomain) -> Output
+            + Copy
+            + 'static,
+    {
+        check_function_is_zero_sized::<F>()?;
+        unsafe {
+            Self::from_graphblas_function(
+                context,
+                index_unary_function_trampoline::<EvaluationDomain, Output, F>,
+            )
+        }
+    }
+
+    /// The function must be a function item or a closure that does not capture its environment.
+    /// The JIT compiles the C definition under the given name instead of calling the function.
+    pub fn from_function_with_definition<F>(
+        context: Arc<Context>,
+        _function: F,
+        name: &str,
+        definition: &str,
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        F: Fn(&EvaluationDomain, RowIndex, ColumnIndex, &EvaluationDomain) -> Output
+            + Copy
+            + 'static,
+    {
+        check_function_is_zero_sized::<F>()?;
+        unsafe {
+            Self::from_graphblas_function_with_definition(
+                context,
+                index_unary_function_trampoline::<EvaluationDomain, Output, F>,
+                name,
+                definition,
+            )
+        }
+    }
+
+    /// # Safety
+    /// The function must read values of type EvaluationDomain from x and y, and write a value of type Output to z.
+    pub unsafe fn from_graphblas_function(
+        context: Arc<Context>,
+        function: unsafe extern "C" fn(
+            z: *mut c_void,
+            x: *const c_void,
+            i: GrB_Index,
+            j: GrB_Index,
+            y: *const c_void,
+        ),
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_operator: MaybeUninit<GrB_IndexUnaryOp> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GrB_IndexUnaryOp_new(
+                graphblas_operator.as_mut_ptr(),
+                Some(function),
+                Output::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            _evaluation_domain: PhantomData,
+            _output: PhantomData,
+        })
+    }
+
+    /// # Safety
+    /// The function must read values of type EvaluationDomain from x and y, and write a value of type Output to z.
+    /// The C definition must be equivalent to the function.
+    pub unsafe fn from_graphblas_function_with_definition(
+        context: Arc<Context>,
+        function: unsafe extern "C" fn(
+            z: *mut c_void,
+            x: *const c_void,
+            i: GrB_Index,
+            j: GrB_Index,
+            y: *const c_void,
+        ),
+        name: &str,
+        definition: &str,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let name = to_c_string(name)?;
+        let definition = to_c_string(definition)?;
+        let mut graphblas_operator: MaybeUninit<GrB_IndexUnaryOp> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GxB_IndexUnaryOp_new(
+                graphblas_operator.as_mut_ptr(),
+                Some(function),
+                Output::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+                name.as_ptr(),
+                definition.as_ptr(),
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            _evaluation_domain: PhantomData,
+            _output: PhantomData,
+        })
+    }
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType> IndexUnaryOperator<EvaluationDomain>
+    for UserDefinedIndexUnaryOperator<EvaluationDomain, Output>
+{
+    fn graphblas_type(&self) -> GrB_IndexUnaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType> GetContext
+    for UserDefinedIndexUnaryOperator<EvaluationDomain, Output>
+{
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<EvaluationDomain: ValueType, Output: ValueType> Drop
+    for UserDefinedIndexUnaryOperator<EvaluationDomain, Output>
+{
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_IndexUnaryOp_free(&mut self.graphblas_operator)
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::collections::Collection;
+    use crate::operators::apply::{ApplyIndexUnaryOperator, IndexUnaryOperatorApplier};
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::options::OptionsForOperatorWithMatrixArgument;
+    use crate::operators::select::{MatrixSelector, SelectFromMatrix};
+
+    fn is_within_partition_block(
+        _value: &usize,
+        row_index: RowIndex,
+        column_index: ColumnIndex,
+        block_size: &usize,
+    ) -> bool {
+        row_index / block_size == column_index / block_size
+    }
+
+    #[test]
+    fn select_edges_within_partition_block() {
+        let context = Context::init_default().unwrap();
+
+        let element_list = MatrixElementList::<f64>::from_element_vector(vec![
+            (0, 1, 1.0).into(),
+            (1, 2, 2.0).into(),
+            (2, 3, 3.0).into(),
+            (3, 0, 4.0).into(),
+        ]);
+
+        let matrix_size: Size = (4, 4).into();
+        let matrix = SparseMatrix::<f64>::from_element_list(
+            context.clone(),
+            matrix_size,
+            element_list,
+            &First::<f64>::new(),
+        )
+        .unwrap();
+
+        let mut product_matrix = SparseMatrix::<f64>::new(context.clone(), matrix_size).unwrap();
+
+        let is_within_partition_block = UserDefinedIndexUnaryOperator::<usize>::from_function(
+            context.clone(),
+            is_within_partition_block,
+        )
+        .unwrap();
+
+        MatrixSelector::new()
+            .apply(
+                &is_within_partition_block,
+                2,
+                &matrix,
+                &Assignment::new(),
+                &mut product_matrix,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product_matrix.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product_matrix.element_value(0, 1).unwrap(), Some(1.0));
+        assert_eq!(product_matrix.element_value(2, 3).unwrap(), Some(3.0));
+        assert_eq!(product_matrix.element_value(1, 2).unwrap(), None);
+    }
+
+    #[test]
+    fn apply_user_defined_index_unary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let element_list = MatrixElementList::<i32>::from_element_vector(vec![
+            (1, 1, 1).into(),
+            (2, 1, 2).into(),
+            (4, 2, 4).into(),
+        ]);
+
+        let matrix_size: Size = (10, 15).into();
+        let matrix = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            matrix_size,
+            element_list,
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        let mut product_matrix = SparseMatrix::<i32>::new(context.clone(), matrix_size).unwrap();
+
+        let weighted_position = UserDefinedIndexUnaryOperator::<i32, i32>::from_function(
+            context.clone(),
+            |value: &i32, row_index: RowIndex, column_index: ColumnIndex, weight: &i32| {
+                value + weight * (row_index + column_index) as i32
+            },
+        )
+        .unwrap();
+
+        IndexUnaryOperatorApplier::new()
+            .apply_to_matrix(
+                &matrix,
+                &weighted_position,
+                &10,
+                &Assignment::new(),
+                &mut product_matrix,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product_matrix.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product_matrix.element_value_or_default(1, 1).unwrap(), 21);
+        assert_eq!(product_matrix.element_value_or_default(2, 1).unwrap(), 32);
+        assert_eq!(product_matrix.element_value_or_default(4, 2).unwrap(), 64);
+    }
+}
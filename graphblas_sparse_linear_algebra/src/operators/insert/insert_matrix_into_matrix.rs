@@ -1,12 +1,13 @@
 use crate::collections::sparse_matrix::operations::sparse_matrix_column_width;
 use crate::collections::sparse_matrix::operations::sparse_matrix_row_height;
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::index::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::MatrixMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArguments;
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::GrB_Matrix_assign;
@@ -77,7 +78,7 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertMatrixIntoMatrix<AccumulatorE
                 ElementIndexSelectorGraphblasType::Index(row),
                 ElementIndexSelectorGraphblasType::Index(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Matrix_assign(
                             matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -91,14 +92,16 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertMatrixIntoMatrix<AccumulatorE
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GrB_Matrix_assign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_insert)],
                 )?;
             }
             (
                 ElementIndexSelectorGraphblasType::All(row),
                 ElementIndexSelectorGraphblasType::Index(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Matrix_assign(
                             matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -112,14 +115,16 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertMatrixIntoMatrix<AccumulatorE
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GrB_Matrix_assign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_insert)],
                 )?;
             }
             (
                 ElementIndexSelectorGraphblasType::Index(row),
                 ElementIndexSelectorGraphblasType::All(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Matrix_assign(
                             matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -133,14 +138,16 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertMatrixIntoMatrix<AccumulatorE
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GrB_Matrix_assign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_insert)],
                 )?;
             }
             (
                 ElementIndexSelectorGraphblasType::All(row),
                 ElementIndexSelectorGraphblasType::All(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Matrix_assign(
                             matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -154,7 +161,9 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertMatrixIntoMatrix<AccumulatorE
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GrB_Matrix_assign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_insert)],
                 )?;
             }
         }
@@ -1,7 +1,7 @@
 use crate::collections::sparse_matrix::operations::sparse_matrix_column_width;
 use crate::collections::sparse_matrix::operations::sparse_matrix_row_height;
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::index::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
@@ -93,7 +93,7 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                         ElementIndexSelectorGraphblasType::Index(row),
                         ElementIndexSelectorGraphblasType::Index(column),
                     ) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -107,14 +107,19 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                            &[],
                         )?;
                     }
                     (
                         ElementIndexSelectorGraphblasType::All(row),
                         ElementIndexSelectorGraphblasType::Index(column),
                     ) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -128,14 +133,19 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                            &[],
                         )?;
                     }
                     (
                         ElementIndexSelectorGraphblasType::Index(row),
                         ElementIndexSelectorGraphblasType::All(column),
                     ) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -149,14 +159,19 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                            &[],
                         )?;
                     }
                     (
                         ElementIndexSelectorGraphblasType::All(row),
                         ElementIndexSelectorGraphblasType::All(column),
                     ) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -170,7 +185,12 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                            &[],
                         )?;
                     }
                 }
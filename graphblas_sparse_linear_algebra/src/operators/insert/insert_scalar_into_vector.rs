@@ -1,6 +1,6 @@
 use crate::collections::sparse_vector::operations::sparse_vector_length;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::index::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
@@ -80,7 +80,7 @@ macro_rules! implement_insert_scalar_into_vector_trait {
 
                 match indices_to_insert_into {
                     ElementIndexSelectorGraphblasType::Index(index) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     vector_to_insert_into.graphblas_vector_ptr(),
@@ -92,12 +92,17 @@ macro_rules! implement_insert_scalar_into_vector_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { vector_to_insert_into.graphblas_vector_ptr_ref() },
+                            &[],
                         )?;
                     }
 
                     ElementIndexSelectorGraphblasType::All(index) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     vector_to_insert_into.graphblas_vector_ptr(),
@@ -109,7 +114,12 @@ macro_rules! implement_insert_scalar_into_vector_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { vector_to_insert_into.graphblas_vector_ptr_ref() },
+                            &[],
                         )?;
                     }
                 }
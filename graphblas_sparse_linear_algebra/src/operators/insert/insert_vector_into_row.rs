@@ -1,7 +1,7 @@
 use crate::collections::sparse_matrix::operations::sparse_matrix_row_height;
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::index::{
     ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
@@ -9,6 +9,7 @@ use crate::index::{
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArgument;
+use crate::property::Operand;
 
 use crate::value_type::ValueType;
 
@@ -73,7 +74,7 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertVectorIntoRow<AccumulatorEval
 
         match indices_to_insert_into {
             ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Row_assign(
                             matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -86,12 +87,14 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertVectorIntoRow<AccumulatorEval
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GrB_Row_assign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::vector(vector_to_insert)],
                 )?;
             }
 
             ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Row_assign(
                             matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -104,7 +107,9 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertVectorIntoRow<AccumulatorEval
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GrB_Row_assign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::vector(vector_to_insert)],
                 )?;
             }
         }
@@ -1,8 +1,9 @@
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOperatorOptions;
+use crate::property::Operand;
 
 use crate::collections::sparse_vector::operations::sparse_vector_length;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
@@ -67,7 +68,7 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertVectorIntoVector<AccumulatorE
 
         match indices_to_insert_into {
             ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Vector_assign(
                             vector_to_insert_into.graphblas_vector_ptr(),
@@ -79,12 +80,14 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertVectorIntoVector<AccumulatorE
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GrB_Vector_assign"),
                     unsafe { vector_to_insert_into.graphblas_vector_ptr_ref() },
+                    &[Operand::vector(vector_to_insert)],
                 )?;
             }
 
             ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GrB_Vector_assign(
                             vector_to_insert_into.graphblas_vector_ptr(),
@@ -96,7 +99,9 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertVectorIntoVector<AccumulatorE
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GrB_Vector_assign"),
                     unsafe { vector_to_insert_into.graphblas_vector_ptr_ref() },
+                    &[Operand::vector(vector_to_insert)],
                 )?;
             }
         }
@@ -1,7 +1,8 @@
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::{binary_operator::BinaryOperator, monoid::Monoid, semiring::Semiring};
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::{
@@ -55,7 +56,7 @@ impl<EvaluationDomain: ValueType> SemiringKroneckerProduct<EvaluationDomain>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_kronecker_Semiring(
                     product.graphblas_matrix_ptr(),
@@ -67,7 +68,13 @@ impl<EvaluationDomain: ValueType> SemiringKroneckerProduct<EvaluationDomain>
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::KroneckerProduct,
+                "GrB_Matrix_kronecker_Semiring",
+            )
+            .with_semiring(multiplication_operator.graphblas_type()),
             unsafe { product.graphblas_matrix_ptr_ref() },
+            &[Operand::matrix(multiplier), Operand::matrix(multiplicant)],
         )?;
 
         Ok(())
@@ -111,7 +118,7 @@ impl<EvaluationDomain: ValueType> MonoidKroneckerProduct<EvaluationDomain>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_kronecker_Monoid(
                     product.graphblas_matrix_ptr(),
@@ -123,7 +130,13 @@ impl<EvaluationDomain: ValueType> MonoidKroneckerProduct<EvaluationDomain>
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::KroneckerProduct,
+                "GrB_Matrix_kronecker_Monoid",
+            )
+            .with_monoid(multiplication_operator.graphblas_type()),
             unsafe { product.graphblas_matrix_ptr_ref() },
+            &[Operand::matrix(multiplier), Operand::matrix(multiplicant)],
         )?;
 
         Ok(())
@@ -167,7 +180,7 @@ impl<EvaluationDomain: ValueType> BinaryOperatorKroneckerProduct<EvaluationDomai
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_kronecker_BinaryOp(
                     product.graphblas_matrix_ptr(),
@@ -179,7 +192,13 @@ impl<EvaluationDomain: ValueType> BinaryOperatorKroneckerProduct<EvaluationDomai
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(
+                OperationKind::KroneckerProduct,
+                "GrB_Matrix_kronecker_BinaryOp",
+            )
+            .with_binary_operator(multiplication_operator.graphblas_type()),
             unsafe { product.graphblas_matrix_ptr_ref() },
+            &[Operand::matrix(multiplier), Operand::matrix(multiplicant)],
         )?;
 
         Ok(())
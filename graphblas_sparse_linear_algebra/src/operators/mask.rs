@@ -12,6 +12,18 @@ pub trait VectorMask: GetContext {
     unsafe fn graphblas_vector_ptr(&self) -> GrB_Vector;
 }
 
+impl<M: MatrixMask + ?Sized> MatrixMask for &M {
+    unsafe fn graphblas_matrix_ptr(&self) -> GrB_Matrix {
+        (**self).graphblas_matrix_ptr()
+    }
+}
+
+impl<M: VectorMask + ?Sized> VectorMask for &M {
+    unsafe fn graphblas_vector_ptr(&self) -> GrB_Vector {
+        (**self).graphblas_vector_ptr()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SelectEntireMatrix {
     context: Arc<Context>,
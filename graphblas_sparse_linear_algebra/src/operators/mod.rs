@@ -1,8 +1,11 @@
 pub mod apply;
 pub mod binary_operator;
+pub mod builder;
 pub mod element_wise_addition;
 pub mod element_wise_multiplication;
+pub mod element_wise_union;
 pub mod extract;
+pub mod index_binary_operator;
 pub mod index_unary_operator;
 pub mod insert;
 pub mod kronecker_product;
@@ -15,4 +18,7 @@ pub mod select;
 pub mod semiring;
 pub mod subinsert;
 pub mod transpose;
+pub mod typed;
 pub mod unary_operator;
+
+mod user_defined_operator;
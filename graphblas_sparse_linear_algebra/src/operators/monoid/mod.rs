@@ -1,3 +1,9 @@
 mod monoid;
+mod monoid_components;
+mod monoid_handle;
+mod user_defined_monoid;
 
 pub use monoid::*;
+pub use monoid_components::*;
+pub use monoid_handle::*;
+pub use user_defined_monoid::*;
@@ -6,7 +6,7 @@ use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
     implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_with_postfix,
     implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers_with_postfix,
 };
-use crate::value_type::ValueType;
+use crate::value_type::{UnsignedInteger, ValueType};
 
 pub trait Monoid<T>
 where
@@ -15,13 +15,26 @@ where
     fn graphblas_type(&self) -> GrB_Monoid;
 }
 
+impl<T: ValueType, M: Monoid<T> + ?Sized> Monoid<T> for &M {
+    fn graphblas_type(&self) -> GrB_Monoid {
+        (**self).graphblas_type()
+    }
+}
+
 macro_rules! implement_monoid_operator {
     ($monoid_operator_name:ident, $graphblas_operator_trait_name:ident) => {
-        pub trait $graphblas_operator_trait_name<T: ValueType> {
+        implement_monoid_operator!(
+            $monoid_operator_name,
+            $graphblas_operator_trait_name,
+            ValueType
+        );
+    };
+    ($monoid_operator_name:ident, $graphblas_operator_trait_name:ident, $evaluation_domain_bound:ident) => {
+        pub trait $graphblas_operator_trait_name<T: $evaluation_domain_bound> {
             fn graphblas_type() -> GrB_Monoid;
         }
 
-        impl<T: ValueType + $graphblas_operator_trait_name<T>> Monoid<T>
+        impl<T: $evaluation_domain_bound + $graphblas_operator_trait_name<T>> Monoid<T>
             for $monoid_operator_name<T>
         {
             fn graphblas_type(&self) -> GrB_Monoid {
@@ -29,7 +42,7 @@ macro_rules! implement_monoid_operator {
             }
         }
 
-        impl<T: ValueType> $monoid_operator_name<T> {
+        impl<T: $evaluation_domain_bound> $monoid_operator_name<T> {
             pub fn new() -> Self {
                 Self {
                     _value_type: PhantomData,
@@ -149,25 +162,34 @@ implement_typed_monoid_operator!(LogicalExclusiveOrMonoidTyped, GrB_LXOR_MONOID_
 implement_typed_monoid_operator!(EqualMonoidTyped, GrB_LXNOR_MONOID_BOOL, bool);
 
 #[derive(Debug, Clone)]
-pub struct BitwiseLogicalOr<T: ValueType> {
+pub struct BitwiseLogicalOr<T: UnsignedInteger> {
     _value_type: PhantomData<T>,
 }
 
 #[derive(Debug, Clone)]
-pub struct BitwiseLogicalAnd<T: ValueType> {
+pub struct BitwiseLogicalAnd<T: UnsignedInteger> {
     _value_type: PhantomData<T>,
 }
 
 #[derive(Debug, Clone)]
-pub struct BitwiseLogicalExclusiveOr<T: ValueType> {
+pub struct BitwiseLogicalExclusiveOr<T: UnsignedInteger> {
     _value_type: PhantomData<T>,
 }
 
-implement_monoid_operator!(BitwiseLogicalOr, BitwiseLogicalOrMonoidTyped);
-implement_monoid_operator!(BitwiseLogicalAnd, BitwiseLogicalAndMonoidTyped);
+implement_monoid_operator!(
+    BitwiseLogicalOr,
+    BitwiseLogicalOrMonoidTyped,
+    UnsignedInteger
+);
+implement_monoid_operator!(
+    BitwiseLogicalAnd,
+    BitwiseLogicalAndMonoidTyped,
+    UnsignedInteger
+);
 implement_monoid_operator!(
     BitwiseLogicalExclusiveOr,
-    BitwiseLogicalExclusiveOrMonoidTyped
+    BitwiseLogicalExclusiveOrMonoidTyped,
+    UnsignedInteger
 );
 
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers_with_postfix!(
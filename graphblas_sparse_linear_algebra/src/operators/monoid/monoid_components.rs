@@ -0,0 +1,140 @@
+use std::mem::MaybeUninit;
+
+use crate::context::{CallGraphBlasContext, Context};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::*;
+use crate::operators::binary_operator::BinaryOperatorHandle;
+use crate::value_type::ValueType;
+
+use super::Monoid;
+
+/// Retrieves the values and operator a monoid was defined with.
+pub trait GetMonoidComponents<T: ValueType> {
+    fn identity(&self, context: &Context) -> Result<T, SparseLinearAlgebraError>;
+
+    /// None if the monoid has no terminal value.
+    fn terminal(&self, context: &Context) -> Result<Option<T>, SparseLinearAlgebraError>;
+
+    fn binary_operator(
+        &self,
+        context: &Context,
+    ) -> Result<BinaryOperatorHandle<'_, T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType, M: Monoid<T>> GetMonoidComponents<T> for M {
+    fn identity(&self, context: &Context) -> Result<T, SparseLinearAlgebraError> {
+        // The GraphBLAS type of a monoid over T has the size and layout of T.
+        let mut identity: MaybeUninit<T> = MaybeUninit::uninit();
+        let graphblas_monoid = self.graphblas_type();
+
+        context.call(
+            || unsafe { GxB_Monoid_identity(identity.as_mut_ptr().cast(), graphblas_monoid) },
+            &graphblas_monoid,
+        )?;
+
+        Ok(unsafe { identity.assume_init() })
+    }
+
+    fn terminal(&self, context: &Context) -> Result<Option<T>, SparseLinearAlgebraError> {
+        let mut has_terminal = false;
+        let mut terminal: MaybeUninit<T> = MaybeUninit::uninit();
+        let graphblas_monoid = self.graphblas_type();
+
+        context.call(
+            || unsafe {
+                GxB_Monoid_terminal(
+                    &mut has_terminal,
+                    terminal.as_mut_ptr().cast(),
+                    graphblas_monoid,
+                )
+            },
+            &graphblas_monoid,
+        )?;
+
+        if has_terminal {
+            Ok(Some(unsafe { terminal.assume_init() }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn binary_operator(
+        &self,
+        context: &Context,
+    ) -> Result<BinaryOperatorHandle<'_, T>, SparseLinearAlgebraError> {
+        let mut graphblas_operator: MaybeUninit<GrB_BinaryOp> = MaybeUninit::uninit();
+        let graphblas_monoid = self.graphblas_type();
+
+        context.call(
+            || unsafe { GxB_Monoid_operator(graphblas_operator.as_mut_ptr(), graphblas_monoid) },
+            &graphblas_monoid,
+        )?;
+
+        Ok(BinaryOperatorHandle::new(unsafe {
+            graphblas_operator.assume_init()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::binary_operator::{
+        BinaryOperator, GetBinaryOperatorTypeNames, UserDefinedBinaryOperator,
+    };
+    use crate::operators::monoid::{Any, Max, Plus, UserDefinedMonoid};
+
+    #[test]
+    fn identity_and_terminal_of_built_in_monoids() {
+        let context = Context::init_default().unwrap();
+
+        assert_eq!(Plus::<f32>::new().identity(&context).unwrap(), 0.0);
+        assert_eq!(Plus::<f32>::new().terminal(&context).unwrap(), None);
+        assert_eq!(Max::<u8>::new().identity(&context).unwrap(), 0);
+        assert_eq!(Max::<u8>::new().terminal(&context).unwrap(), Some(u8::MAX));
+        assert_eq!(Max::<isize>::new().identity(&context).unwrap(), isize::MIN);
+        assert_eq!(
+            Max::<isize>::new().terminal(&context).unwrap(),
+            Some(isize::MAX)
+        );
+    }
+
+    #[test]
+    fn binary_operator_of_built_in_monoid() {
+        let context = Context::init_default().unwrap();
+
+        let monoid = Any::<i16>::new();
+        let binary_operator = monoid.binary_operator(&context).unwrap();
+
+        assert_eq!(
+            binary_operator.output_type_name(&context).unwrap(),
+            "int16_t"
+        );
+    }
+
+    #[test]
+    fn identity_and_terminal_of_user_defined_monoid() {
+        let context = Context::init_default().unwrap();
+
+        let operator =
+            UserDefinedBinaryOperator::<u8>::from_function(context.clone(), |x: &u8, y: &u8| {
+                x.saturating_add(*y)
+            })
+            .unwrap();
+        let monoid = UserDefinedMonoid::<u8>::from_binary_operator_with_terminal(
+            context.clone(),
+            &operator,
+            0,
+            u8::MAX,
+        )
+        .unwrap();
+
+        assert_eq!(monoid.identity(&context).unwrap(), 0);
+        assert_eq!(monoid.terminal(&context).unwrap(), Some(u8::MAX));
+        assert_eq!(
+            monoid.binary_operator(&context).unwrap().graphblas_type(),
+            operator.graphblas_type()
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use std::marker::PhantomData;
+
+use crate::graphblas_bindings::*;
+use crate::value_type::ValueType;
+
+use super::Monoid;
+
+/// A monoid that is owned by another GraphBLAS object, e.g. the addition of a semiring.
+/// The handle borrows the owner, and is not freed when it is dropped.
+#[derive(Debug, Clone, Copy)]
+pub struct MonoidHandle<'a, T: ValueType> {
+    graphblas_monoid: GrB_Monoid,
+    _value_type: PhantomData<T>,
+    _owner: PhantomData<&'a ()>,
+}
+
+// The handle does not provide mutable access to the GraphBLAS monoid.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<'a, T: ValueType> Send for MonoidHandle<'a, T> {}
+unsafe impl<'a, T: ValueType> Sync for MonoidHandle<'a, T> {}
+
+impl<'a, T: ValueType> MonoidHandle<'a, T> {
+    pub(crate) fn new(graphblas_monoid: GrB_Monoid) -> Self {
+        Self {
+            graphblas_monoid,
+            _value_type: PhantomData,
+            _owner: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ValueType> Monoid<T> for MonoidHandle<'a, T> {
+    fn graphblas_type(&self) -> GrB_Monoid {
+        self.graphblas_monoid
+    }
+}
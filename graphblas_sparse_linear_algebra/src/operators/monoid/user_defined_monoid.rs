@@ -0,0 +1,302 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::*;
+use crate::operators::binary_operator::BinaryOperator;
+use crate::value_type::utilities_to_implement_traits_for_all_value_types::implement_1_type_macro_for_all_value_types_and_2_typed_graphblas_functions_with_implementation_type;
+use crate::value_type::{ConvertScalar, ValueType};
+
+use super::Monoid;
+
+/// A monoid from an associative binary operator and its identity value.
+/// An optional terminal value allows a reduction to stop as soon as it is reached.
+/// GraphBLAS refers to the binary operator, so the monoid borrows it.
+/// The monoid is freed when it is dropped.
+///
+/// ```compile_fail
+/// use graphblas_sparse_linear_algebra::context::Context;
+/// use graphblas_sparse_linear_algebra::operators::binary_operator::UserDefinedBinaryOperator;
+/// use graphblas_sparse_linear_algebra::operators::monoid::UserDefinedMonoid;
+///
+/// let context = Context::init_default().unwrap();
+/// let operator =
+///     UserDefinedBinaryOperator::<u8>::from_function(context.clone(), |x: &u8, y: &u8| x | y)
+///         .unwrap();
+/// let monoid =
+///     UserDefinedMonoid::<u8>::from_binary_operator(context.clone(), &operator, 0).unwrap();
+/// drop(operator);
+/// drop(monoid);
+/// ```
+#[derive(Debug)]
+pub struct UserDefinedMonoid<'a, T: ValueType> {
+    context: Arc<Context>,
+    graphblas_monoid: GrB_Monoid,
+    _value_type: PhantomData<T>,
+    _binary_operator: PhantomData<&'a ()>,
+}
+
+// Implemented methods do not provide mutable access to the GraphBLAS monoid.
+// Code review must consider that no mutable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<'a, T: ValueType> Send for UserDefinedMonoid<'a, T> {}
+unsafe impl<'a, T: ValueType> Sync for UserDefinedMonoid<'a, T> {}
+
+macro_rules! implement_user_defined_monoid_constructors {
+    ($value_type:ty, $implementation_type:ty, $graphblas_function_1:ident, $graphblas_function_2:ident) => {
+        impl<'a> UserDefinedMonoid<'a, $value_type> {
+            /// The binary operator must be associative, and the identity value must be its identity.
+            pub fn from_binary_operator(
+                context: Arc<Context>,
+                binary_operator: &'a impl BinaryOperator<$value_type>,
+                identity: $value_type,
+            ) -> Result<Self, SparseLinearAlgebraError> {
+                let identity: $implementation_type = identity.to_type()?;
+                let mut graphblas_monoid: MaybeUninit<GrB_Monoid> = MaybeUninit::uninit();
+
+                context.call_without_detailed_error_information(|| unsafe {
+                    $graphblas_function_1(
+                        graphblas_monoid.as_mut_ptr(),
+                        binary_operator.graphblas_type(),
+                        identity,
+                    )
+                })?;
+
+                Ok(Self {
+                    context,
+                    graphblas_monoid: unsafe { graphblas_monoid.assume_init() },
+                    _value_type: PhantomData,
+                    _binary_operator: PhantomData,
+                })
+            }
+
+            /// The binary operator must be associative, and the identity value must be its identity.
+            /// The terminal value must satisfy f(terminal, y) = terminal for any y.
+            pub fn from_binary_operator_with_terminal(
+                context: Arc<Context>,
+                binary_operator: &'a impl BinaryOperator<$value_type>,
+                identity: $value_type,
+                terminal: $value_type,
+            ) -> Result<Self, SparseLinearAlgebraError> {
+                let identity: $implementation_type = identity.to_type()?;
+                let terminal: $implementation_type = terminal.to_type()?;
+                let mut graphblas_monoid: MaybeUninit<GrB_Monoid> = MaybeUninit::uninit();
+
+                context.call_without_detailed_error_information(|| unsafe {
+                    $graphblas_function_2(
+                        graphblas_monoid.as_mut_ptr(),
+                        binary_operator.graphblas_type(),
+                        identity,
+                        terminal,
+                    )
+                })?;
+
+                Ok(Self {
+                    context,
+                    graphblas_monoid: unsafe { graphblas_monoid.assume_init() },
+                    _value_type: PhantomData,
+                    _binary_operator: PhantomData,
+                })
+            }
+        }
+    };
+}
+
+implement_1_type_macro_for_all_value_types_and_2_typed_graphblas_functions_with_implementation_type!(
+    implement_user_defined_monoid_constructors,
+    GrB_Monoid_new,
+    GxB_Monoid_terminal_new
+);
+
+impl<'a, T: ValueType> Monoid<T> for UserDefinedMonoid<'a, T> {
+    fn graphblas_type(&self) -> GrB_Monoid {
+        self.graphblas_monoid
+    }
+}
+
+impl<'a, T: ValueType> GetContext for UserDefinedMonoid<'a, T> {
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<'a, T: ValueType> Drop for UserDefinedMonoid<'a, T> {
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_Monoid_free(&mut self.graphblas_monoid)
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::collections::Collection;
+    use crate::operators::binary_operator::{Assignment, First, UserDefinedBinaryOperator};
+    use crate::operators::element_wise_addition::{
+        ApplyElementWiseVectorAdditionMonoidOperator, ElementWiseVectorAdditionMonoidOperator,
+    };
+    use crate::operators::kronecker_product::{
+        MonoidKroneckerProduct, MonoidKroneckerProductOperator,
+    };
+    use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+    use crate::operators::options::{OperatorOptions, OptionsForOperatorWithMatrixArguments};
+    use crate::operators::reduce::{MonoidReducer, MonoidScalarReducer};
+
+    fn larger_magnitude(x: &i32, y: &i32) -> i32 {
+        if x.abs() >= y.abs() {
+            *x
+        } else {
+            *y
+        }
+    }
+
+    #[test]
+    fn element_wise_addition_with_user_defined_monoid() {
+        let context = Context::init_default().unwrap();
+
+        let operator =
+            UserDefinedBinaryOperator::<i32>::from_function(context.clone(), larger_magnitude)
+                .unwrap();
+        let monoid =
+            UserDefinedMonoid::<i32>::from_binary_operator(context.clone(), &operator, 0).unwrap();
+
+        let length = 10;
+        let multiplier = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            length,
+            VectorElementList::from_element_vector(vec![(1, 5).into(), (2, -3).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            length,
+            VectorElementList::from_element_vector(vec![(1, -8).into(), (3, 4).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<i32>::new(context.clone(), length).unwrap();
+
+        ElementWiseVectorAdditionMonoidOperator::new()
+            .apply(
+                &multiplier,
+                &monoid,
+                &multiplicant,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.element_value_or_default(1).unwrap(), -8);
+        assert_eq!(product.element_value_or_default(2).unwrap(), -3);
+        assert_eq!(product.element_value_or_default(3).unwrap(), 4);
+    }
+
+    #[test]
+    fn reduce_with_user_defined_monoid_with_terminal() {
+        let context = Context::init_default().unwrap();
+
+        let operator =
+            UserDefinedBinaryOperator::<u8>::from_function(context.clone(), |x: &u8, y: &u8| {
+                x.saturating_add(*y)
+            })
+            .unwrap();
+        let monoid = UserDefinedMonoid::<u8>::from_binary_operator_with_terminal(
+            context.clone(),
+            &operator,
+            0,
+            u8::MAX,
+        )
+        .unwrap();
+
+        let vector = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            10,
+            VectorElementList::from_element_vector(vec![
+                (1, 200).into(),
+                (2, 100).into(),
+                (4, 1).into(),
+            ]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        let mut product = 0u8;
+        MonoidReducer::new()
+            .vector_to_scalar(
+                &monoid,
+                &vector,
+                &Assignment::new(),
+                &mut product,
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product, u8::MAX);
+    }
+
+    #[test]
+    fn kronecker_product_with_user_defined_monoid() {
+        let context = Context::init_default().unwrap();
+
+        let operator =
+            UserDefinedBinaryOperator::<i32>::from_function(context.clone(), larger_magnitude)
+                .unwrap();
+        let monoid =
+            UserDefinedMonoid::<i32>::from_binary_operator(context.clone(), &operator, 0).unwrap();
+
+        let multiplier = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            (2, 2).into(),
+            MatrixElementList::from_element_vector(vec![(0, 0, 2).into(), (1, 1, -3).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            (1, 1).into(),
+            MatrixElementList::from_element_vector(vec![(0, 0, -1).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        let product_size: Size = (2, 2).into();
+        let mut product = SparseMatrix::<i32>::new(context.clone(), product_size).unwrap();
+
+        MonoidKroneckerProductOperator::new()
+            .apply(
+                &multiplier,
+                &monoid,
+                &multiplicant,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value_or_default(0, 0).unwrap(), 2);
+        assert_eq!(product.element_value_or_default(1, 1).unwrap(), -3);
+    }
+}
@@ -1,9 +1,10 @@
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::MatrixMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArguments;
+use crate::property::Operand;
 
 use crate::operators::semiring::Semiring;
 use crate::value_type::ValueType;
@@ -55,7 +56,7 @@ impl<EvaluationDomain: ValueType> MultiplyMatrices<EvaluationDomain>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_mxm(
                     product.graphblas_matrix_ptr(),
@@ -67,7 +68,10 @@ impl<EvaluationDomain: ValueType> MultiplyMatrices<EvaluationDomain>
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::MatrixMultiplication, "GrB_mxm")
+                .with_semiring(operator.graphblas_type()),
             unsafe { product.graphblas_matrix_ptr_ref() },
+            &[Operand::matrix(multiplier), Operand::matrix(multiplicant)],
         )?;
 
         Ok(())
@@ -1,10 +1,11 @@
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixAsFirstArgument;
+use crate::property::Operand;
 
 use crate::operators::semiring::Semiring;
 use crate::value_type::ValueType;
@@ -56,7 +57,7 @@ impl<EvaluationDomain: ValueType> MultiplyMatrixByVector<EvaluationDomain>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_mxv(
                     product.graphblas_vector_ptr(),
@@ -68,7 +69,10 @@ impl<EvaluationDomain: ValueType> MultiplyMatrixByVector<EvaluationDomain>
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::MatrixVectorMultiplication, "GrB_mxv")
+                .with_semiring(operator.graphblas_type()),
             unsafe { product.graphblas_vector_ptr_ref() },
+            &[Operand::matrix(multiplier), Operand::vector(multiplicant)],
         )?;
 
         Ok(())
@@ -91,7 +95,7 @@ mod tests {
     use crate::operators::binary_operator::{Assignment, First};
     use crate::operators::mask::SelectEntireVector;
     use crate::operators::options::OptionsForOperatorWithMatrixAsFirstArgument;
-    use crate::operators::semiring::PlusTimes;
+    use crate::operators::semiring::{MinRowIndexSecondArgument, PlusTimes};
 
     #[test]
     fn test_multiplication_with_plus_times() {
@@ -222,4 +226,53 @@ mod tests {
         assert_eq!(product.element_value_or_default(0).unwrap(), 19.);
         assert_eq!(product.element_value(1).unwrap(), None);
     }
+
+    #[test]
+    fn test_parent_vector_with_positional_semiring() {
+        let context = Context::init_default().unwrap();
+
+        let number_of_vertices = 5;
+        let size: Size = (number_of_vertices, number_of_vertices).into();
+
+        // transposed adjacency matrix of the edges 0->1, 0->2, 3->2, and 2->4
+        let transposed_adjacency_matrix = SparseMatrix::<bool>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![
+                (1, 0, true).into(),
+                (2, 0, true).into(),
+                (2, 3, true).into(),
+                (4, 2, true).into(),
+            ]),
+            &First::<bool>::new(),
+        )
+        .unwrap();
+
+        let frontier = SparseVector::<bool>::from_element_list(
+            context.clone(),
+            number_of_vertices,
+            VectorElementList::from_element_vector(vec![(0, true).into(), (3, true).into()]),
+            &First::<bool>::new(),
+        )
+        .unwrap();
+
+        let mut parents = SparseVector::<i64>::new(context.clone(), number_of_vertices).unwrap();
+
+        MatrixVectorMultiplicationOperator::new()
+            .apply(
+                &transposed_adjacency_matrix,
+                &MinRowIndexSecondArgument::<i64>::new(),
+                &frontier,
+                &Assignment::new(),
+                &mut parents,
+                &SelectEntireVector::new(context.clone()),
+                &OptionsForOperatorWithMatrixAsFirstArgument::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(parents.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(parents.element_value(1).unwrap(), Some(0));
+        assert_eq!(parents.element_value(2).unwrap(), Some(0));
+        assert_eq!(parents.element_value(4).unwrap(), None);
+    }
 }
@@ -1,10 +1,11 @@
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixAsSecondArgument;
+use crate::property::Operand;
 
 use crate::operators::semiring::Semiring;
 use crate::value_type::ValueType;
@@ -56,7 +57,7 @@ impl<EvaluationDomain: ValueType> MultiplyVectorByMatrix<EvaluationDomain>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_vxm(
                     product.graphblas_vector_ptr(),
@@ -68,7 +69,10 @@ impl<EvaluationDomain: ValueType> MultiplyVectorByMatrix<EvaluationDomain>
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::VectorMatrixMultiplication, "GrB_vxm")
+                .with_semiring(operator.graphblas_type()),
             unsafe { product.graphblas_vector_ptr_ref() },
+            &[Operand::vector(multiplier), Operand::matrix(multiplicant)],
         )?;
 
         Ok(())
@@ -1,6 +1,6 @@
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::binary_operator::BinaryOperator;
@@ -8,6 +8,7 @@ use crate::operators::mask::VectorMask;
 use crate::operators::options::{
     GetOptionsForOperatorWithMatrixArgument, WithTransposeMatrixArgument,
 };
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::GrB_Matrix_reduce_BinaryOp;
@@ -63,7 +64,7 @@ impl<EvaluationDomain: ValueType> ReduceWithBinaryOperator<EvaluationDomain>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_reduce_BinaryOp(
                     product.graphblas_vector_ptr(),
@@ -74,7 +75,10 @@ impl<EvaluationDomain: ValueType> ReduceWithBinaryOperator<EvaluationDomain>
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::Reduce, "GrB_Matrix_reduce_BinaryOp")
+                .with_binary_operator(operator.graphblas_type()),
             unsafe { product.graphblas_vector_ptr_ref() },
+            &[Operand::matrix(argument)],
         )?;
 
         Ok(())
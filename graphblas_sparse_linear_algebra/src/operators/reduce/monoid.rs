@@ -2,7 +2,7 @@ use std::convert::TryInto;
 
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::{
     GrB_Matrix_reduce_BOOL, GrB_Matrix_reduce_FP32, GrB_Matrix_reduce_FP64,
@@ -20,6 +20,7 @@ use crate::operators::monoid::Monoid;
 use crate::operators::options::{
     GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument, WithTransposeMatrixArgument,
 };
+use crate::property::Operand;
 use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
     convert_mut_scalar_to_type, identity_conversion,
     implement_macro_for_all_value_types_and_2_typed_graphblas_functions_with_mutable_scalar_type_conversion,
@@ -75,7 +76,7 @@ impl<EvaluationDomain: ValueType> MonoidVectorReducer<EvaluationDomain> for Mono
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_Matrix_reduce_Monoid(
                     product.graphblas_vector_ptr(),
@@ -86,7 +87,10 @@ impl<EvaluationDomain: ValueType> MonoidVectorReducer<EvaluationDomain> for Mono
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::Reduce, "GrB_Matrix_reduce_Monoid")
+                .with_monoid(operator.graphblas_type()),
             unsafe { product.graphblas_vector_ptr_ref() },
+            &[Operand::matrix(argument)],
         )?;
 
         Ok(())
@@ -147,15 +151,20 @@ macro_rules! implement_monoid_reducer {
                 let mut tmp_product = product.clone().to_type()?;
 
                 // TODO: support detailed error information
-                context.call_without_detailed_error_information(|| unsafe {
-                    $matrix_reducer_operator(
-                        &mut tmp_product,
-                        accumulator.accumulator_graphblas_type(),
-                        operator.graphblas_type(),
-                        argument.graphblas_matrix_ptr(),
-                        options.graphblas_descriptor(),
-                    )
-                })?;
+                context.call_operation_without_output(
+                    || unsafe {
+                        $matrix_reducer_operator(
+                            &mut tmp_product,
+                            accumulator.accumulator_graphblas_type(),
+                            operator.graphblas_type(),
+                            argument.graphblas_matrix_ptr(),
+                            options.graphblas_descriptor(),
+                        )
+                    },
+                    Operation::new(OperationKind::Reduce, stringify!($matrix_reducer_operator))
+                        .with_monoid(operator.graphblas_type()),
+                    &[Operand::matrix(argument)],
+                )?;
 
                 $convert_to_type!(tmp_product, $value_type);
                 *product = tmp_product;
@@ -174,15 +183,20 @@ macro_rules! implement_monoid_reducer {
                 let context = argument.context_ref();
                 let mut tmp_product = product.clone().to_type()?;
 
-                context.call_without_detailed_error_information(|| unsafe {
-                    $vector_reducer_operator(
-                        &mut tmp_product,
-                        accumulator.accumulator_graphblas_type(),
-                        operator.graphblas_type(),
-                        argument.graphblas_vector_ptr(),
-                        options.graphblas_descriptor(),
-                    )
-                })?;
+                context.call_operation_without_output(
+                    || unsafe {
+                        $vector_reducer_operator(
+                            &mut tmp_product,
+                            accumulator.accumulator_graphblas_type(),
+                            operator.graphblas_type(),
+                            argument.graphblas_vector_ptr(),
+                            options.graphblas_descriptor(),
+                        )
+                    },
+                    Operation::new(OperationKind::Reduce, stringify!($vector_reducer_operator))
+                        .with_monoid(operator.graphblas_type()),
+                    &[Operand::vector(argument)],
+                )?;
 
                 $convert_to_type!(tmp_product, $value_type);
                 *product = tmp_product;
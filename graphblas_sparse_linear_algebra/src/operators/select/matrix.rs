@@ -6,12 +6,13 @@ use suitesparse_graphblas_sys::{
 };
 
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::index_unary_operator::IndexUnaryOperator;
 use crate::operators::mask::MatrixMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArgument;
+use crate::property::Operand;
 
 use crate::value_type::utilities_to_implement_traits_for_all_value_types::implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_implementation_type;
 use crate::value_type::{ConvertScalar, ValueType};
@@ -58,7 +59,7 @@ macro_rules! implement_select_from_matrix {
                 options: &impl GetOptionsForOperatorWithMatrixArgument,
             ) -> Result<(), SparseLinearAlgebraError> {
                 let selector_argument = selector_argument.clone().to_type()?;
-                argument.context_ref().call(
+                argument.context_ref().call_operation(
                     || unsafe {
                         $graphblas_operator(
                             product.graphblas_matrix_ptr(),
@@ -70,7 +71,10 @@ macro_rules! implement_select_from_matrix {
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Select, stringify!($graphblas_operator))
+                        .with_index_unary_operator(selector.graphblas_type()),
                     unsafe { product.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(argument)],
                 )?;
 
                 Ok(())
@@ -1,3 +1,7 @@
 mod semiring;
+mod semiring_components;
+mod user_defined_semiring;
 
 pub use semiring::*;
+pub use semiring_components::*;
+pub use user_defined_semiring::*;
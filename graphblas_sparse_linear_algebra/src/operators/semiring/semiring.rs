@@ -1,7 +1,12 @@
 use std::marker::PhantomData;
 
-use crate::value_type::utilities_to_implement_traits_for_all_value_types::implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean;
-use crate::value_type::ValueType;
+use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
+    implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types,
+    implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types,
+    implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean,
+    implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers,
+};
+use crate::value_type::{GraphBlasIndexInteger, UnsignedInteger, ValueType};
 
 use crate::graphblas_bindings::*;
 
@@ -12,12 +17,23 @@ where
     fn graphblas_type(&self) -> GrB_Semiring;
 }
 
+impl<EvaluationDomain: ValueType, S: Semiring<EvaluationDomain> + ?Sized> Semiring<EvaluationDomain>
+    for &S
+{
+    fn graphblas_type(&self) -> GrB_Semiring {
+        (**self).graphblas_type()
+    }
+}
+
 macro_rules! define_semiring {
     ($semiring:ident) => {
+        define_semiring!($semiring, ValueType);
+    };
+    ($semiring:ident, $evaluation_domain_bound:ident) => {
         #[derive(Debug, Clone)]
         pub struct $semiring<EvaluationDomain>
         where
-            EvaluationDomain: ValueType,
+            EvaluationDomain: $evaluation_domain_bound,
         {
             _evaluation_domain: PhantomData<EvaluationDomain>,
         }
@@ -44,17 +60,22 @@ macro_rules! define_semiring {
 
 macro_rules! implement_semiring {
     ($operator_name:ident, $graphblas_operator_trait_name:ident) => {
-        pub trait $graphblas_operator_trait_name<T: ValueType> {
+        implement_semiring!($operator_name, $graphblas_operator_trait_name, ValueType);
+    };
+    ($operator_name:ident, $graphblas_operator_trait_name:ident, $evaluation_domain_bound:ident) => {
+        pub trait $graphblas_operator_trait_name<T: $evaluation_domain_bound> {
             fn graphblas_type() -> GrB_Semiring;
         }
 
-        impl<T: ValueType + $graphblas_operator_trait_name<T>> Semiring<T> for $operator_name<T> {
+        impl<T: $evaluation_domain_bound + $graphblas_operator_trait_name<T>> Semiring<T>
+            for $operator_name<T>
+        {
             fn graphblas_type(&self) -> GrB_Semiring {
                 T::graphblas_type()
             }
         }
 
-        impl<T: ValueType> $operator_name<T> {
+        impl<T: $evaluation_domain_bound> $operator_name<T> {
             pub fn new() -> Self {
                 Self {
                     _evaluation_domain: PhantomData,
@@ -77,8 +98,9 @@ macro_rules! implement_typed_semiring {
     };
 }
 
-// MAX
+// The multiplication operator PAIR, also known as ONEB, returns one: z = 1
 
+// MAX
 define_semiring!(MaxFirst);
 implement_semiring!(MaxFirst, MaxFirstTyped);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
@@ -95,28 +117,14 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MAX_SECOND_SEMIRING
 );
 
-// define_semiring!(MaxOne);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxOne,
-//     GrB_MAX_ONEB_SEMIRING
-// );
-
-define_semiring!(MaxMin);
-implement_semiring!(MaxMin, MaxMinTyped);
+define_semiring!(MaxPair);
+implement_semiring!(MaxPair, MaxPairTyped);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
     implement_typed_semiring,
-    MaxMinTyped,
-    GrB_MAX_MIN_SEMIRING
+    MaxPairTyped,
+    GxB_MAX_PAIR
 );
 
-// define_semiring!(MaxMax);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxMax,
-//     GrB_MAX_MAX_SEMIRING
-// );
-
 define_semiring!(MaxPlus);
 implement_semiring!(MaxPlus, MaxPlusTyped);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
@@ -125,19 +133,21 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MAX_PLUS_SEMIRING
 );
 
-// define_semiring!(MaxMaxus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxMaxus,
-//     GrB_MAX_MINUS_SEMIRING
-// );
+define_semiring!(MaxMinus);
+implement_semiring!(MaxMinus, MaxMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxMinusTyped,
+    GxB_MAX_MINUS
+);
 
-// define_semiring!(MaxRightMaxus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxRightMaxus,
-//     GrB_MAX_RMINUS_SEMIRING
-// );
+define_semiring!(MaxReverseMinus);
+implement_semiring!(MaxReverseMinus, MaxReverseMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxReverseMinusTyped,
+    GxB_MAX_RMINUS
+);
 
 define_semiring!(MaxTimes);
 implement_semiring!(MaxTimes, MaxTimesTyped);
@@ -147,85 +157,117 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MAX_TIMES_SEMIRING
 );
 
-// define_semiring!(MaxDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxDivide,
-//     GrB_MAX_DIVIDE_SEMIRING
-// );
-
-// define_semiring!(MaxRightDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxRightDivide,
-//     GrB_MAX_RDIVIDE_SEMIRING
-// );
-
-// define_semiring!(MaxIsEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsEqualTo,
-//     GrB_MAX_ISEQ_SEMIRING
-// );
-
-// define_semiring!(MaxIsNotEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsNotEqualTo,
-//     GrB_MAX_ISNE_SEMIRING
-// );
-
-// define_semiring!(MaxIsLessThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsLessThan,
-//     GrB_MAX_ISLT_SEMIRING
-// );
-
-// define_semiring!(MaxIsGreaterThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsGreaterThan,
-//     GrB_MAX_ISGT_SEMIRING
-// );
-
-// define_semiring!(MaxIsLessThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsLessThanOrEqualTo,
-//     GrB_MAX_ISLE_SEMIRING
-// );
-
-// define_semiring!(MaxIsGreaterThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsGreaterThanOrEqualTo,
-//     GrB_MAX_ISGE_SEMIRING
-// );
-
-// define_semiring!(MaxLogicalOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxLogicalOr,
-//     GrB_MAX_LOR_SEMIRING
-// );
-
-// define_semiring!(MaxLogicalAnd);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxLogicalAnd,
-//     GrB_MAX_LAND_SEMIRING
-// );
-
-// define_semiring!(MaxLogicalExclusiveOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxLogicalExclusiveOr,
-//     GrB_MAX_LXOR_SEMIRING
-// );
+define_semiring!(MaxDivide);
+implement_semiring!(MaxDivide, MaxDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxDivideTyped,
+    GxB_MAX_DIV
+);
+
+define_semiring!(MaxReverseDivide);
+implement_semiring!(MaxReverseDivide, MaxReverseDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxReverseDivideTyped,
+    GxB_MAX_RDIV
+);
 
-// MIN
+define_semiring!(MaxMin);
+implement_semiring!(MaxMin, MaxMinTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxMinTyped,
+    GrB_MAX_MIN_SEMIRING
+);
+
+define_semiring!(MaxMax);
+implement_semiring!(MaxMax, MaxMaxTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxMaxTyped,
+    GxB_MAX_MAX
+);
+
+define_semiring!(MaxTypedIsEqual);
+implement_semiring!(MaxTypedIsEqual, MaxTypedIsEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxTypedIsEqualTyped,
+    GxB_MAX_ISEQ
+);
+
+define_semiring!(MaxTypedIsNotEqual);
+implement_semiring!(MaxTypedIsNotEqual, MaxTypedIsNotEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxTypedIsNotEqualTyped,
+    GxB_MAX_ISNE
+);
+
+define_semiring!(MaxTypedIsGreaterThan);
+implement_semiring!(MaxTypedIsGreaterThan, MaxTypedIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxTypedIsGreaterThanTyped,
+    GxB_MAX_ISGT
+);
+
+define_semiring!(MaxTypedIsLessThan);
+implement_semiring!(MaxTypedIsLessThan, MaxTypedIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxTypedIsLessThanTyped,
+    GxB_MAX_ISLT
+);
+
+define_semiring!(MaxTypedIsGreaterThanOrEqualTo);
+implement_semiring!(
+    MaxTypedIsGreaterThanOrEqualTo,
+    MaxTypedIsGreaterThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxTypedIsGreaterThanOrEqualToTyped,
+    GxB_MAX_ISGE
+);
+
+define_semiring!(MaxTypedIsLessThanOrEqualTo);
+implement_semiring!(
+    MaxTypedIsLessThanOrEqualTo,
+    MaxTypedIsLessThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxTypedIsLessThanOrEqualToTyped,
+    GxB_MAX_ISLE
+);
+
+define_semiring!(MaxLogicalOr);
+implement_semiring!(MaxLogicalOr, MaxLogicalOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxLogicalOrTyped,
+    GxB_MAX_LOR
+);
+
+define_semiring!(MaxLogicalAnd);
+implement_semiring!(MaxLogicalAnd, MaxLogicalAndTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxLogicalAndTyped,
+    GxB_MAX_LAND
+);
+
+define_semiring!(MaxLogicalExclusiveOr);
+implement_semiring!(MaxLogicalExclusiveOr, MaxLogicalExclusiveOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxLogicalExclusiveOrTyped,
+    GxB_MAX_LXOR
+);
 
+// MIN
 define_semiring!(MinFirst);
 implement_semiring!(MinFirst, MinFirstTyped);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
@@ -242,26 +284,12 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MIN_SECOND_SEMIRING
 );
 
-// define_semiring!(MinOne);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinOne,
-//     GrB_MIN_ONEB_SEMIRING
-// );
-
-// define_semiring!(MinMIN);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinSecond,
-//     GrB_MIN_MIN_SEMIRING
-// );
-
-define_semiring!(MinMax);
-implement_semiring!(MinMax, MinMaxTyped);
+define_semiring!(MinPair);
+implement_semiring!(MinPair, MinPairTyped);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
     implement_typed_semiring,
-    MinMaxTyped,
-    GrB_MIN_MAX_SEMIRING
+    MinPairTyped,
+    GxB_MIN_PAIR
 );
 
 define_semiring!(MinPlus);
@@ -272,19 +300,21 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MIN_PLUS_SEMIRING
 );
 
-// define_semiring!(MinMinus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinMinus,
-//     GrB_MIN_MINUS_SEMIRING
-// );
+define_semiring!(MinMinus);
+implement_semiring!(MinMinus, MinMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinMinusTyped,
+    GxB_MIN_MINUS
+);
 
-// define_semiring!(MinRightMinus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinRightMinus,
-//     GrB_MIN_RMINUS_SEMIRING
-// );
+define_semiring!(MinReverseMinus);
+implement_semiring!(MinReverseMinus, MinReverseMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinReverseMinusTyped,
+    GxB_MIN_RMINUS
+);
 
 define_semiring!(MinTimes);
 implement_semiring!(MinTimes, MinTimesTyped);
@@ -294,140 +324,164 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MIN_TIMES_SEMIRING
 );
 
-// define_semiring!(MinDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinDivide,
-//     GrB_MIN_DIVIDE_SEMIRING
-// );
-
-// define_semiring!(MinRightDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinRightDivide,
-//     GrB_MIN_RDIVIDE_SEMIRING
-// );
-
-// define_semiring!(MinIsEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsEqualTo,
-//     GrB_MIN_ISEQ_SEMIRING
-// );
-
-// define_semiring!(MinIsNotEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsNotEqualTo,
-//     GrB_MIN_ISNE_SEMIRING
-// );
-
-// define_semiring!(MinIsLessThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsLessThan,
-//     GrB_MIN_ISLT_SEMIRING
-// );
-
-// define_semiring!(MinIsGreaterThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsGreaterThan,
-//     GrB_MIN_ISGT_SEMIRING
-// );
-
-// define_semiring!(MinIsLessThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsLessThanOrEqualTo,
-//     GrB_MIN_ISLE_SEMIRING
-// );
-
-// define_semiring!(MinIsGreaterThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsGreaterThanOrEqualTo,
-//     GrB_MIN_ISGE_SEMIRING
-// );
-
-// define_semiring!(MinLogicalOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinLogicalOr,
-//     GrB_MIN_LOR_SEMIRING
-// );
-
-// define_semiring!(MinLogicalAnd);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinLogicalAnd,
-//     GrB_MIN_LAND_SEMIRING
-// );
-
-// define_semiring!(MinLogicalExclusiveOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinLogicalExclusiveOr,
-//     GrB_MIN_LXOR_SEMIRING
-// );
+define_semiring!(MinDivide);
+implement_semiring!(MinDivide, MinDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinDivideTyped,
+    GxB_MIN_DIV
+);
+
+define_semiring!(MinReverseDivide);
+implement_semiring!(MinReverseDivide, MinReverseDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinReverseDivideTyped,
+    GxB_MIN_RDIV
+);
+
+define_semiring!(MinMin);
+implement_semiring!(MinMin, MinMinTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinMinTyped,
+    GxB_MIN_MIN
+);
+
+define_semiring!(MinMax);
+implement_semiring!(MinMax, MinMaxTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinMaxTyped,
+    GrB_MIN_MAX_SEMIRING
+);
+
+define_semiring!(MinTypedIsEqual);
+implement_semiring!(MinTypedIsEqual, MinTypedIsEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinTypedIsEqualTyped,
+    GxB_MIN_ISEQ
+);
+
+define_semiring!(MinTypedIsNotEqual);
+implement_semiring!(MinTypedIsNotEqual, MinTypedIsNotEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinTypedIsNotEqualTyped,
+    GxB_MIN_ISNE
+);
+
+define_semiring!(MinTypedIsGreaterThan);
+implement_semiring!(MinTypedIsGreaterThan, MinTypedIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinTypedIsGreaterThanTyped,
+    GxB_MIN_ISGT
+);
+
+define_semiring!(MinTypedIsLessThan);
+implement_semiring!(MinTypedIsLessThan, MinTypedIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinTypedIsLessThanTyped,
+    GxB_MIN_ISLT
+);
+
+define_semiring!(MinTypedIsGreaterThanOrEqualTo);
+implement_semiring!(
+    MinTypedIsGreaterThanOrEqualTo,
+    MinTypedIsGreaterThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinTypedIsGreaterThanOrEqualToTyped,
+    GxB_MIN_ISGE
+);
+
+define_semiring!(MinTypedIsLessThanOrEqualTo);
+implement_semiring!(
+    MinTypedIsLessThanOrEqualTo,
+    MinTypedIsLessThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinTypedIsLessThanOrEqualToTyped,
+    GxB_MIN_ISLE
+);
+
+define_semiring!(MinLogicalOr);
+implement_semiring!(MinLogicalOr, MinLogicalOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinLogicalOrTyped,
+    GxB_MIN_LOR
+);
+
+define_semiring!(MinLogicalAnd);
+implement_semiring!(MinLogicalAnd, MinLogicalAndTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinLogicalAndTyped,
+    GxB_MIN_LAND
+);
+
+define_semiring!(MinLogicalExclusiveOr);
+implement_semiring!(MinLogicalExclusiveOr, MinLogicalExclusiveOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinLogicalExclusiveOrTyped,
+    GxB_MIN_LXOR
+);
 
 // PLUS
+define_semiring!(PlusFirst);
+implement_semiring!(PlusFirst, PlusFirstTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusFirstTyped,
+    GxB_PLUS_FIRST
+);
+
+define_semiring!(PlusSecond);
+implement_semiring!(PlusSecond, PlusSecondTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusSecondTyped,
+    GxB_PLUS_SECOND
+);
+
+define_semiring!(PlusPair);
+implement_semiring!(PlusPair, PlusPairTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusPairTyped,
+    GxB_PLUS_PAIR
+);
+
+define_semiring!(PlusPlus);
+implement_semiring!(PlusPlus, PlusPlusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusPlusTyped,
+    GxB_PLUS_PLUS
+);
+
+define_semiring!(PlusMinus);
+implement_semiring!(PlusMinus, PlusMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusMinusTyped,
+    GxB_PLUS_MINUS
+);
 
-// define_semiring!(PlusFirst);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusFirst,
-//     GrB_PLUS_FIRST_SEMIRING
-// );
-
-// define_semiring!(PlusSecond);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusSecond,
-//     GrB_PLUS_SECOND_SEMIRING
-// );
-
-// define_semiring!(PlusOne);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusOne,
-//     GrB_PLUS_ONEB_SEMIRING
-// );
-
-// define_semiring!(PlusPLUS);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusSecond,
-//     GrB_PLUS_PLUS_SEMIRING
-// );
-
-// define_semiring!(PlusMax);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusMax,
-//     GrB_PLUS_MAX_SEMIRING
-// );
-
-// define_semiring!(PlusPlus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusPlus,
-//     GrB_PLUS_PLUS_SEMIRING
-// );
-
-// define_semiring!(PlusPlus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusPlus,
-//     GrB_PLUS_PLUS_SEMIRING
-// );
-
-// define_semiring!(PlusRightPlusus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusRightPlusus,
-//     GrB_PLUS_RPLUSUS_SEMIRING
-// );
+define_semiring!(PlusReverseMinus);
+implement_semiring!(PlusReverseMinus, PlusReverseMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusReverseMinusTyped,
+    GxB_PLUS_RMINUS
+);
 
 define_semiring!(PlusTimes);
 implement_semiring!(PlusTimes, PlusTimesTyped);
@@ -437,236 +491,1605 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_PLUS_TIMES_SEMIRING
 );
 
-// define_semiring!(PlusDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusDivide,
-//     GrB_PLUS_DIVIDE_SEMIRING
-// );
-
-// define_semiring!(PlusRightDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusRightDivide,
-//     GrB_PLUS_RDIVIDE_SEMIRING
-// );
-
-// define_semiring!(PlusIsEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsEqualTo,
-//     GrB_PLUS_ISEQ_SEMIRING
-// );
-
-// define_semiring!(PlusIsNotEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsNotEqualTo,
-//     GrB_PLUS_ISNE_SEMIRING
-// );
-
-// define_semiring!(PlusIsLessThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsLessThan,
-//     GrB_PLUS_ISLT_SEMIRING
-// );
-
-// define_semiring!(PlusIsGreaterThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsGreaterThan,
-//     GrB_PLUS_ISGT_SEMIRING
-// );
-
-// define_semiring!(PlusIsLessThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsLessThanOrEqualTo,
-//     GrB_PLUS_ISLE_SEMIRING
-// );
-
-// define_semiring!(PlusIsGreaterThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsGreaterThanOrEqualTo,
-//     GrB_PLUS_ISGE_SEMIRING
-// );
-
-// define_semiring!(PlusLogicalOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusLogicalOr,
-//     GrB_PLUS_LOR_SEMIRING
-// );
-
-// define_semiring!(PlusLogicalAnd);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusLogicalAnd,
-//     GrB_PLUS_LAND_SEMIRING
-// );
-
-// define_semiring!(PlusLogicalExclusiveOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusLogicalExclusiveOr,
-//     GrB_MIN_LXOR_SEMIRING
-// );
+define_semiring!(PlusDivide);
+implement_semiring!(PlusDivide, PlusDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusDivideTyped,
+    GxB_PLUS_DIV
+);
+
+define_semiring!(PlusReverseDivide);
+implement_semiring!(PlusReverseDivide, PlusReverseDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusReverseDivideTyped,
+    GxB_PLUS_RDIV
+);
+
+define_semiring!(PlusMin);
+implement_semiring!(PlusMin, PlusMinTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusMinTyped,
+    GrB_PLUS_MIN_SEMIRING
+);
+
+define_semiring!(PlusMax);
+implement_semiring!(PlusMax, PlusMaxTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusMaxTyped,
+    GxB_PLUS_MAX
+);
+
+define_semiring!(PlusTypedIsEqual);
+implement_semiring!(PlusTypedIsEqual, PlusTypedIsEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusTypedIsEqualTyped,
+    GxB_PLUS_ISEQ
+);
+
+define_semiring!(PlusTypedIsNotEqual);
+implement_semiring!(PlusTypedIsNotEqual, PlusTypedIsNotEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusTypedIsNotEqualTyped,
+    GxB_PLUS_ISNE
+);
+
+define_semiring!(PlusTypedIsGreaterThan);
+implement_semiring!(PlusTypedIsGreaterThan, PlusTypedIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusTypedIsGreaterThanTyped,
+    GxB_PLUS_ISGT
+);
+
+define_semiring!(PlusTypedIsLessThan);
+implement_semiring!(PlusTypedIsLessThan, PlusTypedIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusTypedIsLessThanTyped,
+    GxB_PLUS_ISLT
+);
+
+define_semiring!(PlusTypedIsGreaterThanOrEqualTo);
+implement_semiring!(
+    PlusTypedIsGreaterThanOrEqualTo,
+    PlusTypedIsGreaterThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusTypedIsGreaterThanOrEqualToTyped,
+    GxB_PLUS_ISGE
+);
+
+define_semiring!(PlusTypedIsLessThanOrEqualTo);
+implement_semiring!(
+    PlusTypedIsLessThanOrEqualTo,
+    PlusTypedIsLessThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusTypedIsLessThanOrEqualToTyped,
+    GxB_PLUS_ISLE
+);
+
+define_semiring!(PlusLogicalOr);
+implement_semiring!(PlusLogicalOr, PlusLogicalOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusLogicalOrTyped,
+    GxB_PLUS_LOR
+);
+
+define_semiring!(PlusLogicalAnd);
+implement_semiring!(PlusLogicalAnd, PlusLogicalAndTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusLogicalAndTyped,
+    GxB_PLUS_LAND
+);
+
+define_semiring!(PlusLogicalExclusiveOr);
+implement_semiring!(PlusLogicalExclusiveOr, PlusLogicalExclusiveOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusLogicalExclusiveOrTyped,
+    GxB_PLUS_LXOR
+);
 
 // TIMES
+define_semiring!(TimesFirst);
+implement_semiring!(TimesFirst, TimesFirstTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesFirstTyped,
+    GxB_TIMES_FIRST
+);
 
-// define_semiring!(TimesFirst);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesFirst,
-//     GrB_TIMES_FIRST_SEMIRING
-// );
-
-// define_semiring!(TimesSecond);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesSecond,
-//     GrB_TIMES_SECOND_SEMIRING
-// );
-
-// define_semiring!(TimesOne);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesOne,
-//     GrB_TIMES_ONEB_SEMIRING
-// );
-
-// define_semiring!(TimesTIMES);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesSecond,
-//     GrB_TIMES_TIMES_SEMIRING
-// );
-
-// define_semiring!(TimesMax);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesMax,
-//     GrB_TIMES_MAX_SEMIRING
-// );
-
-// define_semiring!(TimesTimes);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesTimes,
-//     GrB_TIMES_TIMES_SEMIRING
-// );
-
-// define_semiring!(TimesTimes);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesTimes,
-//     GrB_TIMES_TIMES_SEMIRING
-// );
-
-// define_semiring!(TimesRightTimesus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesRightTimesus,
-//     GrB_TIMES_RTIMESUS_SEMIRING
-// );
-
-// define_semiring!(TimesTimes);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesTimes,
-//     GrB_TIMES_TIMES_SEMIRING
-// );
-
-// define_semiring!(TimesDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesDivide,
-//     GrB_TIMES_DIVIDE_SEMIRING
-// );
-
-// define_semiring!(TimesRightDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesRightDivide,
-//     GrB_TIMES_RDIVIDE_SEMIRING
-// );
-
-// define_semiring!(TimesIsEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsEqualTo,
-//     GrB_TIMES_ISEQ_SEMIRING
-// );
-
-// define_semiring!(TimesIsNotEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsNotEqualTo,
-//     GrB_TIMES_ISNE_SEMIRING
-// );
-
-// define_semiring!(TimesIsLessThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsLessThan,
-//     GrB_TIMES_ISLT_SEMIRING
-// );
-
-// define_semiring!(TimesIsGreaterThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsGreaterThan,
-//     GrB_TIMES_ISGT_SEMIRING
-// );
-
-// define_semiring!(TimesIsLessThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsLessThanOrEqualTo,
-//     GrB_TIMES_ISLE_SEMIRING
-// );
-
-// define_semiring!(TimesIsGreaterThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsGreaterThanOrEqualTo,
-//     GrB_TIMES_ISGE_SEMIRING
-// );
-
-// define_semiring!(TimesLogicalOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesLogicalOr,
-//     GrB_TIMES_LOR_SEMIRING
-// );
-
-// define_semiring!(TimesLogicalAnd);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesLogicalAnd,
-//     GrB_PLUS_LAND_SEMIRING
-// );
-
-// define_semiring!(TimesLogicalExclusiveOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesLogicalExclusiveOr,
-//     GrB_PLUS_LXOR_SEMIRING
-// );
+define_semiring!(TimesSecond);
+implement_semiring!(TimesSecond, TimesSecondTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesSecondTyped,
+    GxB_TIMES_SECOND
+);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+define_semiring!(TimesPair);
+implement_semiring!(TimesPair, TimesPairTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesPairTyped,
+    GxB_TIMES_PAIR
+);
 
-    #[test]
-    fn test_new_semiring() {
-        let semiring = PlusTimes::<i8>::new();
+define_semiring!(TimesPlus);
+implement_semiring!(TimesPlus, TimesPlusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesPlusTyped,
+    GxB_TIMES_PLUS
+);
 
-        unsafe {
-            assert_eq!(semiring.graphblas_type(), GrB_PLUS_TIMES_SEMIRING_INT8);
-            assert_ne!(semiring.graphblas_type(), GrB_PLUS_TIMES_SEMIRING_INT16);
-        }
+define_semiring!(TimesMinus);
+implement_semiring!(TimesMinus, TimesMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesMinusTyped,
+    GxB_TIMES_MINUS
+);
+
+define_semiring!(TimesReverseMinus);
+implement_semiring!(TimesReverseMinus, TimesReverseMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesReverseMinusTyped,
+    GxB_TIMES_RMINUS
+);
+
+define_semiring!(TimesTimes);
+implement_semiring!(TimesTimes, TimesTimesTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesTimesTyped,
+    GxB_TIMES_TIMES
+);
+
+define_semiring!(TimesDivide);
+implement_semiring!(TimesDivide, TimesDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesDivideTyped,
+    GxB_TIMES_DIV
+);
+
+define_semiring!(TimesReverseDivide);
+implement_semiring!(TimesReverseDivide, TimesReverseDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesReverseDivideTyped,
+    GxB_TIMES_RDIV
+);
+
+define_semiring!(TimesMin);
+implement_semiring!(TimesMin, TimesMinTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesMinTyped,
+    GxB_TIMES_MIN
+);
+
+define_semiring!(TimesMax);
+implement_semiring!(TimesMax, TimesMaxTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesMaxTyped,
+    GxB_TIMES_MAX
+);
+
+define_semiring!(TimesTypedIsEqual);
+implement_semiring!(TimesTypedIsEqual, TimesTypedIsEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesTypedIsEqualTyped,
+    GxB_TIMES_ISEQ
+);
+
+define_semiring!(TimesTypedIsNotEqual);
+implement_semiring!(TimesTypedIsNotEqual, TimesTypedIsNotEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesTypedIsNotEqualTyped,
+    GxB_TIMES_ISNE
+);
+
+define_semiring!(TimesTypedIsGreaterThan);
+implement_semiring!(TimesTypedIsGreaterThan, TimesTypedIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesTypedIsGreaterThanTyped,
+    GxB_TIMES_ISGT
+);
+
+define_semiring!(TimesTypedIsLessThan);
+implement_semiring!(TimesTypedIsLessThan, TimesTypedIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesTypedIsLessThanTyped,
+    GxB_TIMES_ISLT
+);
+
+define_semiring!(TimesTypedIsGreaterThanOrEqualTo);
+implement_semiring!(
+    TimesTypedIsGreaterThanOrEqualTo,
+    TimesTypedIsGreaterThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesTypedIsGreaterThanOrEqualToTyped,
+    GxB_TIMES_ISGE
+);
+
+define_semiring!(TimesTypedIsLessThanOrEqualTo);
+implement_semiring!(
+    TimesTypedIsLessThanOrEqualTo,
+    TimesTypedIsLessThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesTypedIsLessThanOrEqualToTyped,
+    GxB_TIMES_ISLE
+);
+
+define_semiring!(TimesLogicalOr);
+implement_semiring!(TimesLogicalOr, TimesLogicalOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesLogicalOrTyped,
+    GxB_TIMES_LOR
+);
+
+define_semiring!(TimesLogicalAnd);
+implement_semiring!(TimesLogicalAnd, TimesLogicalAndTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesLogicalAndTyped,
+    GxB_TIMES_LAND
+);
+
+define_semiring!(TimesLogicalExclusiveOr);
+implement_semiring!(TimesLogicalExclusiveOr, TimesLogicalExclusiveOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesLogicalExclusiveOrTyped,
+    GxB_TIMES_LXOR
+);
+
+// ANY
+define_semiring!(AnyFirst);
+implement_semiring!(AnyFirst, AnyFirstTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyFirstTyped,
+    GxB_ANY_FIRST
+);
+
+define_semiring!(AnySecond);
+implement_semiring!(AnySecond, AnySecondTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnySecondTyped,
+    GxB_ANY_SECOND
+);
+
+define_semiring!(AnyPair);
+implement_semiring!(AnyPair, AnyPairTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyPairTyped,
+    GxB_ANY_PAIR
+);
+
+define_semiring!(AnyPlus);
+implement_semiring!(AnyPlus, AnyPlusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyPlusTyped,
+    GxB_ANY_PLUS
+);
+
+define_semiring!(AnyMinus);
+implement_semiring!(AnyMinus, AnyMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyMinusTyped,
+    GxB_ANY_MINUS
+);
+
+define_semiring!(AnyReverseMinus);
+implement_semiring!(AnyReverseMinus, AnyReverseMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyReverseMinusTyped,
+    GxB_ANY_RMINUS
+);
+
+define_semiring!(AnyTimes);
+implement_semiring!(AnyTimes, AnyTimesTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyTimesTyped,
+    GxB_ANY_TIMES
+);
+
+define_semiring!(AnyDivide);
+implement_semiring!(AnyDivide, AnyDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyDivideTyped,
+    GxB_ANY_DIV
+);
+
+define_semiring!(AnyReverseDivide);
+implement_semiring!(AnyReverseDivide, AnyReverseDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyReverseDivideTyped,
+    GxB_ANY_RDIV
+);
+
+define_semiring!(AnyMin);
+implement_semiring!(AnyMin, AnyMinTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyMinTyped,
+    GxB_ANY_MIN
+);
+
+define_semiring!(AnyMax);
+implement_semiring!(AnyMax, AnyMaxTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyMaxTyped,
+    GxB_ANY_MAX
+);
+
+define_semiring!(AnyTypedIsEqual);
+implement_semiring!(AnyTypedIsEqual, AnyTypedIsEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyTypedIsEqualTyped,
+    GxB_ANY_ISEQ
+);
+
+define_semiring!(AnyTypedIsNotEqual);
+implement_semiring!(AnyTypedIsNotEqual, AnyTypedIsNotEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyTypedIsNotEqualTyped,
+    GxB_ANY_ISNE
+);
+
+define_semiring!(AnyTypedIsGreaterThan);
+implement_semiring!(AnyTypedIsGreaterThan, AnyTypedIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyTypedIsGreaterThanTyped,
+    GxB_ANY_ISGT
+);
+
+define_semiring!(AnyTypedIsLessThan);
+implement_semiring!(AnyTypedIsLessThan, AnyTypedIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyTypedIsLessThanTyped,
+    GxB_ANY_ISLT
+);
+
+define_semiring!(AnyTypedIsGreaterThanOrEqualTo);
+implement_semiring!(
+    AnyTypedIsGreaterThanOrEqualTo,
+    AnyTypedIsGreaterThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyTypedIsGreaterThanOrEqualToTyped,
+    GxB_ANY_ISGE
+);
+
+define_semiring!(AnyTypedIsLessThanOrEqualTo);
+implement_semiring!(
+    AnyTypedIsLessThanOrEqualTo,
+    AnyTypedIsLessThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyTypedIsLessThanOrEqualToTyped,
+    GxB_ANY_ISLE
+);
+
+define_semiring!(AnyLogicalOr);
+implement_semiring!(AnyLogicalOr, AnyLogicalOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyLogicalOrTyped,
+    GxB_ANY_LOR
+);
+
+define_semiring!(AnyLogicalAnd);
+implement_semiring!(AnyLogicalAnd, AnyLogicalAndTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyLogicalAndTyped,
+    GxB_ANY_LAND
+);
+
+define_semiring!(AnyLogicalExclusiveOr);
+implement_semiring!(AnyLogicalExclusiveOr, AnyLogicalExclusiveOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyLogicalExclusiveOrTyped,
+    GxB_ANY_LXOR
+);
+
+define_semiring!(AnyIsEqual);
+implement_semiring!(AnyIsEqual, AnyIsEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyIsEqualTyped,
+    GxB_ANY_EQ
+);
+
+define_semiring!(AnyIsNotEqual);
+implement_semiring!(AnyIsNotEqual, AnyIsNotEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyIsNotEqualTyped,
+    GxB_ANY_NE
+);
+
+define_semiring!(AnyIsGreaterThan);
+implement_semiring!(AnyIsGreaterThan, AnyIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyIsGreaterThanTyped,
+    GxB_ANY_GT
+);
+
+define_semiring!(AnyIsLessThan);
+implement_semiring!(AnyIsLessThan, AnyIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyIsLessThanTyped,
+    GxB_ANY_LT
+);
+
+define_semiring!(AnyIsGreaterThanOrEqualTo);
+implement_semiring!(AnyIsGreaterThanOrEqualTo, AnyIsGreaterThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyIsGreaterThanOrEqualToTyped,
+    GxB_ANY_GE
+);
+
+define_semiring!(AnyIsLessThanOrEqualTo);
+implement_semiring!(AnyIsLessThanOrEqualTo, AnyIsLessThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyIsLessThanOrEqualToTyped,
+    GxB_ANY_LE
+);
+
+// LOR
+define_semiring!(LogicalOrFirst);
+implement_semiring!(LogicalOrFirst, LogicalOrFirstTyped);
+implement_typed_semiring!(LogicalOrFirstTyped, GxB_LOR_FIRST_BOOL, bool);
+
+define_semiring!(LogicalOrSecond);
+implement_semiring!(LogicalOrSecond, LogicalOrSecondTyped);
+implement_typed_semiring!(LogicalOrSecondTyped, GxB_LOR_SECOND_BOOL, bool);
+
+define_semiring!(LogicalOrPair);
+implement_semiring!(LogicalOrPair, LogicalOrPairTyped);
+implement_typed_semiring!(LogicalOrPairTyped, GxB_LOR_PAIR_BOOL, bool);
+
+define_semiring!(LogicalOrLogicalOr);
+implement_semiring!(LogicalOrLogicalOr, LogicalOrLogicalOrTyped);
+implement_typed_semiring!(LogicalOrLogicalOrTyped, GxB_LOR_LOR_BOOL, bool);
+
+define_semiring!(LogicalOrLogicalAnd);
+implement_semiring!(LogicalOrLogicalAnd, LogicalOrLogicalAndTyped);
+implement_typed_semiring!(LogicalOrLogicalAndTyped, GrB_LOR_LAND_SEMIRING_BOOL, bool);
+
+define_semiring!(LogicalOrLogicalExclusiveOr);
+implement_semiring!(
+    LogicalOrLogicalExclusiveOr,
+    LogicalOrLogicalExclusiveOrTyped
+);
+implement_typed_semiring!(LogicalOrLogicalExclusiveOrTyped, GxB_LOR_LXOR_BOOL, bool);
+
+define_semiring!(LogicalOrIsEqual);
+implement_semiring!(LogicalOrIsEqual, LogicalOrIsEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalOrIsEqualTyped,
+    GxB_LOR_EQ
+);
+
+define_semiring!(LogicalOrIsNotEqual);
+implement_semiring!(LogicalOrIsNotEqual, LogicalOrIsNotEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    LogicalOrIsNotEqualTyped,
+    GxB_LOR_NE
+);
+
+define_semiring!(LogicalOrIsGreaterThan);
+implement_semiring!(LogicalOrIsGreaterThan, LogicalOrIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalOrIsGreaterThanTyped,
+    GxB_LOR_GT
+);
+
+define_semiring!(LogicalOrIsLessThan);
+implement_semiring!(LogicalOrIsLessThan, LogicalOrIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalOrIsLessThanTyped,
+    GxB_LOR_LT
+);
+
+define_semiring!(LogicalOrIsGreaterThanOrEqualTo);
+implement_semiring!(
+    LogicalOrIsGreaterThanOrEqualTo,
+    LogicalOrIsGreaterThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalOrIsGreaterThanOrEqualToTyped,
+    GxB_LOR_GE
+);
+
+define_semiring!(LogicalOrIsLessThanOrEqualTo);
+implement_semiring!(
+    LogicalOrIsLessThanOrEqualTo,
+    LogicalOrIsLessThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalOrIsLessThanOrEqualToTyped,
+    GxB_LOR_LE
+);
+
+// LAND
+define_semiring!(LogicalAndFirst);
+implement_semiring!(LogicalAndFirst, LogicalAndFirstTyped);
+implement_typed_semiring!(LogicalAndFirstTyped, GxB_LAND_FIRST_BOOL, bool);
+
+define_semiring!(LogicalAndSecond);
+implement_semiring!(LogicalAndSecond, LogicalAndSecondTyped);
+implement_typed_semiring!(LogicalAndSecondTyped, GxB_LAND_SECOND_BOOL, bool);
+
+define_semiring!(LogicalAndPair);
+implement_semiring!(LogicalAndPair, LogicalAndPairTyped);
+implement_typed_semiring!(LogicalAndPairTyped, GxB_LAND_PAIR_BOOL, bool);
+
+define_semiring!(LogicalAndLogicalOr);
+implement_semiring!(LogicalAndLogicalOr, LogicalAndLogicalOrTyped);
+implement_typed_semiring!(LogicalAndLogicalOrTyped, GrB_LAND_LOR_SEMIRING_BOOL, bool);
+
+define_semiring!(LogicalAndLogicalAnd);
+implement_semiring!(LogicalAndLogicalAnd, LogicalAndLogicalAndTyped);
+implement_typed_semiring!(LogicalAndLogicalAndTyped, GxB_LAND_LAND_BOOL, bool);
+
+define_semiring!(LogicalAndLogicalExclusiveOr);
+implement_semiring!(
+    LogicalAndLogicalExclusiveOr,
+    LogicalAndLogicalExclusiveOrTyped
+);
+implement_typed_semiring!(LogicalAndLogicalExclusiveOrTyped, GxB_LAND_LXOR_BOOL, bool);
+
+define_semiring!(LogicalAndIsEqual);
+implement_semiring!(LogicalAndIsEqual, LogicalAndIsEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalAndIsEqualTyped,
+    GxB_LAND_EQ
+);
+
+define_semiring!(LogicalAndIsNotEqual);
+implement_semiring!(LogicalAndIsNotEqual, LogicalAndIsNotEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    LogicalAndIsNotEqualTyped,
+    GxB_LAND_NE
+);
+
+define_semiring!(LogicalAndIsGreaterThan);
+implement_semiring!(LogicalAndIsGreaterThan, LogicalAndIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalAndIsGreaterThanTyped,
+    GxB_LAND_GT
+);
+
+define_semiring!(LogicalAndIsLessThan);
+implement_semiring!(LogicalAndIsLessThan, LogicalAndIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalAndIsLessThanTyped,
+    GxB_LAND_LT
+);
+
+define_semiring!(LogicalAndIsGreaterThanOrEqualTo);
+implement_semiring!(
+    LogicalAndIsGreaterThanOrEqualTo,
+    LogicalAndIsGreaterThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalAndIsGreaterThanOrEqualToTyped,
+    GxB_LAND_GE
+);
+
+define_semiring!(LogicalAndIsLessThanOrEqualTo);
+implement_semiring!(
+    LogicalAndIsLessThanOrEqualTo,
+    LogicalAndIsLessThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalAndIsLessThanOrEqualToTyped,
+    GxB_LAND_LE
+);
+
+// LXOR
+define_semiring!(LogicalExclusiveOrFirst);
+implement_semiring!(LogicalExclusiveOrFirst, LogicalExclusiveOrFirstTyped);
+implement_typed_semiring!(LogicalExclusiveOrFirstTyped, GxB_LXOR_FIRST_BOOL, bool);
+
+define_semiring!(LogicalExclusiveOrSecond);
+implement_semiring!(LogicalExclusiveOrSecond, LogicalExclusiveOrSecondTyped);
+implement_typed_semiring!(LogicalExclusiveOrSecondTyped, GxB_LXOR_SECOND_BOOL, bool);
+
+define_semiring!(LogicalExclusiveOrPair);
+implement_semiring!(LogicalExclusiveOrPair, LogicalExclusiveOrPairTyped);
+implement_typed_semiring!(LogicalExclusiveOrPairTyped, GxB_LXOR_PAIR_BOOL, bool);
+
+define_semiring!(LogicalExclusiveOrLogicalOr);
+implement_semiring!(
+    LogicalExclusiveOrLogicalOr,
+    LogicalExclusiveOrLogicalOrTyped
+);
+implement_typed_semiring!(LogicalExclusiveOrLogicalOrTyped, GxB_LXOR_LOR_BOOL, bool);
+
+define_semiring!(LogicalExclusiveOrLogicalAnd);
+implement_semiring!(
+    LogicalExclusiveOrLogicalAnd,
+    LogicalExclusiveOrLogicalAndTyped
+);
+implement_typed_semiring!(
+    LogicalExclusiveOrLogicalAndTyped,
+    GrB_LXOR_LAND_SEMIRING_BOOL,
+    bool
+);
+
+define_semiring!(LogicalExclusiveOrLogicalExclusiveOr);
+implement_semiring!(
+    LogicalExclusiveOrLogicalExclusiveOr,
+    LogicalExclusiveOrLogicalExclusiveOrTyped
+);
+implement_typed_semiring!(
+    LogicalExclusiveOrLogicalExclusiveOrTyped,
+    GxB_LXOR_LXOR_BOOL,
+    bool
+);
+
+define_semiring!(LogicalExclusiveOrIsEqual);
+implement_semiring!(LogicalExclusiveOrIsEqual, LogicalExclusiveOrIsEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveOrIsEqualTyped,
+    GxB_LXOR_EQ
+);
+
+define_semiring!(LogicalExclusiveOrIsNotEqual);
+implement_semiring!(
+    LogicalExclusiveOrIsNotEqual,
+    LogicalExclusiveOrIsNotEqualTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    LogicalExclusiveOrIsNotEqualTyped,
+    GxB_LXOR_NE
+);
+
+define_semiring!(LogicalExclusiveOrIsGreaterThan);
+implement_semiring!(
+    LogicalExclusiveOrIsGreaterThan,
+    LogicalExclusiveOrIsGreaterThanTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveOrIsGreaterThanTyped,
+    GxB_LXOR_GT
+);
+
+define_semiring!(LogicalExclusiveOrIsLessThan);
+implement_semiring!(
+    LogicalExclusiveOrIsLessThan,
+    LogicalExclusiveOrIsLessThanTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveOrIsLessThanTyped,
+    GxB_LXOR_LT
+);
+
+define_semiring!(LogicalExclusiveOrIsGreaterThanOrEqualTo);
+implement_semiring!(
+    LogicalExclusiveOrIsGreaterThanOrEqualTo,
+    LogicalExclusiveOrIsGreaterThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveOrIsGreaterThanOrEqualToTyped,
+    GxB_LXOR_GE
+);
+
+define_semiring!(LogicalExclusiveOrIsLessThanOrEqualTo);
+implement_semiring!(
+    LogicalExclusiveOrIsLessThanOrEqualTo,
+    LogicalExclusiveOrIsLessThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveOrIsLessThanOrEqualToTyped,
+    GxB_LXOR_LE
+);
+
+// EQ (LXNOR)
+define_semiring!(EqualFirst);
+implement_semiring!(EqualFirst, EqualFirstTyped);
+implement_typed_semiring!(EqualFirstTyped, GxB_EQ_FIRST_BOOL, bool);
+
+define_semiring!(EqualSecond);
+implement_semiring!(EqualSecond, EqualSecondTyped);
+implement_typed_semiring!(EqualSecondTyped, GxB_EQ_SECOND_BOOL, bool);
+
+define_semiring!(EqualPair);
+implement_semiring!(EqualPair, EqualPairTyped);
+implement_typed_semiring!(EqualPairTyped, GxB_EQ_PAIR_BOOL, bool);
+
+define_semiring!(EqualLogicalOr);
+implement_semiring!(EqualLogicalOr, EqualLogicalOrTyped);
+implement_typed_semiring!(EqualLogicalOrTyped, GrB_LXNOR_LOR_SEMIRING_BOOL, bool);
+
+define_semiring!(EqualLogicalAnd);
+implement_semiring!(EqualLogicalAnd, EqualLogicalAndTyped);
+implement_typed_semiring!(EqualLogicalAndTyped, GxB_EQ_LAND_BOOL, bool);
+
+define_semiring!(EqualLogicalExclusiveOr);
+implement_semiring!(EqualLogicalExclusiveOr, EqualLogicalExclusiveOrTyped);
+implement_typed_semiring!(EqualLogicalExclusiveOrTyped, GxB_EQ_LXOR_BOOL, bool);
+
+define_semiring!(EqualIsEqual);
+implement_semiring!(EqualIsEqual, EqualIsEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    EqualIsEqualTyped,
+    GxB_EQ_EQ
+);
+
+define_semiring!(EqualIsNotEqual);
+implement_semiring!(EqualIsNotEqual, EqualIsNotEqualTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    EqualIsNotEqualTyped,
+    GxB_EQ_NE
+);
+
+define_semiring!(EqualIsGreaterThan);
+implement_semiring!(EqualIsGreaterThan, EqualIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    EqualIsGreaterThanTyped,
+    GxB_EQ_GT
+);
+
+define_semiring!(EqualIsLessThan);
+implement_semiring!(EqualIsLessThan, EqualIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    EqualIsLessThanTyped,
+    GxB_EQ_LT
+);
+
+define_semiring!(EqualIsGreaterThanOrEqualTo);
+implement_semiring!(
+    EqualIsGreaterThanOrEqualTo,
+    EqualIsGreaterThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    EqualIsGreaterThanOrEqualToTyped,
+    GxB_EQ_GE
+);
+
+define_semiring!(EqualIsLessThanOrEqualTo);
+implement_semiring!(EqualIsLessThanOrEqualTo, EqualIsLessThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    EqualIsLessThanOrEqualToTyped,
+    GxB_EQ_LE
+);
+
+// BOR
+define_semiring!(BitwiseLogicalOrBitwiseLogicalOr, UnsignedInteger);
+implement_semiring!(
+    BitwiseLogicalOrBitwiseLogicalOr,
+    BitwiseLogicalOrBitwiseLogicalOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalOrBitwiseLogicalOrTyped,
+    GxB_BOR_BOR
+);
+
+define_semiring!(BitwiseLogicalOrBitwiseLogicalAnd, UnsignedInteger);
+implement_semiring!(
+    BitwiseLogicalOrBitwiseLogicalAnd,
+    BitwiseLogicalOrBitwiseLogicalAndTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalOrBitwiseLogicalAndTyped,
+    GxB_BOR_BAND
+);
+
+define_semiring!(BitwiseLogicalOrBitwiseLogicalExclusiveOr, UnsignedInteger);
+implement_semiring!(
+    BitwiseLogicalOrBitwiseLogicalExclusiveOr,
+    BitwiseLogicalOrBitwiseLogicalExclusiveOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalOrBitwiseLogicalExclusiveOrTyped,
+    GxB_BOR_BXOR
+);
+
+define_semiring!(
+    BitwiseLogicalOrBitwiseLogicalExclusiveNotOr,
+    UnsignedInteger
+);
+implement_semiring!(
+    BitwiseLogicalOrBitwiseLogicalExclusiveNotOr,
+    BitwiseLogicalOrBitwiseLogicalExclusiveNotOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalOrBitwiseLogicalExclusiveNotOrTyped,
+    GxB_BOR_BXNOR
+);
+
+// BAND
+define_semiring!(BitwiseLogicalAndBitwiseLogicalOr, UnsignedInteger);
+implement_semiring!(
+    BitwiseLogicalAndBitwiseLogicalOr,
+    BitwiseLogicalAndBitwiseLogicalOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalAndBitwiseLogicalOrTyped,
+    GxB_BAND_BOR
+);
+
+define_semiring!(BitwiseLogicalAndBitwiseLogicalAnd, UnsignedInteger);
+implement_semiring!(
+    BitwiseLogicalAndBitwiseLogicalAnd,
+    BitwiseLogicalAndBitwiseLogicalAndTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalAndBitwiseLogicalAndTyped,
+    GxB_BAND_BAND
+);
+
+define_semiring!(BitwiseLogicalAndBitwiseLogicalExclusiveOr, UnsignedInteger);
+implement_semiring!(
+    BitwiseLogicalAndBitwiseLogicalExclusiveOr,
+    BitwiseLogicalAndBitwiseLogicalExclusiveOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalAndBitwiseLogicalExclusiveOrTyped,
+    GxB_BAND_BXOR
+);
+
+define_semiring!(
+    BitwiseLogicalAndBitwiseLogicalExclusiveNotOr,
+    UnsignedInteger
+);
+implement_semiring!(
+    BitwiseLogicalAndBitwiseLogicalExclusiveNotOr,
+    BitwiseLogicalAndBitwiseLogicalExclusiveNotOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalAndBitwiseLogicalExclusiveNotOrTyped,
+    GxB_BAND_BXNOR
+);
+
+// BXOR
+define_semiring!(BitwiseLogicalExclusiveOrBitwiseLogicalOr, UnsignedInteger);
+implement_semiring!(
+    BitwiseLogicalExclusiveOrBitwiseLogicalOr,
+    BitwiseLogicalExclusiveOrBitwiseLogicalOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalExclusiveOrBitwiseLogicalOrTyped,
+    GxB_BXOR_BOR
+);
+
+define_semiring!(BitwiseLogicalExclusiveOrBitwiseLogicalAnd, UnsignedInteger);
+implement_semiring!(
+    BitwiseLogicalExclusiveOrBitwiseLogicalAnd,
+    BitwiseLogicalExclusiveOrBitwiseLogicalAndTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalExclusiveOrBitwiseLogicalAndTyped,
+    GxB_BXOR_BAND
+);
+
+define_semiring!(
+    BitwiseLogicalExclusiveOrBitwiseLogicalExclusiveOr,
+    UnsignedInteger
+);
+implement_semiring!(
+    BitwiseLogicalExclusiveOrBitwiseLogicalExclusiveOr,
+    BitwiseLogicalExclusiveOrBitwiseLogicalExclusiveOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalExclusiveOrBitwiseLogicalExclusiveOrTyped,
+    GxB_BXOR_BXOR
+);
+
+define_semiring!(
+    BitwiseLogicalExclusiveOrBitwiseLogicalExclusiveNotOr,
+    UnsignedInteger
+);
+implement_semiring!(
+    BitwiseLogicalExclusiveOrBitwiseLogicalExclusiveNotOr,
+    BitwiseLogicalExclusiveOrBitwiseLogicalExclusiveNotOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalExclusiveOrBitwiseLogicalExclusiveNotOrTyped,
+    GxB_BXOR_BXNOR
+);
+
+// BXNOR
+define_semiring!(
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalOr,
+    UnsignedInteger
+);
+implement_semiring!(
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalOr,
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalOrTyped,
+    GxB_BXNOR_BOR
+);
+
+define_semiring!(
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalAnd,
+    UnsignedInteger
+);
+implement_semiring!(
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalAnd,
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalAndTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalAndTyped,
+    GxB_BXNOR_BAND
+);
+
+define_semiring!(
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalExclusiveOr,
+    UnsignedInteger
+);
+implement_semiring!(
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalExclusiveOr,
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalExclusiveOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalExclusiveOrTyped,
+    GxB_BXNOR_BXOR
+);
+
+define_semiring!(
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalExclusiveNotOr,
+    UnsignedInteger
+);
+implement_semiring!(
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalExclusiveNotOr,
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalExclusiveNotOrTyped,
+    UnsignedInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitwiseLogicalExclusiveNotOrBitwiseLogicalExclusiveNotOrTyped,
+    GxB_BXNOR_BXNOR
+);
+
+// POSITIONAL
+// The multiplication operator returns the index of an argument instead of its value.
+// For z = A(i,k) * B(k,j): FIRSTI returns i, FIRSTJ returns k, SECONDI returns k, and SECONDJ returns j.
+// The one-based forms add one to the index.
+
+define_semiring!(MinRowIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MinRowIndexFirstArgument,
+    MinRowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinRowIndexFirstArgumentTyped,
+    GxB_MIN_FIRSTI
+);
+
+define_semiring!(MinOneBasedRowIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MinOneBasedRowIndexFirstArgument,
+    MinOneBasedRowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinOneBasedRowIndexFirstArgumentTyped,
+    GxB_MIN_FIRSTI1
+);
+
+define_semiring!(MinColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MinColumnIndexFirstArgument,
+    MinColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinColumnIndexFirstArgumentTyped,
+    GxB_MIN_FIRSTJ
+);
+
+define_semiring!(MinOneBasedColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MinOneBasedColumnIndexFirstArgument,
+    MinOneBasedColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinOneBasedColumnIndexFirstArgumentTyped,
+    GxB_MIN_FIRSTJ1
+);
+
+define_semiring!(MinRowIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MinRowIndexSecondArgument,
+    MinRowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinRowIndexSecondArgumentTyped,
+    GxB_MIN_SECONDI
+);
+
+define_semiring!(MinOneBasedRowIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MinOneBasedRowIndexSecondArgument,
+    MinOneBasedRowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinOneBasedRowIndexSecondArgumentTyped,
+    GxB_MIN_SECONDI1
+);
+
+define_semiring!(MinColumnIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MinColumnIndexSecondArgument,
+    MinColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinColumnIndexSecondArgumentTyped,
+    GxB_MIN_SECONDJ
+);
+
+define_semiring!(MinOneBasedColumnIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MinOneBasedColumnIndexSecondArgument,
+    MinOneBasedColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinOneBasedColumnIndexSecondArgumentTyped,
+    GxB_MIN_SECONDJ1
+);
+
+define_semiring!(MaxRowIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MaxRowIndexFirstArgument,
+    MaxRowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxRowIndexFirstArgumentTyped,
+    GxB_MAX_FIRSTI
+);
+
+define_semiring!(MaxOneBasedRowIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MaxOneBasedRowIndexFirstArgument,
+    MaxOneBasedRowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxOneBasedRowIndexFirstArgumentTyped,
+    GxB_MAX_FIRSTI1
+);
+
+define_semiring!(MaxColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MaxColumnIndexFirstArgument,
+    MaxColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxColumnIndexFirstArgumentTyped,
+    GxB_MAX_FIRSTJ
+);
+
+define_semiring!(MaxOneBasedColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MaxOneBasedColumnIndexFirstArgument,
+    MaxOneBasedColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxOneBasedColumnIndexFirstArgumentTyped,
+    GxB_MAX_FIRSTJ1
+);
+
+define_semiring!(MaxRowIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MaxRowIndexSecondArgument,
+    MaxRowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxRowIndexSecondArgumentTyped,
+    GxB_MAX_SECONDI
+);
+
+define_semiring!(MaxOneBasedRowIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MaxOneBasedRowIndexSecondArgument,
+    MaxOneBasedRowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxOneBasedRowIndexSecondArgumentTyped,
+    GxB_MAX_SECONDI1
+);
+
+define_semiring!(MaxColumnIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MaxColumnIndexSecondArgument,
+    MaxColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxColumnIndexSecondArgumentTyped,
+    GxB_MAX_SECONDJ
+);
+
+define_semiring!(MaxOneBasedColumnIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    MaxOneBasedColumnIndexSecondArgument,
+    MaxOneBasedColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxOneBasedColumnIndexSecondArgumentTyped,
+    GxB_MAX_SECONDJ1
+);
+
+define_semiring!(AnyRowIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    AnyRowIndexFirstArgument,
+    AnyRowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyRowIndexFirstArgumentTyped,
+    GxB_ANY_FIRSTI
+);
+
+define_semiring!(AnyOneBasedRowIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    AnyOneBasedRowIndexFirstArgument,
+    AnyOneBasedRowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyOneBasedRowIndexFirstArgumentTyped,
+    GxB_ANY_FIRSTI1
+);
+
+define_semiring!(AnyColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    AnyColumnIndexFirstArgument,
+    AnyColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyColumnIndexFirstArgumentTyped,
+    GxB_ANY_FIRSTJ
+);
+
+define_semiring!(AnyOneBasedColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    AnyOneBasedColumnIndexFirstArgument,
+    AnyOneBasedColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyOneBasedColumnIndexFirstArgumentTyped,
+    GxB_ANY_FIRSTJ1
+);
+
+define_semiring!(AnyRowIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    AnyRowIndexSecondArgument,
+    AnyRowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyRowIndexSecondArgumentTyped,
+    GxB_ANY_SECONDI
+);
+
+define_semiring!(AnyOneBasedRowIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    AnyOneBasedRowIndexSecondArgument,
+    AnyOneBasedRowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyOneBasedRowIndexSecondArgumentTyped,
+    GxB_ANY_SECONDI1
+);
+
+define_semiring!(AnyColumnIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    AnyColumnIndexSecondArgument,
+    AnyColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyColumnIndexSecondArgumentTyped,
+    GxB_ANY_SECONDJ
+);
+
+define_semiring!(AnyOneBasedColumnIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    AnyOneBasedColumnIndexSecondArgument,
+    AnyOneBasedColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyOneBasedColumnIndexSecondArgumentTyped,
+    GxB_ANY_SECONDJ1
+);
+
+define_semiring!(PlusRowIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    PlusRowIndexFirstArgument,
+    PlusRowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusRowIndexFirstArgumentTyped,
+    GxB_PLUS_FIRSTI
+);
+
+define_semiring!(PlusOneBasedRowIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    PlusOneBasedRowIndexFirstArgument,
+    PlusOneBasedRowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusOneBasedRowIndexFirstArgumentTyped,
+    GxB_PLUS_FIRSTI1
+);
+
+define_semiring!(PlusColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    PlusColumnIndexFirstArgument,
+    PlusColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusColumnIndexFirstArgumentTyped,
+    GxB_PLUS_FIRSTJ
+);
+
+define_semiring!(PlusOneBasedColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    PlusOneBasedColumnIndexFirstArgument,
+    PlusOneBasedColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusOneBasedColumnIndexFirstArgumentTyped,
+    GxB_PLUS_FIRSTJ1
+);
+
+define_semiring!(PlusRowIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    PlusRowIndexSecondArgument,
+    PlusRowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusRowIndexSecondArgumentTyped,
+    GxB_PLUS_SECONDI
+);
+
+define_semiring!(PlusOneBasedRowIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    PlusOneBasedRowIndexSecondArgument,
+    PlusOneBasedRowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusOneBasedRowIndexSecondArgumentTyped,
+    GxB_PLUS_SECONDI1
+);
+
+define_semiring!(PlusColumnIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    PlusColumnIndexSecondArgument,
+    PlusColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusColumnIndexSecondArgumentTyped,
+    GxB_PLUS_SECONDJ
+);
+
+define_semiring!(PlusOneBasedColumnIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    PlusOneBasedColumnIndexSecondArgument,
+    PlusOneBasedColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusOneBasedColumnIndexSecondArgumentTyped,
+    GxB_PLUS_SECONDJ1
+);
+
+define_semiring!(TimesRowIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    TimesRowIndexFirstArgument,
+    TimesRowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesRowIndexFirstArgumentTyped,
+    GxB_TIMES_FIRSTI
+);
+
+define_semiring!(TimesOneBasedRowIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    TimesOneBasedRowIndexFirstArgument,
+    TimesOneBasedRowIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesOneBasedRowIndexFirstArgumentTyped,
+    GxB_TIMES_FIRSTI1
+);
+
+define_semiring!(TimesColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    TimesColumnIndexFirstArgument,
+    TimesColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesColumnIndexFirstArgumentTyped,
+    GxB_TIMES_FIRSTJ
+);
+
+define_semiring!(TimesOneBasedColumnIndexFirstArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    TimesOneBasedColumnIndexFirstArgument,
+    TimesOneBasedColumnIndexFirstArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesOneBasedColumnIndexFirstArgumentTyped,
+    GxB_TIMES_FIRSTJ1
+);
+
+define_semiring!(TimesRowIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    TimesRowIndexSecondArgument,
+    TimesRowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesRowIndexSecondArgumentTyped,
+    GxB_TIMES_SECONDI
+);
+
+define_semiring!(TimesOneBasedRowIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    TimesOneBasedRowIndexSecondArgument,
+    TimesOneBasedRowIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesOneBasedRowIndexSecondArgumentTyped,
+    GxB_TIMES_SECONDI1
+);
+
+define_semiring!(TimesColumnIndexSecondArgument, GraphBlasIndexInteger);
+implement_semiring!(
+    TimesColumnIndexSecondArgument,
+    TimesColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesColumnIndexSecondArgumentTyped,
+    GxB_TIMES_SECONDJ
+);
+
+define_semiring!(
+    TimesOneBasedColumnIndexSecondArgument,
+    GraphBlasIndexInteger
+);
+implement_semiring!(
+    TimesOneBasedColumnIndexSecondArgument,
+    TimesOneBasedColumnIndexSecondArgumentTyped,
+    GraphBlasIndexInteger
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesOneBasedColumnIndexSecondArgumentTyped,
+    GxB_TIMES_SECONDJ1
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_semiring() {
+        let semiring = PlusTimes::<i8>::new();
+
+        unsafe {
+            assert_eq!(semiring.graphblas_type(), GrB_PLUS_TIMES_SEMIRING_INT8);
+            assert_ne!(semiring.graphblas_type(), GrB_PLUS_TIMES_SEMIRING_INT16);
+        }
+    }
+
+    #[test]
+    fn test_new_boolean_semiring() {
+        let semiring = LogicalOrLogicalAnd::<bool>::new();
+
+        assert_eq!(semiring.graphblas_type(), unsafe {
+            GrB_LOR_LAND_SEMIRING_BOOL
+        });
+    }
+
+    #[test]
+    fn test_new_structural_semiring() {
+        let semiring = AnyPair::<bool>::new();
+        assert_eq!(semiring.graphblas_type(), unsafe { GxB_ANY_PAIR_BOOL });
+
+        let semiring = PlusPair::<usize>::new();
+        assert_eq!(semiring.graphblas_type(), unsafe { GxB_PLUS_PAIR_UINT64 });
+    }
+
+    #[test]
+    fn test_new_comparison_semiring() {
+        let semiring = MaxTypedIsLessThan::<f32>::new();
+        assert_eq!(semiring.graphblas_type(), unsafe { GxB_MAX_ISLT_FP32 });
+
+        let semiring = LogicalOrIsEqual::<i16>::new();
+        assert_eq!(semiring.graphblas_type(), unsafe { GxB_LOR_EQ_INT16 });
+    }
+
+    #[test]
+    fn test_new_bitwise_semiring() {
+        let semiring = BitwiseLogicalOrBitwiseLogicalAnd::<u8>::new();
+
+        assert_eq!(semiring.graphblas_type(), unsafe { GxB_BOR_BAND_UINT8 });
+    }
+
+    #[test]
+    fn test_new_positional_semiring() {
+        let semiring = AnyRowIndexSecondArgument::<i64>::new();
+        assert_eq!(semiring.graphblas_type(), unsafe { GxB_ANY_SECONDI_INT64 });
+
+        let semiring = MinOneBasedColumnIndexFirstArgument::<i32>::new();
+        assert_eq!(semiring.graphblas_type(), unsafe { GxB_MIN_FIRSTJ1_INT32 });
     }
 }
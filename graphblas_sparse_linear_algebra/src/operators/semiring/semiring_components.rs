@@ -0,0 +1,115 @@
+use std::mem::MaybeUninit;
+
+use crate::context::{CallGraphBlasContext, Context};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::*;
+use crate::operators::binary_operator::BinaryOperatorHandle;
+use crate::operators::monoid::MonoidHandle;
+use crate::value_type::ValueType;
+
+use super::Semiring;
+
+/// Retrieves the addition monoid and the multiplication operator a semiring was defined with.
+pub trait GetSemiringComponents<EvaluationDomain: ValueType> {
+    fn addition(
+        &self,
+        context: &Context,
+    ) -> Result<MonoidHandle<'_, EvaluationDomain>, SparseLinearAlgebraError>;
+
+    fn multiplication(
+        &self,
+        context: &Context,
+    ) -> Result<BinaryOperatorHandle<'_, EvaluationDomain>, SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType, S: Semiring<EvaluationDomain>>
+    GetSemiringComponents<EvaluationDomain> for S
+{
+    fn addition(
+        &self,
+        context: &Context,
+    ) -> Result<MonoidHandle<'_, EvaluationDomain>, SparseLinearAlgebraError> {
+        let mut graphblas_monoid: MaybeUninit<GrB_Monoid> = MaybeUninit::uninit();
+        let graphblas_semiring = self.graphblas_type();
+
+        context.call(
+            || unsafe { GxB_Semiring_add(graphblas_monoid.as_mut_ptr(), graphblas_semiring) },
+            &graphblas_semiring,
+        )?;
+
+        Ok(MonoidHandle::new(unsafe { graphblas_monoid.assume_init() }))
+    }
+
+    fn multiplication(
+        &self,
+        context: &Context,
+    ) -> Result<BinaryOperatorHandle<'_, EvaluationDomain>, SparseLinearAlgebraError> {
+        let mut graphblas_operator: MaybeUninit<GrB_BinaryOp> = MaybeUninit::uninit();
+        let graphblas_semiring = self.graphblas_type();
+
+        context.call(
+            || unsafe {
+                GxB_Semiring_multiply(graphblas_operator.as_mut_ptr(), graphblas_semiring)
+            },
+            &graphblas_semiring,
+        )?;
+
+        Ok(BinaryOperatorHandle::new(unsafe {
+            graphblas_operator.assume_init()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::binary_operator::{BinaryOperator, GetBinaryOperatorTypeNames, Plus};
+    use crate::operators::monoid::{GetMonoidComponents, Min, Monoid};
+    use crate::operators::semiring::{MinPlus, PlusTimes, UserDefinedSemiring};
+
+    #[test]
+    fn components_of_built_in_semiring() {
+        let context = Context::init_default().unwrap();
+
+        let semiring = PlusTimes::<f32>::new();
+        let addition = semiring.addition(&context).unwrap();
+        let multiplication = semiring.multiplication(&context).unwrap();
+
+        assert_eq!(addition.identity(&context).unwrap(), 0.0);
+        assert_eq!(multiplication.output_type_name(&context).unwrap(), "float");
+
+        let semiring = MinPlus::<i32>::new();
+        assert_eq!(
+            semiring
+                .addition(&context)
+                .unwrap()
+                .identity(&context)
+                .unwrap(),
+            i32::MAX
+        );
+    }
+
+    #[test]
+    fn components_of_user_defined_semiring() {
+        let context = Context::init_default().unwrap();
+
+        let addition = Min::<u64>::new();
+        let multiplication = Plus::<u64>::new();
+        let semiring = UserDefinedSemiring::<u64>::from_monoid_and_binary_operator(
+            context.clone(),
+            &addition,
+            &multiplication,
+        )
+        .unwrap();
+
+        assert_eq!(
+            semiring.addition(&context).unwrap().graphblas_type(),
+            addition.graphblas_type()
+        );
+        assert_eq!(
+            semiring.multiplication(&context).unwrap().graphblas_type(),
+            multiplication.graphblas_type()
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{GrB_Semiring, GrB_Semiring_free, GrB_Semiring_new};
+use crate::operators::binary_operator::BinaryOperator;
+use crate::operators::monoid::Monoid;
+use crate::value_type::ValueType;
+
+use super::Semiring;
+
+/// A semiring from an addition monoid and a multiplication binary operator.
+/// GraphBLAS refers to the monoid and the binary operator, so the semiring borrows both,
+/// including any binary operator the monoid itself borrows.
+/// The semiring is freed when it is dropped.
+///
+/// ```compile_fail
+/// use graphblas_sparse_linear_algebra::context::Context;
+/// use graphblas_sparse_linear_algebra::operators::binary_operator::{
+///     Plus, UserDefinedBinaryOperator,
+/// };
+/// use graphblas_sparse_linear_algebra::operators::monoid::UserDefinedMonoid;
+/// use graphblas_sparse_linear_algebra::operators::semiring::UserDefinedSemiring;
+///
+/// let context = Context::init_default().unwrap();
+/// let minimum = UserDefinedBinaryOperator::<u8>::from_function(context.clone(), |x: &u8, y: &u8| {
+///     *x.min(y)
+/// })
+/// .unwrap();
+/// let minimum_monoid =
+///     UserDefinedMonoid::<u8>::from_binary_operator(context.clone(), &minimum, u8::MAX).unwrap();
+/// let plus = Plus::<u8>::new();
+/// let semiring =
+///     UserDefinedSemiring::<u8>::from_monoid_and_binary_operator(context, &minimum_monoid, &plus)
+///         .unwrap();
+/// drop(minimum);
+/// drop(semiring);
+/// ```
+#[derive(Debug)]
+pub struct UserDefinedSemiring<'a, EvaluationDomain: ValueType> {
+    context: Arc<Context>,
+    graphblas_semiring: GrB_Semiring,
+    _evaluation_domain: PhantomData<EvaluationDomain>,
+    _monoid_and_binary_operator: PhantomData<&'a ()>,
+}
+
+// Implemented methods do not provide mutable access to the GraphBLAS semiring.
+// Code review must consider that no mutable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<'a, EvaluationDomain: ValueType> Send for UserDefinedSemiring<'a, EvaluationDomain> {}
+unsafe impl<'a, EvaluationDomain: ValueType> Sync for UserDefinedSemiring<'a, EvaluationDomain> {}
+
+impl<'a, EvaluationDomain: ValueType> UserDefinedSemiring<'a, EvaluationDomain> {
+    pub fn from_monoid_and_binary_operator(
+        context: Arc<Context>,
+        addition: &'a impl Monoid<EvaluationDomain>,
+        multiplication: &'a impl BinaryOperator<EvaluationDomain>,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_semiring: MaybeUninit<GrB_Semiring> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GrB_Semiring_new(
+                graphblas_semiring.as_mut_ptr(),
+                addition.graphblas_type(),
+                multiplication.graphblas_type(),
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_semiring: unsafe { graphblas_semiring.assume_init() },
+            _evaluation_domain: PhantomData,
+            _monoid_and_binary_operator: PhantomData,
+        })
+    }
+}
+
+impl<'a, EvaluationDomain: ValueType> Semiring<EvaluationDomain>
+    for UserDefinedSemiring<'a, EvaluationDomain>
+{
+    fn graphblas_type(&self) -> GrB_Semiring {
+        self.graphblas_semiring
+    }
+}
+
+impl<'a, EvaluationDomain: ValueType> GetContext for UserDefinedSemiring<'a, EvaluationDomain> {
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<'a, EvaluationDomain: ValueType> Drop for UserDefinedSemiring<'a, EvaluationDomain> {
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_Semiring_free(&mut self.graphblas_semiring)
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::collections::Collection;
+    use crate::operators::binary_operator::{Assignment, First, Times, UserDefinedBinaryOperator};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::monoid::{Max, UserDefinedMonoid};
+    use crate::operators::multiplication::{MatrixMultiplicationOperator, MultiplyMatrices};
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+
+    fn saturating_addition(x: &u32, y: &u32) -> u32 {
+        x.saturating_add(*y)
+    }
+
+    fn minimum(x: &u32, y: &u32) -> u32 {
+        *x.min(y)
+    }
+
+    #[test]
+    fn multiplication_with_max_times_semiring_on_probabilities() {
+        let context = Context::init_default().unwrap();
+
+        let max = Max::<f64>::new();
+        let times = Times::<f64>::new();
+        let max_times = UserDefinedSemiring::<f64>::from_monoid_and_binary_operator(
+            context.clone(),
+            &max,
+            &times,
+        )
+        .unwrap();
+
+        let size: Size = (2, 2).into();
+        let multiplier = SparseMatrix::<f64>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![
+                (0, 0, 0.5).into(),
+                (0, 1, 0.25).into(),
+                (1, 1, 1.0).into(),
+            ]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseMatrix::<f64>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![
+                (0, 0, 0.5).into(),
+                (1, 0, 0.5).into(),
+                (1, 1, 0.75).into(),
+            ]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<f64>::new(context.clone(), size).unwrap();
+
+        MatrixMultiplicationOperator::new()
+            .apply(
+                &multiplier,
+                &max_times,
+                &multiplicant,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 4);
+        assert_eq!(product.element_value_or_default(0, 0).unwrap(), 0.25);
+        assert_eq!(product.element_value_or_default(0, 1).unwrap(), 0.1875);
+        assert_eq!(product.element_value_or_default(1, 0).unwrap(), 0.5);
+        assert_eq!(product.element_value_or_default(1, 1).unwrap(), 0.75);
+    }
+
+    #[test]
+    fn multiplication_with_user_defined_tropical_semiring() {
+        let context = Context::init_default().unwrap();
+
+        let minimum =
+            UserDefinedBinaryOperator::<u32>::from_function(context.clone(), minimum).unwrap();
+        let minimum_monoid = UserDefinedMonoid::<u32>::from_binary_operator_with_terminal(
+            context.clone(),
+            &minimum,
+            u32::MAX,
+            0,
+        )
+        .unwrap();
+        let saturating_addition =
+            UserDefinedBinaryOperator::<u32>::from_function(context.clone(), saturating_addition)
+                .unwrap();
+
+        let min_plus = UserDefinedSemiring::<u32>::from_monoid_and_binary_operator(
+            context.clone(),
+            &minimum_monoid,
+            &saturating_addition,
+        )
+        .unwrap();
+
+        let size: Size = (3, 3).into();
+        let distances = SparseMatrix::<u32>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![
+                (0, 1, 4).into(),
+                (0, 2, 9).into(),
+                (1, 2, u32::MAX).into(),
+                (2, 2, 0).into(),
+                (1, 1, 0).into(),
+            ]),
+            &First::<u32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<u32>::new(context.clone(), size).unwrap();
+
+        MatrixMultiplicationOperator::new()
+            .apply(
+                &distances,
+                &min_plus,
+                &distances,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value_or_default(0, 1).unwrap(), 4);
+        assert_eq!(product.element_value_or_default(0, 2).unwrap(), 9);
+        assert_eq!(product.element_value_or_default(1, 2).unwrap(), u32::MAX);
+    }
+}
@@ -1,12 +1,13 @@
 use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
 use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
-use crate::context::{CallGraphBlasContext, GetContext};
+use crate::context::{CallGraphBlasOperation, GetContext, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::GxB_Matrix_subassign;
 use crate::index::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::MatrixMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArguments;
+use crate::property::Operand;
 use crate::value_type::ValueType;
 
 // TODO: explicitly define how dupicates are handled
@@ -75,7 +76,7 @@ impl<MatrixToInsertInto: ValueType> InsertMatrixIntoSubMatrix<MatrixToInsertInto
                 ElementIndexSelectorGraphblasType::Index(row),
                 ElementIndexSelectorGraphblasType::Index(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GxB_Matrix_subassign(
                             GetGraphblasSparseMatrix::graphblas_matrix_ptr(matrix_to_insert_into),
@@ -89,14 +90,16 @@ impl<MatrixToInsertInto: ValueType> InsertMatrixIntoSubMatrix<MatrixToInsertInto
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GxB_Matrix_subassign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_insert)],
                 )?;
             }
             (
                 ElementIndexSelectorGraphblasType::All(row),
                 ElementIndexSelectorGraphblasType::Index(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GxB_Matrix_subassign(
                             GetGraphblasSparseMatrix::graphblas_matrix_ptr(matrix_to_insert_into),
@@ -110,14 +113,16 @@ impl<MatrixToInsertInto: ValueType> InsertMatrixIntoSubMatrix<MatrixToInsertInto
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GxB_Matrix_subassign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_insert)],
                 )?;
             }
             (
                 ElementIndexSelectorGraphblasType::Index(row),
                 ElementIndexSelectorGraphblasType::All(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GxB_Matrix_subassign(
                             GetGraphblasSparseMatrix::graphblas_matrix_ptr(matrix_to_insert_into),
@@ -131,14 +136,16 @@ impl<MatrixToInsertInto: ValueType> InsertMatrixIntoSubMatrix<MatrixToInsertInto
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GxB_Matrix_subassign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_insert)],
                 )?;
             }
             (
                 ElementIndexSelectorGraphblasType::All(row),
                 ElementIndexSelectorGraphblasType::All(column),
             ) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GxB_Matrix_subassign(
                             GetGraphblasSparseMatrix::graphblas_matrix_ptr(matrix_to_insert_into),
@@ -152,7 +159,9 @@ impl<MatrixToInsertInto: ValueType> InsertMatrixIntoSubMatrix<MatrixToInsertInto
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GxB_Matrix_subassign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::matrix(matrix_to_insert)],
                 )?;
             }
         }
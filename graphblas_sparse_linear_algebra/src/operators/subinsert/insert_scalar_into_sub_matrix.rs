@@ -1,6 +1,6 @@
 use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
 use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
-use crate::context::{CallGraphBlasContext, GetContext};
+use crate::context::{CallGraphBlasOperation, GetContext, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::{
     GxB_Matrix_subassign_BOOL, GxB_Matrix_subassign_FP32, GxB_Matrix_subassign_FP64,
@@ -89,7 +89,7 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                         ElementIndexSelectorGraphblasType::Index(row),
                         ElementIndexSelectorGraphblasType::Index(column),
                     ) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     GetGraphblasSparseMatrix::graphblas_matrix_ptr(
@@ -105,14 +105,19 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                            &[],
                         )?;
                     }
                     (
                         ElementIndexSelectorGraphblasType::All(row),
                         ElementIndexSelectorGraphblasType::Index(column),
                     ) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     GetGraphblasSparseMatrix::graphblas_matrix_ptr(
@@ -128,14 +133,19 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                            &[],
                         )?;
                     }
                     (
                         ElementIndexSelectorGraphblasType::Index(row),
                         ElementIndexSelectorGraphblasType::All(column),
                     ) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     GetGraphblasSparseMatrix::graphblas_matrix_ptr(
@@ -151,14 +161,19 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                            &[],
                         )?;
                     }
                     (
                         ElementIndexSelectorGraphblasType::All(row),
                         ElementIndexSelectorGraphblasType::All(column),
                     ) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     GetGraphblasSparseMatrix::graphblas_matrix_ptr(
@@ -174,7 +189,12 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                            &[],
                         )?;
                     }
                 }
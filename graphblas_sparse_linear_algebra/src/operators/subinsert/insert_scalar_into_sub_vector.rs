@@ -1,6 +1,6 @@
 use crate::collections::sparse_vector::operations::GetSparseVectorLength;
 use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
-use crate::context::{CallGraphBlasContext, GetContext};
+use crate::context::{CallGraphBlasOperation, GetContext, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::{
     GxB_Vector_subassign_BOOL, GxB_Vector_subassign_FP32, GxB_Vector_subassign_FP64,
@@ -79,7 +79,7 @@ macro_rules! implement_insert_scalar_into_sub_vector_trait {
 
                 match indices_to_insert_into {
                     ElementIndexSelectorGraphblasType::Index(index) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     GetGraphblasSparseVector::graphblas_vector_ptr(
@@ -93,12 +93,17 @@ macro_rules! implement_insert_scalar_into_sub_vector_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { vector_to_insert_into.graphblas_vector_ptr_ref() },
+                            &[],
                         )?;
                     }
 
                     ElementIndexSelectorGraphblasType::All(index) => {
-                        context.call(
+                        context.call_operation(
                             || unsafe {
                                 $graphblas_insert_function(
                                     GetGraphblasSparseVector::graphblas_vector_ptr(
@@ -112,7 +117,12 @@ macro_rules! implement_insert_scalar_into_sub_vector_trait {
                                     options.graphblas_descriptor(),
                                 )
                             },
+                            Operation::new(
+                                OperationKind::Assign,
+                                stringify!($graphblas_insert_function),
+                            ),
                             unsafe { vector_to_insert_into.graphblas_vector_ptr_ref() },
+                            &[],
                         )?;
                     }
                 }
@@ -1,7 +1,7 @@
 use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
 use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::context::{CallGraphBlasContext, GetContext};
+use crate::context::{CallGraphBlasOperation, GetContext, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::GxB_Col_subassign;
 use crate::index::{
@@ -10,6 +10,7 @@ use crate::index::{
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArgument;
+use crate::property::Operand;
 
 use crate::value_type::ValueType;
 
@@ -72,7 +73,7 @@ impl<MatrixToInsertInto: ValueType> InsertVectorIntoSubColumn<MatrixToInsertInto
 
         match indices_to_insert_into {
             ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GxB_Col_subassign(
                             matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -85,12 +86,14 @@ impl<MatrixToInsertInto: ValueType> InsertVectorIntoSubColumn<MatrixToInsertInto
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GxB_Col_subassign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::vector(vector_to_insert)],
                 )?;
             }
 
             ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GxB_Col_subassign(
                             matrix_to_insert_into.graphblas_matrix_ptr(),
@@ -103,7 +106,9 @@ impl<MatrixToInsertInto: ValueType> InsertVectorIntoSubColumn<MatrixToInsertInto
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GxB_Col_subassign"),
                     unsafe { matrix_to_insert_into.graphblas_matrix_ptr_ref() },
+                    &[Operand::vector(vector_to_insert)],
                 )?;
             }
         }
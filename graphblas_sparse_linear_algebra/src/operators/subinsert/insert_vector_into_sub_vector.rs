@@ -1,12 +1,13 @@
 use crate::collections::sparse_vector::operations::GetSparseVectorLength;
 use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
-use crate::context::{CallGraphBlasContext, GetContext};
+use crate::context::{CallGraphBlasOperation, GetContext, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::GxB_Vector_subassign;
 use crate::index::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOperatorOptions;
+use crate::property::Operand;
 
 use crate::value_type::ValueType;
 
@@ -66,7 +67,7 @@ impl<VectorToInsertInto: ValueType> InsertVectorIntoSubVector<VectorToInsertInto
 
         match indices_to_insert_into {
             ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GxB_Vector_subassign(
                             GetGraphblasSparseVector::graphblas_vector_ptr(vector_to_insert_into),
@@ -78,12 +79,14 @@ impl<VectorToInsertInto: ValueType> InsertVectorIntoSubVector<VectorToInsertInto
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GxB_Vector_subassign"),
                     unsafe { vector_to_insert_into.graphblas_vector_ptr_ref() },
+                    &[Operand::vector(vector_to_insert)],
                 )?;
             }
 
             ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
+                context.call_operation(
                     || unsafe {
                         GxB_Vector_subassign(
                             GetGraphblasSparseVector::graphblas_vector_ptr(vector_to_insert_into),
@@ -95,7 +98,9 @@ impl<VectorToInsertInto: ValueType> InsertVectorIntoSubVector<VectorToInsertInto
                             options.graphblas_descriptor(),
                         )
                     },
+                    Operation::new(OperationKind::Assign, "GxB_Vector_subassign"),
                     unsafe { vector_to_insert_into.graphblas_vector_ptr_ref() },
+                    &[Operand::vector(vector_to_insert)],
                 )?;
             }
         }
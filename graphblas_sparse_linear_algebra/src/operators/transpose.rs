@@ -1,7 +1,8 @@
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
-use crate::context::CallGraphBlasContext;
+use crate::context::{CallGraphBlasOperation, Operation, OperationKind};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::GrB_transpose;
+use crate::property::Operand;
 
 use crate::value_type::ValueType;
 
@@ -46,7 +47,7 @@ impl<EvaluationDomain: ValueType> TransposeMatrix<EvaluationDomain> for MatrixTr
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = transpose.context_ref();
 
-        context.call(
+        context.call_operation(
             || unsafe {
                 GrB_transpose(
                     transpose.graphblas_matrix_ptr(),
@@ -56,7 +57,9 @@ impl<EvaluationDomain: ValueType> TransposeMatrix<EvaluationDomain> for MatrixTr
                     options.graphblas_descriptor(),
                 )
             },
+            Operation::new(OperationKind::Transpose, "GrB_transpose"),
             unsafe { transpose.graphblas_matrix_ptr_ref() },
+            &[Operand::matrix(matrix)],
         )?;
 
         Ok(())
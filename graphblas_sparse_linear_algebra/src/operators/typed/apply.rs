@@ -0,0 +1,171 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{ApplyUnaryOperator, UnaryOperatorApplier};
+use crate::operators::binary_operator::AccumulatorBinaryOperator;
+use crate::operators::options::{GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument};
+use crate::operators::unary_operator::UnaryOperator;
+use crate::value_type::ValueType;
+
+use super::{
+    TypedMatrixMask, TypedSparseMatrix, TypedSparseMatrixMut, TypedSparseVector,
+    TypedSparseVectorMut, TypedVectorMask,
+};
+
+pub trait TypedApplyUnaryOperator<T: ValueType> {
+    fn apply_to_vector(
+        &self,
+        operator: &impl UnaryOperator<T>,
+        argument: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn apply_to_matrix(
+        &self,
+        operator: &impl UnaryOperator<T>,
+        argument: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedApplyUnaryOperator<T> for UnaryOperatorApplier {
+    fn apply_to_vector(
+        &self,
+        operator: &impl UnaryOperator<T>,
+        argument: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ApplyUnaryOperator::<T>::apply_to_vector(
+            self,
+            operator,
+            argument,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+
+    fn apply_to_matrix(
+        &self,
+        operator: &impl UnaryOperator<T>,
+        argument: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ApplyUnaryOperator::<T>::apply_to_matrix(
+            self,
+            operator,
+            argument,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, SparseMatrix};
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::{OperatorOptions, OptionsForOperatorWithMatrixArgument};
+    use crate::operators::typed::{CastSparseMatrixValueType, CastSparseVectorValueType};
+    use crate::operators::unary_operator::{AdditiveInverse, Identity};
+
+    #[test]
+    fn apply_to_vector_with_explicit_cast() {
+        let context = Context::init_default().unwrap();
+
+        let argument = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::<u8>::from_element_vector(vec![(0, 1).into(), (2, 200).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<i32>::new(context.clone(), 3).unwrap();
+
+        TypedApplyUnaryOperator::<i32>::apply_to_vector(
+            &UnaryOperatorApplier::new(),
+            &AdditiveInverse::<i32>::new(),
+            &argument.cast::<i32>(),
+            &Assignment::new(),
+            &mut product,
+            &SelectEntireVector::new(context.clone()),
+            &OperatorOptions::new_default(),
+        )
+        .unwrap();
+
+        assert_eq!(product.element_value(0).unwrap(), Some(-1));
+        assert_eq!(product.element_value(1).unwrap(), None);
+        assert_eq!(product.element_value(2).unwrap(), Some(-200));
+    }
+
+    #[test]
+    fn apply_to_matrix_with_cast_mask() {
+        let context = Context::init_default().unwrap();
+
+        let argument = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            (2, 2).into(),
+            MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 0, 1.5).into(),
+                (0, 1, 2.5).into(),
+                (1, 1, 3.5).into(),
+            ]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        // A stored zero casts to false, so (0, 1) is not selected.
+        let mask = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            (2, 2).into(),
+            MatrixElementList::<u8>::from_element_vector(vec![
+                (0, 0, 7).into(),
+                (0, 1, 0).into(),
+                (1, 1, 1).into(),
+            ]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<f32>::new(context.clone(), (2, 2).into()).unwrap();
+
+        TypedApplyUnaryOperator::<f32>::apply_to_matrix(
+            &UnaryOperatorApplier::new(),
+            &Identity::<f32>::new(),
+            &argument,
+            &Assignment::new(),
+            &mut product,
+            &mask.cast::<bool>(),
+            &OptionsForOperatorWithMatrixArgument::new_default(),
+        )
+        .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.element_value(0, 0).unwrap(), Some(1.5));
+        assert_eq!(product.element_value(0, 1).unwrap(), None);
+        assert_eq!(product.element_value(1, 1).unwrap(), Some(3.5));
+    }
+}
@@ -0,0 +1,261 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use suitesparse_graphblas_sys::{GrB_Matrix, GrB_Vector};
+
+use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{Context, GetContext};
+use crate::operators::mask::{MatrixMask, SelectEntireMatrix, SelectEntireVector, VectorMask};
+use crate::value_type::ValueType;
+
+/// A matrix argument whose values are in the domain T, without implicit typecasting.
+pub trait TypedSparseMatrix<T: ValueType>: GetGraphblasSparseMatrix {}
+
+/// A vector argument whose values are in the domain T, without implicit typecasting.
+pub trait TypedSparseVector<T: ValueType>: GetGraphblasSparseVector {}
+
+/// A matrix that stores the product of an operation in the domain T.
+pub trait TypedSparseMatrixMut<T: ValueType>: TypedSparseMatrix<T> {}
+
+/// A vector that stores the product of an operation in the domain T.
+pub trait TypedSparseVectorMut<T: ValueType>: TypedSparseVector<T> {}
+
+/// A mask with boolean values, or a mask that selects everything.
+pub trait TypedMatrixMask: MatrixMask {}
+
+/// A mask with boolean values, or a mask that selects everything.
+pub trait TypedVectorMask: VectorMask {}
+
+impl<T: ValueType> TypedSparseMatrix<T> for SparseMatrix<T> {}
+impl<T: ValueType> TypedSparseVector<T> for SparseVector<T> {}
+impl<T: ValueType> TypedSparseMatrixMut<T> for SparseMatrix<T> {}
+impl<T: ValueType> TypedSparseVectorMut<T> for SparseVector<T> {}
+
+impl TypedMatrixMask for SparseMatrix<bool> {}
+impl TypedMatrixMask for SelectEntireMatrix {}
+impl TypedVectorMask for SparseVector<bool> {}
+impl TypedVectorMask for SelectEntireVector {}
+
+/// Explicitly accepts the typecast of a matrix with values of type S to the domain T.
+pub trait CastSparseMatrixValueType<S: ValueType> {
+    fn cast<T: ValueType>(&self) -> CastSparseMatrix<'_, S, T>;
+    /// GraphBLAS typecasts the result of an operation to S when it is stored in the matrix.
+    fn cast_mut<T: ValueType>(&mut self) -> CastSparseMatrixMut<'_, S, T>;
+}
+
+impl<S: ValueType> CastSparseMatrixValueType<S> for SparseMatrix<S> {
+    fn cast<T: ValueType>(&self) -> CastSparseMatrix<'_, S, T> {
+        CastSparseMatrix {
+            graphblas_matrix: unsafe { GetGraphblasSparseMatrix::graphblas_matrix_ptr(self) },
+            matrix: self,
+            target_value_type: PhantomData,
+        }
+    }
+
+    fn cast_mut<T: ValueType>(&mut self) -> CastSparseMatrixMut<'_, S, T> {
+        CastSparseMatrixMut {
+            matrix: self,
+            target_value_type: PhantomData,
+        }
+    }
+}
+
+/// Explicitly accepts the typecast of a vector with values of type S to the domain T.
+pub trait CastSparseVectorValueType<S: ValueType> {
+    fn cast<T: ValueType>(&self) -> CastSparseVector<'_, S, T>;
+    /// GraphBLAS typecasts the result of an operation to S when it is stored in the vector.
+    fn cast_mut<T: ValueType>(&mut self) -> CastSparseVectorMut<'_, S, T>;
+}
+
+impl<S: ValueType> CastSparseVectorValueType<S> for SparseVector<S> {
+    fn cast<T: ValueType>(&self) -> CastSparseVector<'_, S, T> {
+        CastSparseVector {
+            graphblas_vector: unsafe { GetGraphblasSparseVector::graphblas_vector_ptr(self) },
+            vector: self,
+            target_value_type: PhantomData,
+        }
+    }
+
+    fn cast_mut<T: ValueType>(&mut self) -> CastSparseVectorMut<'_, S, T> {
+        CastSparseVectorMut {
+            vector: self,
+            target_value_type: PhantomData,
+        }
+    }
+}
+
+/// Only reads the matrix, so it cannot store the product of an operation.
+///
+/// ```compile_fail
+/// use graphblas_sparse_linear_algebra::collections::sparse_matrix::SparseMatrix;
+/// use graphblas_sparse_linear_algebra::context::Context;
+/// use graphblas_sparse_linear_algebra::operators::binary_operator::Assignment;
+/// use graphblas_sparse_linear_algebra::operators::mask::SelectEntireMatrix;
+/// use graphblas_sparse_linear_algebra::operators::options::OptionsForOperatorWithMatrixArgument;
+/// use graphblas_sparse_linear_algebra::operators::transpose::MatrixTranspose;
+/// use graphblas_sparse_linear_algebra::operators::typed::{
+///     CastSparseMatrixValueType, TypedTransposeMatrix,
+/// };
+///
+/// let context = Context::init_default().unwrap();
+/// let matrix = SparseMatrix::<f32>::new(context.clone(), (2, 2).into()).unwrap();
+/// let transpose = SparseMatrix::<u8>::new(context.clone(), (2, 2).into()).unwrap();
+/// TypedTransposeMatrix::<f32>::apply(
+///     &MatrixTranspose::new(),
+///     &matrix,
+///     &Assignment::new(),
+///     &mut transpose.cast::<f32>(),
+///     &SelectEntireMatrix::new(context),
+///     &OptionsForOperatorWithMatrixArgument::new_default(),
+/// )
+/// .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct CastSparseMatrix<'a, S: ValueType, T: ValueType> {
+    matrix: &'a SparseMatrix<S>,
+    // A copy of the pointer, so that changing it does not affect the borrowed matrix
+    graphblas_matrix: GrB_Matrix,
+    target_value_type: PhantomData<T>,
+}
+
+impl<'a, S: ValueType, T: ValueType> TypedSparseMatrix<T> for CastSparseMatrix<'a, S, T> {}
+impl<'a, S: ValueType> TypedMatrixMask for CastSparseMatrix<'a, S, bool> {}
+
+impl<'a, S: ValueType, T: ValueType> GetGraphblasSparseMatrix for CastSparseMatrix<'a, S, T> {
+    unsafe fn graphblas_matrix_ptr(&self) -> GrB_Matrix {
+        GetGraphblasSparseMatrix::graphblas_matrix_ptr(self.matrix)
+    }
+
+    unsafe fn graphblas_matrix_ptr_ref(&self) -> &GrB_Matrix {
+        self.matrix.graphblas_matrix_ptr_ref()
+    }
+
+    unsafe fn graphblas_matrix_ptr_mut(&mut self) -> &mut GrB_Matrix {
+        &mut self.graphblas_matrix
+    }
+}
+
+impl<'a, S: ValueType, T: ValueType> MatrixMask for CastSparseMatrix<'a, S, T> {
+    unsafe fn graphblas_matrix_ptr(&self) -> GrB_Matrix {
+        GetGraphblasSparseMatrix::graphblas_matrix_ptr(self.matrix)
+    }
+}
+
+impl<'a, S: ValueType, T: ValueType> GetContext for CastSparseMatrix<'a, S, T> {
+    fn context(&self) -> Arc<Context> {
+        self.matrix.context()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        self.matrix.context_ref()
+    }
+}
+
+#[derive(Debug)]
+pub struct CastSparseMatrixMut<'a, S: ValueType, T: ValueType> {
+    matrix: &'a mut SparseMatrix<S>,
+    target_value_type: PhantomData<T>,
+}
+
+impl<'a, S: ValueType, T: ValueType> TypedSparseMatrix<T> for CastSparseMatrixMut<'a, S, T> {}
+impl<'a, S: ValueType, T: ValueType> TypedSparseMatrixMut<T> for CastSparseMatrixMut<'a, S, T> {}
+
+impl<'a, S: ValueType, T: ValueType> GetGraphblasSparseMatrix for CastSparseMatrixMut<'a, S, T> {
+    unsafe fn graphblas_matrix_ptr(&self) -> GrB_Matrix {
+        GetGraphblasSparseMatrix::graphblas_matrix_ptr(self.matrix)
+    }
+
+    unsafe fn graphblas_matrix_ptr_ref(&self) -> &GrB_Matrix {
+        self.matrix.graphblas_matrix_ptr_ref()
+    }
+
+    unsafe fn graphblas_matrix_ptr_mut(&mut self) -> &mut GrB_Matrix {
+        self.matrix.graphblas_matrix_ptr_mut()
+    }
+}
+
+impl<'a, S: ValueType, T: ValueType> GetContext for CastSparseMatrixMut<'a, S, T> {
+    fn context(&self) -> Arc<Context> {
+        self.matrix.context()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        self.matrix.context_ref()
+    }
+}
+
+/// Only reads the vector, so it cannot store the product of an operation.
+#[derive(Debug)]
+pub struct CastSparseVector<'a, S: ValueType, T: ValueType> {
+    vector: &'a SparseVector<S>,
+    // A copy of the pointer, so that changing it does not affect the borrowed vector
+    graphblas_vector: GrB_Vector,
+    target_value_type: PhantomData<T>,
+}
+
+impl<'a, S: ValueType, T: ValueType> TypedSparseVector<T> for CastSparseVector<'a, S, T> {}
+impl<'a, S: ValueType> TypedVectorMask for CastSparseVector<'a, S, bool> {}
+
+impl<'a, S: ValueType, T: ValueType> GetGraphblasSparseVector for CastSparseVector<'a, S, T> {
+    unsafe fn graphblas_vector_ptr(&self) -> GrB_Vector {
+        GetGraphblasSparseVector::graphblas_vector_ptr(self.vector)
+    }
+
+    unsafe fn graphblas_vector_ptr_ref(&self) -> &GrB_Vector {
+        self.vector.graphblas_vector_ptr_ref()
+    }
+
+    unsafe fn graphblas_vector_ptr_mut(&mut self) -> &mut GrB_Vector {
+        &mut self.graphblas_vector
+    }
+}
+
+impl<'a, S: ValueType, T: ValueType> VectorMask for CastSparseVector<'a, S, T> {
+    unsafe fn graphblas_vector_ptr(&self) -> GrB_Vector {
+        GetGraphblasSparseVector::graphblas_vector_ptr(self.vector)
+    }
+}
+
+impl<'a, S: ValueType, T: ValueType> GetContext for CastSparseVector<'a, S, T> {
+    fn context(&self) -> Arc<Context> {
+        self.vector.context()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        self.vector.context_ref()
+    }
+}
+
+#[derive(Debug)]
+pub struct CastSparseVectorMut<'a, S: ValueType, T: ValueType> {
+    vector: &'a mut SparseVector<S>,
+    target_value_type: PhantomData<T>,
+}
+
+impl<'a, S: ValueType, T: ValueType> TypedSparseVector<T> for CastSparseVectorMut<'a, S, T> {}
+impl<'a, S: ValueType, T: ValueType> TypedSparseVectorMut<T> for CastSparseVectorMut<'a, S, T> {}
+
+impl<'a, S: ValueType, T: ValueType> GetGraphblasSparseVector for CastSparseVectorMut<'a, S, T> {
+    unsafe fn graphblas_vector_ptr(&self) -> GrB_Vector {
+        GetGraphblasSparseVector::graphblas_vector_ptr(self.vector)
+    }
+
+    unsafe fn graphblas_vector_ptr_ref(&self) -> &GrB_Vector {
+        self.vector.graphblas_vector_ptr_ref()
+    }
+
+    unsafe fn graphblas_vector_ptr_mut(&mut self) -> &mut GrB_Vector {
+        self.vector.graphblas_vector_ptr_mut()
+    }
+}
+
+impl<'a, S: ValueType, T: ValueType> GetContext for CastSparseVectorMut<'a, S, T> {
+    fn context(&self) -> Arc<Context> {
+        self.vector.context()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        self.vector.context_ref()
+    }
+}
@@ -0,0 +1,243 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, BinaryOperator};
+use crate::operators::element_wise_addition::{
+    ApplyElementWiseMatrixAdditionBinaryOperator, ApplyElementWiseVectorAdditionBinaryOperator,
+    ElementWiseMatrixAdditionBinaryOperator, ElementWiseVectorAdditionBinaryOperator,
+};
+use crate::operators::element_wise_multiplication::{
+    ApplyElementWiseMatrixMultiplicationBinaryOperator,
+    ApplyElementWiseVectorMultiplicationBinaryOperator,
+    ElementWiseMatrixMultiplicationBinaryOperator, ElementWiseVectorMultiplicationBinaryOperator,
+};
+use crate::operators::options::{GetOperatorOptions, GetOptionsForOperatorWithMatrixArguments};
+use crate::value_type::ValueType;
+
+use super::{
+    TypedMatrixMask, TypedSparseMatrix, TypedSparseMatrixMut, TypedSparseVector,
+    TypedSparseVectorMut, TypedVectorMask,
+};
+
+pub trait TypedApplyElementWiseMatrixAdditionBinaryOperator<T: ValueType> {
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseMatrix<T>,
+        operator: &impl BinaryOperator<T>,
+        multiplicant: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedApplyElementWiseMatrixAdditionBinaryOperator<T>
+    for ElementWiseMatrixAdditionBinaryOperator
+{
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseMatrix<T>,
+        operator: &impl BinaryOperator<T>,
+        multiplicant: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ApplyElementWiseMatrixAdditionBinaryOperator::<T>::apply(
+            self,
+            multiplier,
+            operator,
+            multiplicant,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedApplyElementWiseVectorAdditionBinaryOperator<T: ValueType> {
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseVector<T>,
+        operator: &impl BinaryOperator<T>,
+        multiplicant: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedApplyElementWiseVectorAdditionBinaryOperator<T>
+    for ElementWiseVectorAdditionBinaryOperator
+{
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseVector<T>,
+        operator: &impl BinaryOperator<T>,
+        multiplicant: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ApplyElementWiseVectorAdditionBinaryOperator::<T>::apply(
+            self,
+            multiplier,
+            operator,
+            multiplicant,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedApplyElementWiseMatrixMultiplicationBinaryOperator<T: ValueType> {
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseMatrix<T>,
+        operator: &impl BinaryOperator<T>,
+        multiplicant: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedApplyElementWiseMatrixMultiplicationBinaryOperator<T>
+    for ElementWiseMatrixMultiplicationBinaryOperator
+{
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseMatrix<T>,
+        operator: &impl BinaryOperator<T>,
+        multiplicant: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ApplyElementWiseMatrixMultiplicationBinaryOperator::<T>::apply(
+            self,
+            multiplier,
+            operator,
+            multiplicant,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedApplyElementWiseVectorMultiplicationBinaryOperator<T: ValueType> {
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseVector<T>,
+        operator: &impl BinaryOperator<T>,
+        multiplicant: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedApplyElementWiseVectorMultiplicationBinaryOperator<T>
+    for ElementWiseVectorMultiplicationBinaryOperator
+{
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseVector<T>,
+        operator: &impl BinaryOperator<T>,
+        multiplicant: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ApplyElementWiseVectorMultiplicationBinaryOperator::<T>::apply(
+            self,
+            multiplier,
+            operator,
+            multiplicant,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First, Plus, Times};
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+    use crate::operators::typed::CastSparseVectorValueType;
+
+    #[test]
+    fn element_wise_vector_operations_with_explicit_cast() {
+        let context = Context::init_default().unwrap();
+
+        let left_argument = SparseVector::<u16>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::<u16>::from_element_vector(vec![(0, 300).into(), (1, 2).into()]),
+            &First::<u16>::new(),
+        )
+        .unwrap();
+        let right_argument = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::<u8>::from_element_vector(vec![(1, 200).into(), (2, 3).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let mut sum = SparseVector::<u16>::new(context.clone(), 3).unwrap();
+
+        TypedApplyElementWiseVectorAdditionBinaryOperator::<u16>::apply(
+            &ElementWiseVectorAdditionBinaryOperator::new(),
+            &left_argument,
+            &Plus::<u16>::new(),
+            &right_argument.cast::<u16>(),
+            &Assignment::new(),
+            &mut sum,
+            &SelectEntireVector::new(context.clone()),
+            &OperatorOptions::new_default(),
+        )
+        .unwrap();
+
+        assert_eq!(sum.element_value(0).unwrap(), Some(300));
+        assert_eq!(sum.element_value(1).unwrap(), Some(202));
+        assert_eq!(sum.element_value(2).unwrap(), Some(3));
+
+        let mut product = SparseVector::<f64>::new(context.clone(), 3).unwrap();
+        TypedApplyElementWiseVectorMultiplicationBinaryOperator::<u16>::apply(
+            &ElementWiseVectorMultiplicationBinaryOperator::new(),
+            &left_argument,
+            &Times::<u16>::new(),
+            &right_argument.cast::<u16>(),
+            &Assignment::new(),
+            &mut product.cast_mut::<u16>(),
+            &SelectEntireVector::new(context.clone()),
+            &OperatorOptions::new_default(),
+        )
+        .unwrap();
+
+        assert_eq!(product.element_value(0).unwrap(), None);
+        assert_eq!(product.element_value(1).unwrap(), Some(400.0));
+        assert_eq!(product.element_value(2).unwrap(), None);
+    }
+}
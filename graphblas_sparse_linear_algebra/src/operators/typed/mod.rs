@@ -0,0 +1,21 @@
+// The arguments, the product and the operator of a typed operation share one value type.
+// GraphBLAS typecasts silently when value types differ. The typed traits only accept another
+// value type when the typecast is requested with cast() or cast_mut(). Only a matrix or vector
+// itself, or the result of cast_mut(), can store the product. Masks must be boolean.
+// The untyped operator traits remain available for arguments with mixed value types.
+
+mod apply;
+mod argument;
+mod element_wise;
+mod multiplication;
+mod reduce;
+mod select;
+mod transpose;
+
+pub use apply::*;
+pub use argument::*;
+pub use element_wise::*;
+pub use multiplication::*;
+pub use reduce::*;
+pub use select::*;
+pub use transpose::*;
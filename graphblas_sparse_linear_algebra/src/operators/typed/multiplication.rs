@@ -0,0 +1,201 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::AccumulatorBinaryOperator;
+use crate::operators::multiplication::{
+    MatrixMultiplicationOperator, MatrixVectorMultiplicationOperator, MultiplyMatrices,
+    MultiplyMatrixByVector, MultiplyVectorByMatrix, VectorMatrixMultiplicationOperator,
+};
+use crate::operators::options::{
+    GetOptionsForOperatorWithMatrixArguments, GetOptionsForOperatorWithMatrixAsFirstArgument,
+    GetOptionsForOperatorWithMatrixAsSecondArgument,
+};
+use crate::operators::semiring::Semiring;
+use crate::value_type::ValueType;
+
+use super::{
+    TypedMatrixMask, TypedSparseMatrix, TypedSparseMatrixMut, TypedSparseVector,
+    TypedSparseVectorMut, TypedVectorMask,
+};
+
+pub trait TypedMultiplyMatrices<T: ValueType> {
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseMatrix<T>,
+        operator: &impl Semiring<T>,
+        multiplicant: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedMultiplyMatrices<T> for MatrixMultiplicationOperator {
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseMatrix<T>,
+        operator: &impl Semiring<T>,
+        multiplicant: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MultiplyMatrices::<T>::apply(
+            self,
+            multiplier,
+            operator,
+            multiplicant,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedMultiplyMatrixByVector<T: ValueType> {
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseMatrix<T>,
+        operator: &impl Semiring<T>,
+        multiplicant: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixAsFirstArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedMultiplyMatrixByVector<T> for MatrixVectorMultiplicationOperator {
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseMatrix<T>,
+        operator: &impl Semiring<T>,
+        multiplicant: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixAsFirstArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MultiplyMatrixByVector::<T>::apply(
+            self,
+            multiplier,
+            operator,
+            multiplicant,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedMultiplyVectorByMatrix<T: ValueType> {
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseVector<T>,
+        operator: &impl Semiring<T>,
+        multiplicant: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixAsSecondArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedMultiplyVectorByMatrix<T> for VectorMatrixMultiplicationOperator {
+    fn apply(
+        &self,
+        multiplier: &impl TypedSparseVector<T>,
+        operator: &impl Semiring<T>,
+        multiplicant: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixAsSecondArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MultiplyVectorByMatrix::<T>::apply(
+            self,
+            multiplier,
+            operator,
+            multiplicant,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+    use crate::operators::semiring::PlusTimes;
+    use crate::operators::typed::CastSparseMatrixValueType;
+
+    #[test]
+    fn multiply_matrices_with_explicit_cast() {
+        let context = Context::init_default().unwrap();
+
+        let multiplier = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            (2, 2).into(),
+            MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 0, 1.5).into(),
+                (1, 1, 2.0).into(),
+            ]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            (2, 2).into(),
+            MatrixElementList::<u8>::from_element_vector(vec![(0, 1, 2).into(), (1, 0, 3).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<f32>::new(context.clone(), Size::new(2, 2)).unwrap();
+
+        // Passing multiplicant without cast::<f32>() does not compile.
+        TypedMultiplyMatrices::<f32>::apply(
+            &MatrixMultiplicationOperator::new(),
+            &multiplier,
+            &PlusTimes::<f32>::new(),
+            &multiplicant.cast::<f32>(),
+            &Assignment::new(),
+            &mut product,
+            &SelectEntireMatrix::new(context.clone()),
+            &OptionsForOperatorWithMatrixArguments::new_default(),
+        )
+        .unwrap();
+
+        assert_eq!(product.element_value_or_default(0, 1).unwrap(), 3.0);
+        assert_eq!(product.element_value_or_default(1, 0).unwrap(), 6.0);
+
+        let mut integer_product =
+            SparseMatrix::<u8>::new(context.clone(), Size::new(2, 2)).unwrap();
+        TypedMultiplyMatrices::<f32>::apply(
+            &MatrixMultiplicationOperator::new(),
+            &multiplier,
+            &PlusTimes::<f32>::new(),
+            &multiplicant.cast::<f32>(),
+            &Assignment::new(),
+            &mut integer_product.cast_mut::<f32>(),
+            &SelectEntireMatrix::new(context.clone()),
+            &OptionsForOperatorWithMatrixArguments::new_default(),
+        )
+        .unwrap();
+
+        assert_eq!(integer_product.element_value_or_default(0, 1).unwrap(), 3);
+        assert_eq!(integer_product.element_value_or_default(1, 0).unwrap(), 6);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::AccumulatorBinaryOperator;
+use crate::operators::monoid::Monoid;
+use crate::operators::options::{
+    GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument, WithTransposeMatrixArgument,
+};
+use crate::operators::reduce::{MonoidReducer, MonoidScalarReducer, MonoidVectorReducer};
+use crate::value_type::ValueType;
+
+use super::{TypedSparseMatrix, TypedSparseVector, TypedSparseVectorMut, TypedVectorMask};
+
+pub trait TypedMonoidVectorReducer<T: ValueType> {
+    fn to_column_vector(
+        &self,
+        operator: &impl Monoid<T>,
+        argument: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn to_row_vector(
+        &self,
+        operator: &impl Monoid<T>,
+        argument: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &(impl GetOptionsForOperatorWithMatrixArgument + WithTransposeMatrixArgument),
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedMonoidVectorReducer<T> for MonoidReducer {
+    fn to_column_vector(
+        &self,
+        operator: &impl Monoid<T>,
+        argument: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidVectorReducer::<T>::to_column_vector(
+            self,
+            operator,
+            argument,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+
+    fn to_row_vector(
+        &self,
+        operator: &impl Monoid<T>,
+        argument: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &(impl GetOptionsForOperatorWithMatrixArgument + WithTransposeMatrixArgument),
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidVectorReducer::<T>::to_row_vector(
+            self,
+            operator,
+            argument,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+/// The scalar product is already typed, only the argument is checked.
+pub trait TypedMonoidScalarReducer<T: ValueType> {
+    fn matrix_to_scalar(
+        &self,
+        operator: &impl Monoid<T>,
+        argument: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut T,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn vector_to_scalar(
+        &self,
+        operator: &impl Monoid<T>,
+        argument: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut T,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedMonoidScalarReducer<T> for MonoidReducer
+where
+    MonoidReducer: MonoidScalarReducer<T>,
+{
+    fn matrix_to_scalar(
+        &self,
+        operator: &impl Monoid<T>,
+        argument: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut T,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidScalarReducer::<T>::matrix_to_scalar(
+            self,
+            operator,
+            argument,
+            accumulator,
+            product,
+            options,
+        )
+    }
+
+    fn vector_to_scalar(
+        &self,
+        operator: &impl Monoid<T>,
+        argument: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut T,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidScalarReducer::<T>::vector_to_scalar(
+            self,
+            operator,
+            argument,
+            accumulator,
+            product,
+            options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::{MatrixElementList, SparseMatrix};
+    use crate::collections::sparse_vector::operations::GetSparseVectorElementValue;
+    use crate::collections::sparse_vector::SparseVector;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::monoid::Plus;
+    use crate::operators::options::{OperatorOptions, OptionsForOperatorWithMatrixArgument};
+    use crate::operators::typed::{CastSparseMatrixValueType, CastSparseVectorValueType};
+
+    #[test]
+    fn reduce_with_explicit_cast() {
+        let context = Context::init_default().unwrap();
+
+        let argument = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            (2, 2).into(),
+            MatrixElementList::<u8>::from_element_vector(vec![
+                (0, 0, 200).into(),
+                (0, 1, 100).into(),
+                (1, 1, 1).into(),
+            ]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        // Reducing in u32 avoids the overflow of 200 + 100 in u8.
+        let mut row_sums = SparseVector::<u32>::new(context.clone(), 2).unwrap();
+        TypedMonoidVectorReducer::<u32>::to_column_vector(
+            &MonoidReducer::new(),
+            &Plus::<u32>::new(),
+            &argument.cast::<u32>(),
+            &Assignment::new(),
+            &mut row_sums,
+            &SelectEntireVector::new(context.clone()),
+            &OptionsForOperatorWithMatrixArgument::new_default(),
+        )
+        .unwrap();
+
+        assert_eq!(row_sums.element_value(0).unwrap(), Some(300));
+        assert_eq!(row_sums.element_value(1).unwrap(), Some(1));
+
+        let mut total = 0;
+        TypedMonoidScalarReducer::<u32>::matrix_to_scalar(
+            &MonoidReducer::new(),
+            &Plus::<u32>::new(),
+            &argument.cast::<u32>(),
+            &Assignment::new(),
+            &mut total,
+            &OptionsForOperatorWithMatrixArgument::new_default(),
+        )
+        .unwrap();
+        assert_eq!(total, 301);
+
+        let mut total = 0.0;
+        TypedMonoidScalarReducer::<f64>::vector_to_scalar(
+            &MonoidReducer::new(),
+            &Plus::<f64>::new(),
+            &row_sums.cast::<f64>(),
+            &Assignment::new(),
+            &mut total,
+            &OperatorOptions::new_default(),
+        )
+        .unwrap();
+        assert_eq!(total, 301.0);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::AccumulatorBinaryOperator;
+use crate::operators::index_unary_operator::IndexUnaryOperator;
+use crate::operators::options::{GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument};
+use crate::operators::select::{
+    MatrixSelector, SelectFromMatrix, SelectFromVector, VectorSelector,
+};
+use crate::value_type::ValueType;
+
+use super::{
+    TypedMatrixMask, TypedSparseMatrix, TypedSparseMatrixMut, TypedSparseVector,
+    TypedSparseVectorMut, TypedVectorMask,
+};
+
+pub trait TypedSelectFromMatrix<T: ValueType> {
+    fn apply(
+        &self,
+        selector: &impl IndexUnaryOperator<T>,
+        selector_argument: T,
+        argument: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedSelectFromMatrix<T> for MatrixSelector
+where
+    MatrixSelector: SelectFromMatrix<T>,
+{
+    fn apply(
+        &self,
+        selector: &impl IndexUnaryOperator<T>,
+        selector_argument: T,
+        argument: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        SelectFromMatrix::<T>::apply(
+            self,
+            selector,
+            selector_argument,
+            argument,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedSelectFromVector<T: ValueType> {
+    fn apply(
+        &self,
+        selector: &impl IndexUnaryOperator<T>,
+        selector_argument: T,
+        argument: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedSelectFromVector<T> for VectorSelector
+where
+    VectorSelector: SelectFromVector<T>,
+{
+    fn apply(
+        &self,
+        selector: &impl IndexUnaryOperator<T>,
+        selector_argument: T,
+        argument: &impl TypedSparseVector<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut impl TypedSparseVectorMut<T>,
+        mask: &impl TypedVectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        SelectFromVector::<T>::apply(
+            self,
+            selector,
+            selector_argument,
+            argument,
+            accumulator,
+            product,
+            mask,
+            options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::index_unary_operator::IsValueGreaterThan;
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+    use crate::operators::typed::CastSparseVectorValueType;
+
+    #[test]
+    fn select_from_vector_with_explicit_cast() {
+        let context = Context::init_default().unwrap();
+
+        let argument = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            4,
+            VectorElementList::<u8>::from_element_vector(vec![
+                (0, 1).into(),
+                (1, 2).into(),
+                (3, 3).into(),
+            ]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<u8>::new(context.clone(), 4).unwrap();
+
+        // The threshold 1.5 cannot be represented in u8.
+        TypedSelectFromVector::<f32>::apply(
+            &VectorSelector::new(),
+            &IsValueGreaterThan::<f32>::new(),
+            1.5,
+            &argument.cast::<f32>(),
+            &Assignment::new(),
+            &mut product.cast_mut::<f32>(),
+            &SelectEntireVector::new(context.clone()),
+            &OperatorOptions::new_default(),
+        )
+        .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.element_value(0).unwrap(), None);
+        assert_eq!(product.element_value(1).unwrap(), Some(2));
+        assert_eq!(product.element_value(3).unwrap(), Some(3));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::AccumulatorBinaryOperator;
+use crate::operators::options::GetOptionsForOperatorWithMatrixArgument;
+use crate::operators::transpose::{MatrixTranspose, TransposeMatrix};
+use crate::value_type::ValueType;
+
+use super::{TypedMatrixMask, TypedSparseMatrix, TypedSparseMatrixMut};
+
+pub trait TypedTransposeMatrix<T: ValueType> {
+    fn apply(
+        &self,
+        matrix: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        transpose: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TypedTransposeMatrix<T> for MatrixTranspose {
+    fn apply(
+        &self,
+        matrix: &impl TypedSparseMatrix<T>,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        transpose: &mut impl TypedSparseMatrixMut<T>,
+        mask: &impl TypedMatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        TransposeMatrix::<T>::apply(self, matrix, accumulator, transpose, mask, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, SparseMatrix};
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::options::OptionsForOperatorWithMatrixArgument;
+    use crate::operators::typed::CastSparseMatrixValueType;
+
+    #[test]
+    fn transpose_with_cast_product_and_cast_mask() {
+        let context = Context::init_default().unwrap();
+
+        let matrix = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            (2, 3).into(),
+            MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 1, 2.0).into(),
+                (1, 0, 3.0).into(),
+                (1, 2, 4.0).into(),
+            ]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        let mask = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            (3, 2).into(),
+            MatrixElementList::<i32>::from_element_vector(vec![
+                (1, 0, -1).into(),
+                (2, 1, 1).into(),
+            ]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let mut transpose = SparseMatrix::<u8>::new(context.clone(), (3, 2).into()).unwrap();
+
+        TypedTransposeMatrix::<f32>::apply(
+            &MatrixTranspose::new(),
+            &matrix,
+            &Assignment::new(),
+            &mut transpose.cast_mut::<f32>(),
+            &mask.cast::<bool>(),
+            &OptionsForOperatorWithMatrixArgument::new_default(),
+        )
+        .unwrap();
+
+        assert_eq!(transpose.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(transpose.element_value(1, 0).unwrap(), Some(2));
+        assert_eq!(transpose.element_value(0, 1).unwrap(), None);
+        assert_eq!(transpose.element_value(2, 1).unwrap(), Some(4));
+    }
+}
@@ -1,3 +1,7 @@
+mod unary_operator_type_names;
 mod unary_operators;
+mod user_defined_unary_operator;
 
+pub use unary_operator_type_names::*;
 pub use unary_operators::*;
+pub use user_defined_unary_operator::*;
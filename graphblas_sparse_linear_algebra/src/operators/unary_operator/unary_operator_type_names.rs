@@ -0,0 +1,58 @@
+use crate::context::{CallGraphBlasContext, Context};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::*;
+use crate::operators::user_defined_operator::from_c_char_buffer;
+use crate::value_type::ValueType;
+
+use super::UnaryOperator;
+
+/// Retrieves the names of the GraphBLAS types a unary operator z = f(x) is defined with.
+pub trait GetUnaryOperatorTypeNames<T: ValueType> {
+    fn input_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError>;
+    fn output_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType, O: UnaryOperator<T>> GetUnaryOperatorTypeNames<T> for O {
+    fn input_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError> {
+        type_name(context, self.graphblas_type(), GxB_UnaryOp_xtype_name)
+    }
+
+    fn output_type_name(&self, context: &Context) -> Result<String, SparseLinearAlgebraError> {
+        type_name(context, self.graphblas_type(), GxB_UnaryOp_ztype_name)
+    }
+}
+
+fn type_name(
+    context: &Context,
+    graphblas_operator: GrB_UnaryOp,
+    graphblas_function: unsafe extern "C" fn(*mut std::ffi::c_char, GrB_UnaryOp) -> GrB_Info,
+) -> Result<String, SparseLinearAlgebraError> {
+    let mut name = [0; GxB_MAX_NAME_LEN as usize];
+    context.call(
+        || unsafe { graphblas_function(name.as_mut_ptr(), graphblas_operator) },
+        &graphblas_operator,
+    )?;
+    from_c_char_buffer(&name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::unary_operator::{Identity, UserDefinedUnaryOperator};
+
+    #[test]
+    fn type_names_of_unary_operators() {
+        let context = Context::init_default().unwrap();
+
+        let identity = Identity::<i8>::new();
+        assert_eq!(identity.input_type_name(&context).unwrap(), "int8_t");
+        assert_eq!(identity.output_type_name(&context).unwrap(), "int8_t");
+
+        let is_positive =
+            UserDefinedUnaryOperator::<f64>::from_function(context.clone(), |x: &f64| *x > 0.0)
+                .unwrap();
+        assert_eq!(is_positive.input_type_name(&context).unwrap(), "double");
+        assert_eq!(is_positive.output_type_name(&context).unwrap(), "bool");
+    }
+}
@@ -7,7 +7,7 @@ use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
     implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types,
     implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types,
 };
-use crate::value_type::ValueType;
+use crate::value_type::{FloatingPoint, GraphBlasIndexInteger, Integer, ValueType};
 
 pub trait UnaryOperator<EvaluationDomain>
 where
@@ -16,6 +16,14 @@ where
     fn graphblas_type(&self) -> GrB_UnaryOp;
 }
 
+impl<EvaluationDomain: ValueType, O: UnaryOperator<EvaluationDomain> + ?Sized>
+    UnaryOperator<EvaluationDomain> for &O
+{
+    fn graphblas_type(&self) -> GrB_UnaryOp {
+        (**self).graphblas_type()
+    }
+}
+
 // macro_rules! implement_unary_operator {
 //     ($operator_name:ident,
 //         $graphblas_operator_name:ident,
@@ -39,8 +47,11 @@ where
 
 macro_rules! define_unary_operator {
     ($identifier: ident) => {
+        define_unary_operator!($identifier, ValueType);
+    };
+    ($identifier: ident, $evaluation_domain_bound:ident) => {
         #[derive(Debug, Clone)]
-        pub struct $identifier<EvaluationDomain: ValueType> {
+        pub struct $identifier<EvaluationDomain: $evaluation_domain_bound> {
             _evaluation_domain: PhantomData<EvaluationDomain>,
         }
     };
@@ -48,11 +59,14 @@ macro_rules! define_unary_operator {
 
 macro_rules! implement_unary_operator {
     ($operator_name:ident, $graphblas_operator_trait_name:ident) => {
-        pub trait $graphblas_operator_trait_name<T: ValueType> {
+        implement_unary_operator!($operator_name, $graphblas_operator_trait_name, ValueType);
+    };
+    ($operator_name:ident, $graphblas_operator_trait_name:ident, $evaluation_domain_bound:ident) => {
+        pub trait $graphblas_operator_trait_name<T: $evaluation_domain_bound> {
             fn graphblas_type() -> GrB_UnaryOp;
         }
 
-        impl<T: ValueType + $graphblas_operator_trait_name<T>> UnaryOperator<T>
+        impl<T: $evaluation_domain_bound + $graphblas_operator_trait_name<T>> UnaryOperator<T>
             for $operator_name<T>
         {
             fn graphblas_type(&self) -> GrB_UnaryOp {
@@ -60,7 +74,7 @@ macro_rules! implement_unary_operator {
             }
         }
 
-        impl<T: ValueType> $operator_name<T> {
+        impl<T: $evaluation_domain_bound> $operator_name<T> {
             pub fn new() -> Self {
                 Self {
                     _evaluation_domain: PhantomData,
@@ -131,72 +145,96 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GxB_LNOT
 );
 
-define_unary_operator!(BitwiseNegation);
-implement_unary_operator!(BitwiseNegation, BitwiseNegationUnaryOperatorTyped);
+define_unary_operator!(BitwiseNegation, Integer);
+implement_unary_operator!(BitwiseNegation, BitwiseNegationUnaryOperatorTyped, Integer);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types!(
     implement_typed_unary_operator,
     BitwiseNegationUnaryOperatorTyped,
     GrB_BNOT
 );
 
-define_unary_operator!(RowIndex);
-implement_unary_operator!(RowIndex, RowIndexUnaryOperatorTyped);
+define_unary_operator!(RowIndex, GraphBlasIndexInteger);
+implement_unary_operator!(RowIndex, RowIndexUnaryOperatorTyped, GraphBlasIndexInteger);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
     implement_typed_unary_operator,
     RowIndexUnaryOperatorTyped,
     GxB_POSITIONI
 );
 
-define_unary_operator!(ColumnIndex);
-implement_unary_operator!(ColumnIndex, ColumnIndexUnaryOperatorTyped);
+define_unary_operator!(ColumnIndex, GraphBlasIndexInteger);
+implement_unary_operator!(
+    ColumnIndex,
+    ColumnIndexUnaryOperatorTyped,
+    GraphBlasIndexInteger
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
     implement_typed_unary_operator,
     ColumnIndexUnaryOperatorTyped,
     GxB_POSITIONJ
 );
 
-define_unary_operator!(SquareRoot);
-implement_unary_operator!(SquareRoot, SquareRootUnaryOperatorTyped);
+define_unary_operator!(SquareRoot, FloatingPoint);
+implement_unary_operator!(SquareRoot, SquareRootUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     SquareRootUnaryOperatorTyped,
     GxB_SQRT
 );
 
-define_unary_operator!(NaturalLogarithm);
-implement_unary_operator!(NaturalLogarithm, NaturalLogarithmUnaryOperatorTyped);
+define_unary_operator!(NaturalLogarithm, FloatingPoint);
+implement_unary_operator!(
+    NaturalLogarithm,
+    NaturalLogarithmUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     NaturalLogarithmUnaryOperatorTyped,
     GxB_LOG
 );
 
-define_unary_operator!(NaturalExponent);
-implement_unary_operator!(NaturalExponent, NaturalExponentUnaryOperatorTyped);
+define_unary_operator!(NaturalExponent, FloatingPoint);
+implement_unary_operator!(
+    NaturalExponent,
+    NaturalExponentUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     NaturalExponentUnaryOperatorTyped,
     GxB_EXP
 );
 
-define_unary_operator!(Base10Logarithm);
-implement_unary_operator!(Base10Logarithm, Base10LogarithmUnaryOperatorTyped);
+define_unary_operator!(Base10Logarithm, FloatingPoint);
+implement_unary_operator!(
+    Base10Logarithm,
+    Base10LogarithmUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     Base10LogarithmUnaryOperatorTyped,
     GxB_LOG10
 );
 
-define_unary_operator!(Base2Logarithm);
-implement_unary_operator!(Base2Logarithm, Base2LogarithmUnaryOperatorTyped);
+define_unary_operator!(Base2Logarithm, FloatingPoint);
+implement_unary_operator!(
+    Base2Logarithm,
+    Base2LogarithmUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     Base2LogarithmUnaryOperatorTyped,
     GxB_LOG2
 );
 
-define_unary_operator!(Base2Exponent);
-implement_unary_operator!(Base2Exponent, Base2ExponentUnaryOperatorTyped);
+define_unary_operator!(Base2Exponent, FloatingPoint);
+implement_unary_operator!(
+    Base2Exponent,
+    Base2ExponentUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     Base2ExponentUnaryOperatorTyped,
@@ -204,10 +242,11 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
 );
 
 // z = exp(x)-1
-define_unary_operator!(NaturalExponentMinus1);
+define_unary_operator!(NaturalExponentMinus1, FloatingPoint);
 implement_unary_operator!(
     NaturalExponentMinus1,
-    NaturalExponentMinus1UnaryOperatorTyped
+    NaturalExponentMinus1UnaryOperatorTyped,
+    FloatingPoint
 );
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
@@ -216,10 +255,11 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
 );
 
 // z = log_e (x+1)
-define_unary_operator!(NaturalLogarithmOfArgumentPlusOne);
+define_unary_operator!(NaturalLogarithmOfArgumentPlusOne, FloatingPoint);
 implement_unary_operator!(
     NaturalLogarithmOfArgumentPlusOne,
-    NaturalLogarithmOfArgumentPlusOneUnaryOperatorTyped
+    NaturalLogarithmOfArgumentPlusOneUnaryOperatorTyped,
+    FloatingPoint
 );
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
@@ -227,82 +267,103 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
     GxB_LOG1P
 );
 
-define_unary_operator!(Sine);
-implement_unary_operator!(Sine, SineUnaryOperatorTyped);
+define_unary_operator!(Sine, FloatingPoint);
+implement_unary_operator!(Sine, SineUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     SineUnaryOperatorTyped,
     GxB_SIN
 );
 
-define_unary_operator!(Cosine);
-implement_unary_operator!(Cosine, CosineUnaryOperatorTyped);
+define_unary_operator!(Cosine, FloatingPoint);
+implement_unary_operator!(Cosine, CosineUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     CosineUnaryOperatorTyped,
     GxB_COS
 );
 
-define_unary_operator!(Tangent);
-implement_unary_operator!(Tangent, TangentUnaryOperatorTyped);
+define_unary_operator!(Tangent, FloatingPoint);
+implement_unary_operator!(Tangent, TangentUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     TangentUnaryOperatorTyped,
     GxB_TAN
 );
 
-define_unary_operator!(InverseSine);
-implement_unary_operator!(InverseSine, InverseSineUnaryOperatorTyped);
+define_unary_operator!(InverseSine, FloatingPoint);
+implement_unary_operator!(InverseSine, InverseSineUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     InverseSineUnaryOperatorTyped,
     GxB_ASIN
 );
 
-define_unary_operator!(InverseCosine);
-implement_unary_operator!(InverseCosine, InverseCosineUnaryOperatorTyped);
+define_unary_operator!(InverseCosine, FloatingPoint);
+implement_unary_operator!(
+    InverseCosine,
+    InverseCosineUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     InverseCosineUnaryOperatorTyped,
     GxB_ACOS
 );
 
-define_unary_operator!(InverseTangent);
-implement_unary_operator!(InverseTangent, InverseTangentUnaryOperatorTyped);
+define_unary_operator!(InverseTangent, FloatingPoint);
+implement_unary_operator!(
+    InverseTangent,
+    InverseTangentUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     InverseTangentUnaryOperatorTyped,
     GxB_ATAN
 );
 
-define_unary_operator!(HyberbolicSine);
-implement_unary_operator!(HyberbolicSine, HyberbolicSineUnaryOperatorTyped);
+define_unary_operator!(HyberbolicSine, FloatingPoint);
+implement_unary_operator!(
+    HyberbolicSine,
+    HyberbolicSineUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     HyberbolicSineUnaryOperatorTyped,
     GxB_SINH
 );
 
-define_unary_operator!(HyberbolicCosine);
-implement_unary_operator!(HyberbolicCosine, HyberbolicCosineUnaryOperatorTyped);
+define_unary_operator!(HyberbolicCosine, FloatingPoint);
+implement_unary_operator!(
+    HyberbolicCosine,
+    HyberbolicCosineUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     HyberbolicCosineUnaryOperatorTyped,
     GxB_COSH
 );
 
-define_unary_operator!(HyberbolicTangent);
-implement_unary_operator!(HyberbolicTangent, HyberbolicTangentUnaryOperatorTyped);
+define_unary_operator!(HyberbolicTangent, FloatingPoint);
+implement_unary_operator!(
+    HyberbolicTangent,
+    HyberbolicTangentUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     HyberbolicTangentUnaryOperatorTyped,
     GxB_TANH
 );
 
-define_unary_operator!(InverseHyberbolicSine);
+define_unary_operator!(InverseHyberbolicSine, FloatingPoint);
 implement_unary_operator!(
     InverseHyberbolicSine,
-    InverseHyberbolicSineUnaryOperatorTyped
+    InverseHyberbolicSineUnaryOperatorTyped,
+    FloatingPoint
 );
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
@@ -310,10 +371,11 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
     GxB_ASINH
 );
 
-define_unary_operator!(InverseHyberbolicCosine);
+define_unary_operator!(InverseHyberbolicCosine, FloatingPoint);
 implement_unary_operator!(
     InverseHyberbolicCosine,
-    InverseHyberbolicCosineUnaryOperatorTyped
+    InverseHyberbolicCosineUnaryOperatorTyped,
+    FloatingPoint
 );
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
@@ -321,10 +383,11 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
     GxB_ACOSH
 );
 
-define_unary_operator!(InverseHyberbolicTangent);
+define_unary_operator!(InverseHyberbolicTangent, FloatingPoint);
 implement_unary_operator!(
     InverseHyberbolicTangent,
-    InverseHyberbolicTangentUnaryOperatorTyped
+    InverseHyberbolicTangentUnaryOperatorTyped,
+    FloatingPoint
 );
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
@@ -332,74 +395,75 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
     GxB_ATANH
 );
 
-define_unary_operator!(Sign);
-implement_unary_operator!(Sign, SignUnaryOperatorTyped);
+define_unary_operator!(Sign, FloatingPoint);
+implement_unary_operator!(Sign, SignUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     SignUnaryOperatorTyped,
     GxB_SIGNUM
 );
 
-define_unary_operator!(Ceiling);
-implement_unary_operator!(Ceiling, CeilingUnaryOperatorTyped);
+define_unary_operator!(Ceiling, FloatingPoint);
+implement_unary_operator!(Ceiling, CeilingUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     CeilingUnaryOperatorTyped,
     GxB_CEIL
 );
 
-define_unary_operator!(Floor);
-implement_unary_operator!(Floor, FloorUnaryOperatorTyped);
+define_unary_operator!(Floor, FloatingPoint);
+implement_unary_operator!(Floor, FloorUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     FloorUnaryOperatorTyped,
     GxB_FLOOR
 );
 
-define_unary_operator!(Round);
-implement_unary_operator!(Round, RoundUnaryOperatorTyped);
+define_unary_operator!(Round, FloatingPoint);
+implement_unary_operator!(Round, RoundUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     RoundUnaryOperatorTyped,
     GxB_ROUND
 );
 
-define_unary_operator!(Truncate);
-implement_unary_operator!(Truncate, TruncateUnaryOperatorTyped);
+define_unary_operator!(Truncate, FloatingPoint);
+implement_unary_operator!(Truncate, TruncateUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     TruncateUnaryOperatorTyped,
     GxB_TRUNC
 );
 
-define_unary_operator!(IsInfinite);
-implement_unary_operator!(IsInfinite, IsInfiniteUnaryOperatorTyped);
+define_unary_operator!(IsInfinite, FloatingPoint);
+implement_unary_operator!(IsInfinite, IsInfiniteUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     IsInfiniteUnaryOperatorTyped,
     GxB_ISINF
 );
 
-define_unary_operator!(IsNaN);
-implement_unary_operator!(IsNaN, IsNaNUnaryOperatorTyped);
+define_unary_operator!(IsNaN, FloatingPoint);
+implement_unary_operator!(IsNaN, IsNaNUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     IsNaNUnaryOperatorTyped,
     GxB_ISNAN
 );
 
-define_unary_operator!(IsFinite);
-implement_unary_operator!(IsFinite, IsFiniteUnaryOperatorTyped);
+define_unary_operator!(IsFinite, FloatingPoint);
+implement_unary_operator!(IsFinite, IsFiniteUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     IsFiniteUnaryOperatorTyped,
     GxB_ISFINITE
 );
 
-define_unary_operator!(NaturalLogarithmOfGammaFunction);
+define_unary_operator!(NaturalLogarithmOfGammaFunction, FloatingPoint);
 implement_unary_operator!(
     NaturalLogarithmOfGammaFunction,
-    NaturalLogarithmOfGammaFunctionUnaryOperatorTyped
+    NaturalLogarithmOfGammaFunctionUnaryOperatorTyped,
+    FloatingPoint
 );
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
@@ -407,26 +471,35 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
     GxB_LGAMMA
 );
 
-define_unary_operator!(GammaFunction);
-implement_unary_operator!(GammaFunction, GammaFunctionUnaryOperatorTyped);
+define_unary_operator!(GammaFunction, FloatingPoint);
+implement_unary_operator!(
+    GammaFunction,
+    GammaFunctionUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     GammaFunctionUnaryOperatorTyped,
     GxB_TGAMMA
 );
 
-define_unary_operator!(ErrorFunction);
-implement_unary_operator!(ErrorFunction, ErrorFunctionUnaryOperatorTyped);
+define_unary_operator!(ErrorFunction, FloatingPoint);
+implement_unary_operator!(
+    ErrorFunction,
+    ErrorFunctionUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     ErrorFunctionUnaryOperatorTyped,
     GxB_ERF
 );
 
-define_unary_operator!(ComplimentoryErrorFunction);
+define_unary_operator!(ComplimentoryErrorFunction, FloatingPoint);
 implement_unary_operator!(
     ComplimentoryErrorFunction,
-    ComplimentoryErrorFunctionUnaryOperatorTyped
+    ComplimentoryErrorFunctionUnaryOperatorTyped,
+    FloatingPoint
 );
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
@@ -434,24 +507,32 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_
     GxB_ERFC
 );
 
-define_unary_operator!(CubeRoot);
-implement_unary_operator!(CubeRoot, CubeRootUnaryOperatorTyped);
+define_unary_operator!(CubeRoot, FloatingPoint);
+implement_unary_operator!(CubeRoot, CubeRootUnaryOperatorTyped, FloatingPoint);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     CubeRootUnaryOperatorTyped,
     GxB_CBRT
 );
 
-define_unary_operator!(NormalisedFraction);
-implement_unary_operator!(NormalisedFraction, NormalisedFractionUnaryOperatorTyped);
+define_unary_operator!(NormalisedFraction, FloatingPoint);
+implement_unary_operator!(
+    NormalisedFraction,
+    NormalisedFractionUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     NormalisedFractionUnaryOperatorTyped,
     GxB_FREXPX
 );
 
-define_unary_operator!(NormalisedExponent);
-implement_unary_operator!(NormalisedExponent, NormalisedExponentUnaryOperatorTyped);
+define_unary_operator!(NormalisedExponent, FloatingPoint);
+implement_unary_operator!(
+    NormalisedExponent,
+    NormalisedExponentUnaryOperatorTyped,
+    FloatingPoint
+);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_floating_point_types!(
     implement_typed_unary_operator,
     NormalisedExponentUnaryOperatorTyped,
@@ -482,6 +563,23 @@ mod tests {
         let _graphblas_type = additive_inverse.graphblas_type();
     }
 
+    #[test]
+    fn operators_restricted_to_value_type_domain() {
+        // Excluded value types are covered by the compile_fail examples of the marker traits.
+        fn square_root<T: FloatingPoint + SquareRootUnaryOperatorTyped<T>>() -> GrB_UnaryOp {
+            SquareRoot::<T>::new().graphblas_type()
+        }
+        fn bitwise_negation<T: Integer + BitwiseNegationUnaryOperatorTyped<T>>() -> GrB_UnaryOp {
+            BitwiseNegation::<T>::new().graphblas_type()
+        }
+
+        assert_ne!(square_root::<f32>(), square_root::<f64>());
+        assert_ne!(bitwise_negation::<u8>(), bitwise_negation::<i64>());
+        assert_eq!(RowIndex::<i32>::new().graphblas_type(), unsafe {
+            GxB_POSITIONI_INT32
+        });
+    }
+
     #[test]
     fn test_is_finite_and_type_casting() {
         let context = Context::init_default().unwrap();
@@ -0,0 +1,319 @@
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{GrB_UnaryOp, GrB_UnaryOp_free, GrB_UnaryOp_new, GxB_UnaryOp_new};
+use crate::operators::user_defined_operator::{
+    check_function_is_zero_sized, to_c_string, zero_sized_function_instance,
+};
+use crate::value_type::ValueType;
+
+use super::UnaryOperator;
+
+/// z = f(x), with f a user-defined function.
+/// The operator is freed when it is dropped.
+#[derive(Debug)]
+pub struct UserDefinedUnaryOperator<EvaluationDomain: ValueType> {
+    context: Arc<Context>,
+    graphblas_operator: GrB_UnaryOp,
+    _evaluation_domain: PhantomData<EvaluationDomain>,
+}
+
+// Implemented methods do not provide mutable access to the GraphBLAS operator.
+// Code review must consider that no mutable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<EvaluationDomain: ValueType> Send for UserDefinedUnaryOperator<EvaluationDomain> {}
+unsafe impl<EvaluationDomain: ValueType> Sync for UserDefinedUnaryOperator<EvaluationDomain> {}
+
+unsafe extern "C" fn unary_function_trampoline<X, Z, F>(z: *mut c_void, x: *const c_void)
+where
+    F: Fn(&X) -> Z,
+{
+    let function = unsafe { zero_sized_function_instance::<F>() };
+    let x = unsafe { (x as *const X).read_unaligned() };
+    unsafe { (z as *mut Z).write_unaligned(function(&x)) };
+}
+
+impl<EvaluationDomain: ValueType> UserDefinedUnaryOperator<EvaluationDomain> {
+    /// The function must be a function item or a closure that does not capture its environment.
+    pub fn from_function<Output, F>(
+        context: Arc<Context>,
+        _function: F,
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        Output: ValueType,
+        F: Fn(&EvaluationDomain) -> Output + Copy + 'static,
+    {
+        check_function_is_zero_sized::<F>()?;
+        unsafe {
+            Self::from_graphblas_function::<Output>(
+                context,
+                unary_function_trampoline::<EvaluationDomain, Output, F>,
+            )
+        }
+    }
+
+    /// The function must be a function item or a closure that does not capture its environment.
+    /// The JIT compiles the C definition under the given name instead of calling the function.
+    pub fn from_function_with_definition<Output, F>(
+        context: Arc<Context>,
+        _function: F,
+        name: &str,
+        definition: &str,
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        Output: ValueType,
+        F: Fn(&EvaluationDomain) -> Output + Copy + 'static,
+    {
+        check_function_is_zero_sized::<F>()?;
+        unsafe {
+            Self::from_graphblas_function_with_definition::<Output>(
+                context,
+                unary_function_trampoline::<EvaluationDomain, Output, F>,
+                name,
+                definition,
+            )
+        }
+    }
+
+    /// # Safety
+    /// The function must read a value of type EvaluationDomain from x and write a value of type Output to z.
+    pub unsafe fn from_graphblas_function<Output: ValueType>(
+        context: Arc<Context>,
+        function: unsafe extern "C" fn(z: *mut c_void, x: *const c_void),
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_operator: MaybeUninit<GrB_UnaryOp> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GrB_UnaryOp_new(
+                graphblas_operator.as_mut_ptr(),
+                Some(function),
+                Output::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            _evaluation_domain: PhantomData,
+        })
+    }
+
+    /// # Safety
+    /// The function must read a value of type EvaluationDomain from x and write a value of type Output to z.
+    /// The C definition must be equivalent to the function.
+    pub unsafe fn from_graphblas_function_with_definition<Output: ValueType>(
+        context: Arc<Context>,
+        function: unsafe extern "C" fn(z: *mut c_void, x: *const c_void),
+        name: &str,
+        definition: &str,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let name = to_c_string(name)?;
+        let definition = to_c_string(definition)?;
+        let mut graphblas_operator: MaybeUninit<GrB_UnaryOp> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GxB_UnaryOp_new(
+                graphblas_operator.as_mut_ptr(),
+                Some(function),
+                Output::to_graphblas_type(),
+                EvaluationDomain::to_graphblas_type(),
+                name.as_ptr(),
+                definition.as_ptr(),
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            _evaluation_domain: PhantomData,
+        })
+    }
+}
+
+impl<EvaluationDomain: ValueType> UnaryOperator<EvaluationDomain>
+    for UserDefinedUnaryOperator<EvaluationDomain>
+{
+    fn graphblas_type(&self) -> GrB_UnaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<EvaluationDomain: ValueType> GetContext for UserDefinedUnaryOperator<EvaluationDomain> {
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<EvaluationDomain: ValueType> Drop for UserDefinedUnaryOperator<EvaluationDomain> {
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_UnaryOp_free(&mut self.graphblas_operator)
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::collections::Collection;
+    use crate::operators::apply::{ApplyUnaryOperator, UnaryOperatorApplier};
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+
+    fn clamp_to_unit_interval(value: &f64) -> f64 {
+        value.clamp(0.0, 1.0)
+    }
+
+    unsafe extern "C" fn is_negative(z: *mut c_void, x: *const c_void) {
+        unsafe { *(z as *mut bool) = *(x as *const i32) < 0 };
+    }
+
+    #[test]
+    fn apply_user_defined_unary_operator_from_rust_function() {
+        let context = Context::init_default().unwrap();
+
+        let element_list = VectorElementList::<f64>::from_element_vector(vec![
+            (1, -0.5).into(),
+            (2, 0.25).into(),
+            (4, 1.5).into(),
+        ]);
+
+        let vector_length: usize = 10;
+        let vector = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            vector_length,
+            element_list,
+            &First::<f64>::new(),
+        )
+        .unwrap();
+
+        let mut product_vector = SparseVector::<f64>::new(context.clone(), vector_length).unwrap();
+
+        let clamp =
+            UserDefinedUnaryOperator::<f64>::from_function(context.clone(), clamp_to_unit_interval)
+                .unwrap();
+
+        UnaryOperatorApplier::new()
+            .apply_to_vector(
+                &clamp,
+                &vector,
+                &Assignment::<f64>::new(),
+                &mut product_vector,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product_vector.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product_vector.element_value_or_default(1).unwrap(), 0.0);
+        assert_eq!(product_vector.element_value_or_default(2).unwrap(), 0.25);
+        assert_eq!(product_vector.element_value_or_default(4).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn apply_user_defined_unary_operator_from_closure_with_other_output_type() {
+        let context = Context::init_default().unwrap();
+
+        let element_list =
+            VectorElementList::<u8>::from_element_vector(vec![(1, 3).into(), (2, 4).into()]);
+
+        let vector_length: usize = 10;
+        let vector = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            vector_length,
+            element_list,
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        let mut product_vector = SparseVector::<bool>::new(context.clone(), vector_length).unwrap();
+
+        let is_greater_than_three =
+            UserDefinedUnaryOperator::<u8>::from_function(context.clone(), |value: &u8| *value > 3)
+                .unwrap();
+
+        UnaryOperatorApplier::new()
+            .apply_to_vector(
+                &is_greater_than_three,
+                &vector,
+                &Assignment::<u8>::new(),
+                &mut product_vector,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product_vector.element_value(1).unwrap(), Some(false));
+        assert_eq!(product_vector.element_value(2).unwrap(), Some(true));
+    }
+
+    #[test]
+    fn apply_user_defined_unary_operator_from_graphblas_function() {
+        let context = Context::init_default().unwrap();
+
+        let element_list =
+            VectorElementList::<i32>::from_element_vector(vec![(1, -3).into(), (2, 4).into()]);
+
+        let vector_length: usize = 10;
+        let vector = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            vector_length,
+            element_list,
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        let mut product_vector = SparseVector::<bool>::new(context.clone(), vector_length).unwrap();
+
+        let is_negative = unsafe {
+            UserDefinedUnaryOperator::<i32>::from_graphblas_function::<bool>(
+                context.clone(),
+                is_negative,
+            )
+        }
+        .unwrap();
+
+        UnaryOperatorApplier::new()
+            .apply_to_vector(
+                &is_negative,
+                &vector,
+                &Assignment::<i32>::new(),
+                &mut product_vector,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product_vector.element_value(1).unwrap(), Some(true));
+        assert_eq!(product_vector.element_value(2).unwrap(), Some(false));
+    }
+
+    #[test]
+    fn reject_capturing_closure() {
+        let context = Context::init_default().unwrap();
+
+        let offset = 1.0;
+        let result =
+            UserDefinedUnaryOperator::<f64>::from_function(context.clone(), move |value: &f64| {
+                value + offset
+            });
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use std::ffi::{c_char, CStr, CString};
+use std::mem;
+use std::ptr::NonNull;
+
+use crate::error::{LogicError, LogicErrorType, SparseLinearAlgebraError};
+
+// GraphBLAS calls user-defined functions without a pointer to user data.
+// A Rust function can therefore only be called through a trampoline when its type identifies it,
+// i.e. when it is a function item or a closure that does not capture anything.
+// Such types are zero-sized and can be instantiated from nothing inside the trampoline.
+pub(crate) fn check_function_is_zero_sized<F>() -> Result<(), SparseLinearAlgebraError> {
+    if mem::size_of::<F>() == 0 {
+        Ok(())
+    } else {
+        Err(LogicError::new(
+            LogicErrorType::Other,
+            String::from(
+                "A user-defined operator can only be created from a function item or a closure that does not capture its environment",
+            ),
+            None,
+        )
+        .into())
+    }
+}
+
+/// # Safety
+/// F must be zero-sized, see check_function_is_zero_sized().
+pub(crate) unsafe fn zero_sized_function_instance<F>() -> F {
+    debug_assert_eq!(mem::size_of::<F>(), 0);
+    // Reading a zero-sized value from a dangling, well-aligned pointer is valid.
+    unsafe { NonNull::<F>::dangling().as_ptr().read() }
+}
+
+pub(crate) fn to_c_string(value: &str) -> Result<CString, SparseLinearAlgebraError> {
+    match CString::new(value) {
+        Ok(c_string) => Ok(c_string),
+        Err(error) => Err(LogicError::new(
+            LogicErrorType::Other,
+            format!("Unable to convert \"{}\" into a C string: {}", value, error),
+            None,
+        )
+        .into()),
+    }
+}
+
+/// Reads a name that GraphBLAS wrote into a buffer of GxB_MAX_NAME_LEN characters.
+pub(crate) fn from_c_char_buffer(buffer: &[c_char]) -> Result<String, SparseLinearAlgebraError> {
+    match CStr::from_bytes_until_nul(unsafe {
+        std::slice::from_raw_parts(buffer.as_ptr() as *const u8, buffer.len())
+    }) {
+        Ok(c_str) => Ok(c_str.to_string_lossy().into_owned()),
+        Err(error) => Err(LogicError::new(
+            LogicErrorType::Other,
+            format!("Unable to read name returned by GraphBLAS: {}", error),
+            None,
+        )
+        .into()),
+    }
+}
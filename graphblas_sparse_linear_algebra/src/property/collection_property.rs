@@ -0,0 +1,209 @@
+use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
+use crate::collections::sparse_scalar::GetGraphblasSparseScalar;
+use crate::collections::sparse_vector::GetGraphblasSparseVector;
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{GrB_Matrix, GrB_Scalar, GrB_Vector};
+
+use super::{
+    define_property_fields, get_graphblas_property, implement_graphblas_property_getter_and_setter,
+    set_graphblas_property, GetProperty, Property, PropertyValue, SetProperty,
+    SettablePropertyValue,
+};
+
+implement_graphblas_property_getter_and_setter!(GrB_Matrix, GrB_Matrix);
+implement_graphblas_property_getter_and_setter!(GrB_Vector, GrB_Vector);
+implement_graphblas_property_getter_and_setter!(GrB_Scalar, GrB_Scalar);
+
+define_property_fields!(
+    MatrixProperty {
+        Name => (GxB_Option_Field_GrB_NAME, String),
+        ElementTypeCode => (GxB_Option_Field_GrB_EL_TYPE_CODE, i32),
+        ElementTypeString => (GxB_Option_Field_GrB_EL_TYPE_STRING, String),
+        StorageOrientationHint => (GxB_Option_Field_GrB_STORAGE_ORIENTATION_HINT, i32),
+        Format => (GxB_Option_Field_GxB_FORMAT, i32),
+        SparsityControl => (GxB_Option_Field_GxB_SPARSITY_CONTROL, i32),
+        SparsityStatus => (GxB_Option_Field_GxB_SPARSITY_STATUS, i32),
+        HyperHash => (GxB_Option_Field_GxB_HYPER_HASH, i32),
+        Iso => (GxB_Option_Field_GxB_ISO, i32),
+        IsReadOnly => (GxB_Option_Field_GxB_IS_READONLY, i32),
+        WillWait => (GxB_Option_Field_GxB_WILL_WAIT, i32),
+        RowIndexIntegerHint => (GxB_Option_Field_GxB_ROWINDEX_INTEGER_HINT, i32),
+        ColumnIndexIntegerHint => (GxB_Option_Field_GxB_COLINDEX_INTEGER_HINT, i32),
+        OffsetIntegerHint => (GxB_Option_Field_GxB_OFFSET_INTEGER_HINT, i32),
+        RowIndexIntegerBits => (GxB_Option_Field_GxB_ROWINDEX_INTEGER_BITS, i32),
+        ColumnIndexIntegerBits => (GxB_Option_Field_GxB_COLINDEX_INTEGER_BITS, i32),
+        OffsetIntegerBits => (GxB_Option_Field_GxB_OFFSET_INTEGER_BITS, i32),
+    }
+);
+
+define_property_fields!(
+    VectorProperty {
+        Name => (GxB_Option_Field_GrB_NAME, String),
+        ElementTypeCode => (GxB_Option_Field_GrB_EL_TYPE_CODE, i32),
+        ElementTypeString => (GxB_Option_Field_GrB_EL_TYPE_STRING, String),
+        SparsityControl => (GxB_Option_Field_GxB_SPARSITY_CONTROL, i32),
+        SparsityStatus => (GxB_Option_Field_GxB_SPARSITY_STATUS, i32),
+        Iso => (GxB_Option_Field_GxB_ISO, i32),
+        IsReadOnly => (GxB_Option_Field_GxB_IS_READONLY, i32),
+        WillWait => (GxB_Option_Field_GxB_WILL_WAIT, i32),
+        RowIndexIntegerHint => (GxB_Option_Field_GxB_ROWINDEX_INTEGER_HINT, i32),
+        OffsetIntegerHint => (GxB_Option_Field_GxB_OFFSET_INTEGER_HINT, i32),
+        RowIndexIntegerBits => (GxB_Option_Field_GxB_ROWINDEX_INTEGER_BITS, i32),
+        OffsetIntegerBits => (GxB_Option_Field_GxB_OFFSET_INTEGER_BITS, i32),
+    }
+);
+
+define_property_fields!(
+    ScalarProperty {
+        Name => (GxB_Option_Field_GrB_NAME, String),
+        ElementTypeCode => (GxB_Option_Field_GrB_EL_TYPE_CODE, i32),
+        ElementTypeString => (GxB_Option_Field_GrB_EL_TYPE_STRING, String),
+    }
+);
+
+impl<M: GetGraphblasSparseMatrix> GetProperty<MatrixProperty> for M {
+    fn get_property<Value: PropertyValue>(
+        &self,
+        field: Property<MatrixProperty, Value>,
+    ) -> Result<Value, SparseLinearAlgebraError> {
+        get_graphblas_property(
+            self.context_ref(),
+            unsafe { self.graphblas_matrix_ptr() },
+            field,
+        )
+    }
+}
+
+impl<M: GetGraphblasSparseMatrix> SetProperty<MatrixProperty> for M {
+    fn set_property<Value: SettablePropertyValue>(
+        &mut self,
+        field: Property<MatrixProperty, Value>,
+        value: Value,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        set_graphblas_property(
+            self.context_ref(),
+            unsafe { self.graphblas_matrix_ptr() },
+            field,
+            value,
+        )
+    }
+}
+
+impl<V: GetGraphblasSparseVector> GetProperty<VectorProperty> for V {
+    fn get_property<Value: PropertyValue>(
+        &self,
+        field: Property<VectorProperty, Value>,
+    ) -> Result<Value, SparseLinearAlgebraError> {
+        get_graphblas_property(
+            self.context_ref(),
+            unsafe { self.graphblas_vector_ptr() },
+            field,
+        )
+    }
+}
+
+impl<V: GetGraphblasSparseVector> SetProperty<VectorProperty> for V {
+    fn set_property<Value: SettablePropertyValue>(
+        &mut self,
+        field: Property<VectorProperty, Value>,
+        value: Value,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        set_graphblas_property(
+            self.context_ref(),
+            unsafe { self.graphblas_vector_ptr() },
+            field,
+            value,
+        )
+    }
+}
+
+impl<S: GetGraphblasSparseScalar> GetProperty<ScalarProperty> for S {
+    fn get_property<Value: PropertyValue>(
+        &self,
+        field: Property<ScalarProperty, Value>,
+    ) -> Result<Value, SparseLinearAlgebraError> {
+        get_graphblas_property(
+            self.context_ref(),
+            unsafe { self.graphblas_scalar() },
+            field,
+        )
+    }
+}
+
+impl<S: GetGraphblasSparseScalar> SetProperty<ScalarProperty> for S {
+    fn set_property<Value: SettablePropertyValue>(
+        &mut self,
+        field: Property<ScalarProperty, Value>,
+        value: Value,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        set_graphblas_property(
+            self.context_ref(),
+            unsafe { self.graphblas_scalar() },
+            field,
+            value,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::SparseMatrix;
+    use crate::collections::sparse_scalar::SparseScalar;
+    use crate::collections::sparse_vector::SparseVector;
+    use crate::context::Context;
+
+    #[test]
+    fn get_and_set_matrix_properties() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = SparseMatrix::<f64>::new(context.clone(), (10, 5).into()).unwrap();
+
+        assert_eq!(
+            matrix
+                .get_property(MatrixProperty::ElementTypeString)
+                .unwrap(),
+            "GrB_FP64"
+        );
+
+        matrix
+            .set_property(MatrixProperty::Name, String::from("adjacency"))
+            .unwrap();
+        assert_eq!(
+            matrix.get_property(MatrixProperty::Name).unwrap(),
+            "adjacency"
+        );
+
+        assert!(matrix
+            .set_property(MatrixProperty::Name, String::from("nul\0byte"))
+            .is_err());
+    }
+
+    #[test]
+    fn get_and_set_vector_and_scalar_properties() {
+        let context = Context::init_default().unwrap();
+
+        let mut vector = SparseVector::<u16>::new(context.clone(), 10).unwrap();
+        vector
+            .set_property(VectorProperty::Name, String::from("degrees"))
+            .unwrap();
+        assert_eq!(
+            vector.get_property(VectorProperty::Name).unwrap(),
+            "degrees"
+        );
+        assert_eq!(
+            vector
+                .get_property(VectorProperty::ElementTypeString)
+                .unwrap(),
+            "GrB_UINT16"
+        );
+
+        let scalar = SparseScalar::<bool>::new(context.clone()).unwrap();
+        assert_eq!(
+            scalar
+                .get_property(ScalarProperty::ElementTypeString)
+                .unwrap(),
+            "GrB_BOOL"
+        );
+    }
+}
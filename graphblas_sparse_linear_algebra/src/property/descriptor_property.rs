@@ -0,0 +1,76 @@
+use crate::context::Context;
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::GrB_Descriptor;
+use crate::operators::options::GetGraphblasDescriptor;
+
+use super::{
+    define_property_fields, get_graphblas_property, implement_graphblas_property_getter_and_setter,
+    Property, PropertyValue,
+};
+
+implement_graphblas_property_getter_and_setter!(GrB_Descriptor, GrB_Descriptor);
+
+define_property_fields!(
+    DescriptorProperty {
+        Name => (GxB_Option_Field_GrB_NAME, String),
+        Output => (GxB_Option_Field_GrB_OUTP_FIELD, i32),
+        Mask => (GxB_Option_Field_GrB_MASK_FIELD, i32),
+        FirstInput => (GxB_Option_Field_GrB_INP0_FIELD, i32),
+        SecondInput => (GxB_Option_Field_GrB_INP1_FIELD, i32),
+    }
+);
+
+/// Operator options map onto the predefined GraphBLAS descriptors, which are read-only.
+pub trait GetDescriptorProperty {
+    fn get_property<Value: PropertyValue>(
+        &self,
+        context: &Context,
+        field: Property<DescriptorProperty, Value>,
+    ) -> Result<Value, SparseLinearAlgebraError>;
+}
+
+impl<D: GetGraphblasDescriptor> GetDescriptorProperty for D {
+    fn get_property<Value: PropertyValue>(
+        &self,
+        context: &Context,
+        field: Property<DescriptorProperty, Value>,
+    ) -> Result<Value, SparseLinearAlgebraError> {
+        get_graphblas_property(context, self.graphblas_descriptor(), field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graphblas_bindings::{
+        GrB_Desc_Value_GrB_COMP, GrB_Desc_Value_GrB_REPLACE, GrB_Desc_Value_GrB_TRAN,
+    };
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+
+    #[test]
+    fn get_descriptor_properties() {
+        let context = Context::init_default().unwrap();
+
+        let options = OptionsForOperatorWithMatrixArguments::new(true, false, true, false, true);
+
+        assert_eq!(
+            options
+                .get_property(&context, DescriptorProperty::Output)
+                .unwrap(),
+            GrB_Desc_Value_GrB_REPLACE as i32
+        );
+        assert_eq!(
+            options
+                .get_property(&context, DescriptorProperty::Mask)
+                .unwrap(),
+            GrB_Desc_Value_GrB_COMP as i32
+        );
+        assert_eq!(
+            options
+                .get_property(&context, DescriptorProperty::SecondInput)
+                .unwrap(),
+            GrB_Desc_Value_GrB_TRAN as i32
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use crate::context::Context;
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{GrB_GLOBAL, GrB_Global};
+
+use super::{
+    define_property_fields, get_graphblas_property, implement_graphblas_property_getter_and_setter,
+    set_graphblas_property, Property, PropertyValue, SettablePropertyValue,
+};
+
+implement_graphblas_property_getter_and_setter!(GrB_Global, GrB_Global);
+
+define_property_fields!(
+    /// Properties of the GraphBLAS library, shared by all contexts in the process.
+    GlobalProperty {
+        /// The name of the GraphBLAS implementation.
+        Name => (GxB_Option_Field_GrB_NAME, String),
+        LibraryVersionMajor => (GxB_Option_Field_GrB_LIBRARY_VER_MAJOR, i32),
+        LibraryVersionMinor => (GxB_Option_Field_GrB_LIBRARY_VER_MINOR, i32),
+        LibraryVersionPatch => (GxB_Option_Field_GrB_LIBRARY_VER_PATCH, i32),
+        ApiVersionMajor => (GxB_Option_Field_GrB_API_VER_MAJOR, i32),
+        ApiVersionMinor => (GxB_Option_Field_GrB_API_VER_MINOR, i32),
+        ApiVersionPatch => (GxB_Option_Field_GrB_API_VER_PATCH, i32),
+        BlockingMode => (GxB_Option_Field_GrB_BLOCKING_MODE, i32),
+        StorageOrientationHint => (GxB_Option_Field_GrB_STORAGE_ORIENTATION_HINT, i32),
+        /// Three native-endian C ints: major, minor and patch.
+        LibraryVersion => (GxB_Option_Field_GxB_LIBRARY_VERSION, Vec<u8>),
+        LibraryDate => (GxB_Option_Field_GxB_LIBRARY_DATE, String),
+        LibraryAbout => (GxB_Option_Field_GxB_LIBRARY_ABOUT, String),
+        LibraryUrl => (GxB_Option_Field_GxB_LIBRARY_URL, String),
+        LibraryLicense => (GxB_Option_Field_GxB_LIBRARY_LICENSE, String),
+        LibraryCompileDate => (GxB_Option_Field_GxB_LIBRARY_COMPILE_DATE, String),
+        LibraryCompileTime => (GxB_Option_Field_GxB_LIBRARY_COMPILE_TIME, String),
+        LibraryOpenMp => (GxB_Option_Field_GxB_LIBRARY_OPENMP, i32),
+        /// Three native-endian C ints: major, minor and patch.
+        ApiVersion => (GxB_Option_Field_GxB_API_VERSION, Vec<u8>),
+        ApiDate => (GxB_Option_Field_GxB_API_DATE, String),
+        ApiAbout => (GxB_Option_Field_GxB_API_ABOUT, String),
+        ApiUrl => (GxB_Option_Field_GxB_API_URL, String),
+        /// The compiler that built GraphBLAS.
+        CompilerName => (GxB_Option_Field_GxB_COMPILER_NAME, String),
+        /// Three native-endian C ints: major, minor and patch.
+        CompilerVersion => (GxB_Option_Field_GxB_COMPILER_VERSION, Vec<u8>),
+        Format => (GxB_Option_Field_GxB_FORMAT, i32),
+        HyperHash => (GxB_Option_Field_GxB_HYPER_HASH, i32),
+        NumberOfThreads => (GxB_Option_Field_GxB_GLOBAL_NTHREADS, i32),
+        Burble => (GxB_Option_Field_GxB_BURBLE, i32),
+        PrintOneBased => (GxB_Option_Field_GxB_PRINT_1BASED, i32),
+        IncludeReadOnlyStatistics => (GxB_Option_Field_GxB_INCLUDE_READONLY_STATISTICS, i32),
+        JitCControl => (GxB_Option_Field_GxB_JIT_C_CONTROL, i32),
+        JitCCompilerName => (GxB_Option_Field_GxB_JIT_C_COMPILER_NAME, String),
+        JitCCompilerFlags => (GxB_Option_Field_GxB_JIT_C_COMPILER_FLAGS, String),
+        JitCLinkerFlags => (GxB_Option_Field_GxB_JIT_C_LINKER_FLAGS, String),
+        JitCLibraries => (GxB_Option_Field_GxB_JIT_C_LIBRARIES, String),
+        JitCCmakeLibraries => (GxB_Option_Field_GxB_JIT_C_CMAKE_LIBS, String),
+        JitCPreface => (GxB_Option_Field_GxB_JIT_C_PREFACE, String),
+        JitUseCmake => (GxB_Option_Field_GxB_JIT_USE_CMAKE, i32),
+        JitCachePath => (GxB_Option_Field_GxB_JIT_CACHE_PATH, String),
+        JitErrorLog => (GxB_Option_Field_GxB_JIT_ERROR_LOG, String),
+    }
+);
+
+impl Context {
+    pub fn get_property<Value: PropertyValue>(
+        &self,
+        field: Property<GlobalProperty, Value>,
+    ) -> Result<Value, SparseLinearAlgebraError> {
+        get_graphblas_property(self, unsafe { GrB_GLOBAL }, field)
+    }
+
+    /// Global properties apply to every context in the process.
+    pub fn set_property<Value: SettablePropertyValue>(
+        &self,
+        field: Property<GlobalProperty, Value>,
+        value: Value,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        set_graphblas_property(self, unsafe { GrB_GLOBAL }, field, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::lock_global_configuration;
+
+    #[test]
+    fn get_library_properties() {
+        let context = Context::init_default().unwrap();
+
+        assert_eq!(
+            context.get_property(GlobalProperty::Name).unwrap(),
+            "SuiteSparse:GraphBLAS"
+        );
+        assert_eq!(
+            context
+                .get_property(GlobalProperty::ApiVersionMajor)
+                .unwrap(),
+            2
+        );
+        assert!(!context
+            .get_property(GlobalProperty::LibraryLicense)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            context
+                .get_property(GlobalProperty::LibraryVersion)
+                .unwrap()
+                .len(),
+            3 * std::mem::size_of::<std::ffi::c_int>()
+        );
+    }
+
+    #[test]
+    fn set_global_property() {
+        let _global_configuration = lock_global_configuration();
+        let context = Context::init_default().unwrap();
+
+        // Only affects memory usage statistics, which no other test reads.
+        let include_read_only_statistics = context
+            .get_property(GlobalProperty::IncludeReadOnlyStatistics)
+            .unwrap();
+        context
+            .set_property(
+                GlobalProperty::IncludeReadOnlyStatistics,
+                1 - include_read_only_statistics,
+            )
+            .unwrap();
+        assert_eq!(
+            context
+                .get_property(GlobalProperty::IncludeReadOnlyStatistics)
+                .unwrap(),
+            1 - include_read_only_statistics
+        );
+        context
+            .set_property(
+                GlobalProperty::IncludeReadOnlyStatistics,
+                include_read_only_statistics,
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,17 @@
+mod collection_property;
+mod descriptor_property;
+mod global_property;
+mod name;
+mod operator_property;
+mod property;
+mod serialized_property;
+mod value_type_property;
+
+pub use collection_property::*;
+pub use descriptor_property::*;
+pub use global_property::*;
+pub use name::*;
+pub use operator_property::*;
+pub use property::*;
+pub use serialized_property::*;
+pub use value_type_property::*;
@@ -0,0 +1,194 @@
+use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
+use crate::collections::sparse_scalar::{GetGraphblasSparseScalar, SparseScalar};
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::Context;
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{GrB_Matrix, GrB_Scalar, GrB_Vector};
+use crate::operators::binary_operator::UserDefinedBinaryOperator;
+use crate::operators::index_binary_operator::UserDefinedIndexBinaryOperator;
+use crate::operators::index_unary_operator::UserDefinedIndexUnaryOperator;
+use crate::operators::monoid::UserDefinedMonoid;
+use crate::operators::semiring::UserDefinedSemiring;
+use crate::operators::unary_operator::UserDefinedUnaryOperator;
+use crate::value_type::ValueType;
+
+use super::{
+    get_graphblas_property, GetProperty, MatrixProperty, OperatorProperty, ScalarProperty,
+    SetProperty, VectorProperty,
+};
+
+/// The GrB_NAME of a GraphBLAS object, an empty string if it has not been named.
+pub trait GetName {
+    fn name(&self) -> Result<String, SparseLinearAlgebraError>;
+}
+
+/// Names appear in the error messages of operations on the object.
+pub trait SetName {
+    fn set_name(&mut self, name: &str) -> Result<(), SparseLinearAlgebraError>;
+}
+
+macro_rules! implement_name {
+    ($object:ident<$($type_parameter:ident),+>, $field:expr) => {
+        impl<$($type_parameter: ValueType),+> GetName for $object<$($type_parameter),+> {
+            fn name(&self) -> Result<String, SparseLinearAlgebraError> {
+                self.get_property($field)
+            }
+        }
+
+        impl<$($type_parameter: ValueType),+> SetName for $object<$($type_parameter),+> {
+            fn set_name(&mut self, name: &str) -> Result<(), SparseLinearAlgebraError> {
+                self.set_property($field, name.to_owned())
+            }
+        }
+    };
+}
+
+implement_name!(SparseMatrix<T>, MatrixProperty::Name);
+implement_name!(SparseVector<T>, VectorProperty::Name);
+implement_name!(SparseScalar<T>, ScalarProperty::Name);
+
+// User-defined operators own their context, built-in operators have fixed names.
+macro_rules! implement_operator_name {
+    ($operator:ident<$($lifetime:lifetime,)? $($type_parameter:ident),+>, $operator_trait:path) => {
+        impl<$($lifetime,)? $($type_parameter: ValueType),+> GetName
+            for $operator<$($lifetime,)? $($type_parameter),+> {
+            fn name(&self) -> Result<String, SparseLinearAlgebraError> {
+                get_graphblas_property(
+                    crate::context::GetContext::context_ref(self),
+                    <Self as $operator_trait>::graphblas_type(self),
+                    OperatorProperty::Name,
+                )
+            }
+        }
+
+        impl<$($lifetime,)? $($type_parameter: ValueType),+> SetName
+            for $operator<$($lifetime,)? $($type_parameter),+> {
+            fn set_name(&mut self, name: &str) -> Result<(), SparseLinearAlgebraError> {
+                self.set_property(OperatorProperty::Name, name.to_owned())
+            }
+        }
+    };
+}
+
+implement_operator_name!(
+    UserDefinedUnaryOperator<EvaluationDomain>,
+    crate::operators::unary_operator::UnaryOperator<EvaluationDomain>
+);
+implement_operator_name!(
+    UserDefinedBinaryOperator<EvaluationDomain, Output>,
+    crate::operators::binary_operator::BinaryOperator<EvaluationDomain>
+);
+implement_operator_name!(
+    UserDefinedIndexUnaryOperator<EvaluationDomain, Output>,
+    crate::operators::index_unary_operator::IndexUnaryOperator<EvaluationDomain>
+);
+implement_operator_name!(
+    UserDefinedIndexBinaryOperator<EvaluationDomain, Output, Theta>,
+    crate::operators::index_binary_operator::IndexBinaryOperator<EvaluationDomain>
+);
+implement_operator_name!(
+    UserDefinedMonoid<'a, T>,
+    crate::operators::monoid::Monoid<T>
+);
+implement_operator_name!(
+    UserDefinedSemiring<'a, EvaluationDomain>,
+    crate::operators::semiring::Semiring<EvaluationDomain>
+);
+
+/// A collection taking part in an operation, to name it in error messages.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Operand {
+    Matrix(GrB_Matrix),
+    Vector(GrB_Vector),
+    Scalar(GrB_Scalar),
+}
+
+impl Operand {
+    pub(crate) fn matrix(matrix: &(impl GetGraphblasSparseMatrix + ?Sized)) -> Self {
+        Operand::Matrix(unsafe { matrix.graphblas_matrix_ptr() })
+    }
+
+    pub(crate) fn vector(vector: &(impl GetGraphblasSparseVector + ?Sized)) -> Self {
+        Operand::Vector(unsafe { vector.graphblas_vector_ptr() })
+    }
+
+    pub(crate) fn scalar(scalar: &(impl GetGraphblasSparseScalar + ?Sized)) -> Self {
+        Operand::Scalar(unsafe { scalar.graphblas_scalar() })
+    }
+
+    // Failing to retrieve a name must not hide the original error.
+    pub(crate) fn name(&self, context: &Context) -> Option<String> {
+        let name = match self {
+            Operand::Matrix(matrix) => {
+                get_graphblas_property(context, *matrix, MatrixProperty::Name)
+            }
+            Operand::Vector(vector) => {
+                get_graphblas_property(context, *vector, VectorProperty::Name)
+            }
+            Operand::Scalar(scalar) => {
+                get_graphblas_property(context, *scalar, ScalarProperty::Name)
+            }
+        };
+        name.ok().filter(|name| !name.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::binary_operator::Assignment;
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::multiplication::{MatrixMultiplicationOperator, MultiplyMatrices};
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+    use crate::operators::semiring::PlusTimes;
+
+    #[test]
+    fn name_collections_and_operators() {
+        let context = Context::init_default().unwrap();
+
+        let mut matrix = SparseMatrix::<f32>::new(context.clone(), (2, 2).into()).unwrap();
+        assert_eq!(matrix.name().unwrap(), "");
+        matrix.set_name("adjacency").unwrap();
+        assert_eq!(matrix.name().unwrap(), "adjacency");
+
+        let mut vector = SparseVector::<i8>::new(context.clone(), 2).unwrap();
+        vector.set_name("frontier").unwrap();
+        assert_eq!(vector.name().unwrap(), "frontier");
+
+        let mut operator =
+            UserDefinedBinaryOperator::<f64>::from_function(context.clone(), |x: &f64, y: &f64| {
+                x.hypot(*y)
+            })
+            .unwrap();
+        operator.set_name("hypotenuse").unwrap();
+        assert_eq!(operator.name().unwrap(), "hypotenuse");
+    }
+
+    #[test]
+    fn error_message_names_operands() {
+        let context = Context::init_default().unwrap();
+
+        let mut multiplier = SparseMatrix::<f32>::new(context.clone(), (2, 3).into()).unwrap();
+        multiplier.set_name("multiplier").unwrap();
+        let multiplicant = SparseMatrix::<f32>::new(context.clone(), (2, 3).into()).unwrap();
+        let mut product = SparseMatrix::<f32>::new(context.clone(), (2, 3).into()).unwrap();
+        product.set_name("product").unwrap();
+
+        let error = MatrixMultiplicationOperator::new()
+            .apply(
+                &multiplier,
+                &PlusTimes::<f32>::new(),
+                &multiplicant,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap_err();
+
+        let message = error.to_string();
+        assert!(message.contains("Output: \"product\" (2x3 matrix)"));
+        assert!(message.contains("Inputs: \"multiplier\" (2x3 matrix), <unnamed> (2x3 matrix)"));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{
+    GrB_BinaryOp, GrB_IndexUnaryOp, GrB_Monoid, GrB_Semiring, GrB_UnaryOp, GxB_IndexBinaryOp,
+};
+use crate::operators::binary_operator::{BinaryOperator, UserDefinedBinaryOperator};
+use crate::operators::index_binary_operator::{
+    IndexBinaryOperator, UserDefinedIndexBinaryOperator,
+};
+use crate::operators::index_unary_operator::{IndexUnaryOperator, UserDefinedIndexUnaryOperator};
+use crate::operators::monoid::{Monoid, UserDefinedMonoid};
+use crate::operators::semiring::{Semiring, UserDefinedSemiring};
+use crate::operators::unary_operator::{UnaryOperator, UserDefinedUnaryOperator};
+use crate::value_type::ValueType;
+
+use super::{
+    define_property_fields, get_graphblas_property, implement_graphblas_property_getter_and_setter,
+    set_graphblas_property, Property, PropertyValue, SetProperty, SettablePropertyValue,
+};
+
+implement_graphblas_property_getter_and_setter!(GrB_UnaryOp, GrB_UnaryOp);
+implement_graphblas_property_getter_and_setter!(GrB_BinaryOp, GrB_BinaryOp);
+implement_graphblas_property_getter_and_setter!(GrB_IndexUnaryOp, GrB_IndexUnaryOp);
+implement_graphblas_property_getter_and_setter!(GxB_IndexBinaryOp, GxB_IndexBinaryOp);
+implement_graphblas_property_getter_and_setter!(GrB_Monoid, GrB_Monoid);
+implement_graphblas_property_getter_and_setter!(GrB_Semiring, GrB_Semiring);
+
+define_property_fields!(
+    /// Properties of unary, binary and index operators, monoids and semirings.
+    /// For index operators, the second input is the operator argument y, or theta.
+    OperatorProperty {
+        Name => (GxB_Option_Field_GrB_NAME, String),
+        JitCName => (GxB_Option_Field_GxB_JIT_C_NAME, String),
+        JitCDefinition => (GxB_Option_Field_GxB_JIT_C_DEFINITION, String),
+        FirstInputTypeCode => (GxB_Option_Field_GrB_INP0_TYPE_CODE, i32),
+        SecondInputTypeCode => (GxB_Option_Field_GrB_INP1_TYPE_CODE, i32),
+        OutputTypeCode => (GxB_Option_Field_GrB_OUTP_TYPE_CODE, i32),
+        FirstInputTypeString => (GxB_Option_Field_GrB_INP0_TYPE_STRING, String),
+        SecondInputTypeString => (GxB_Option_Field_GrB_INP1_TYPE_STRING, String),
+        OutputTypeString => (GxB_Option_Field_GrB_OUTP_TYPE_STRING, String),
+    }
+);
+
+// Built-in operators are not owned by a context, so the context to call GraphBLAS with is an argument.
+macro_rules! implement_get_operator_property {
+    ($trait_name:ident, $operator_trait:ident) => {
+        pub trait $trait_name<T: ValueType> {
+            fn get_property<Value: PropertyValue>(
+                &self,
+                context: &Context,
+                field: Property<OperatorProperty, Value>,
+            ) -> Result<Value, SparseLinearAlgebraError>;
+        }
+
+        impl<T: ValueType, O: $operator_trait<T>> $trait_name<T> for O {
+            fn get_property<Value: PropertyValue>(
+                &self,
+                context: &Context,
+                field: Property<OperatorProperty, Value>,
+            ) -> Result<Value, SparseLinearAlgebraError> {
+                get_graphblas_property(context, self.graphblas_type(), field)
+            }
+        }
+    };
+}
+
+implement_get_operator_property!(GetUnaryOperatorProperty, UnaryOperator);
+implement_get_operator_property!(GetBinaryOperatorProperty, BinaryOperator);
+implement_get_operator_property!(GetIndexUnaryOperatorProperty, IndexUnaryOperator);
+implement_get_operator_property!(GetIndexBinaryOperatorProperty, IndexBinaryOperator);
+implement_get_operator_property!(GetMonoidProperty, Monoid);
+implement_get_operator_property!(GetSemiringProperty, Semiring);
+
+macro_rules! implement_set_operator_property {
+    ($operator:ident<$($lifetime:lifetime,)? $($type_parameter:ident),+>, $operator_trait:ident) => {
+        impl<$($lifetime,)? $($type_parameter: ValueType),+> SetProperty<OperatorProperty>
+            for $operator<$($lifetime,)? $($type_parameter),+> {
+            fn set_property<Value: SettablePropertyValue>(
+                &mut self,
+                field: Property<OperatorProperty, Value>,
+                value: Value,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                set_graphblas_property(
+                    self.context_ref(),
+                    $operator_trait::graphblas_type(self),
+                    field,
+                    value,
+                )
+            }
+        }
+    };
+}
+
+implement_set_operator_property!(UserDefinedUnaryOperator<EvaluationDomain>, UnaryOperator);
+implement_set_operator_property!(
+    UserDefinedBinaryOperator<EvaluationDomain, Output>,
+    BinaryOperator
+);
+implement_set_operator_property!(
+    UserDefinedIndexUnaryOperator<EvaluationDomain, Output>,
+    IndexUnaryOperator
+);
+implement_set_operator_property!(
+    UserDefinedIndexBinaryOperator<EvaluationDomain, Output, Theta>,
+    IndexBinaryOperator
+);
+implement_set_operator_property!(UserDefinedMonoid<'a, T>, Monoid);
+implement_set_operator_property!(UserDefinedSemiring<'a, EvaluationDomain>, Semiring);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::binary_operator::Plus;
+    use crate::operators::semiring::PlusTimes;
+
+    #[test]
+    fn get_built_in_operator_properties() {
+        let context = Context::init_default().unwrap();
+
+        assert_eq!(
+            Plus::<i32>::new()
+                .get_property(&context, OperatorProperty::Name)
+                .unwrap(),
+            "GrB_PLUS_INT32"
+        );
+        assert_eq!(
+            Plus::<i32>::new()
+                .get_property(&context, OperatorProperty::OutputTypeString)
+                .unwrap(),
+            "GrB_INT32"
+        );
+        assert_eq!(
+            PlusTimes::<f32>::new()
+                .get_property(&context, OperatorProperty::Name)
+                .unwrap(),
+            "GrB_PLUS_TIMES_SEMIRING_FP32"
+        );
+    }
+
+    #[test]
+    fn set_name_of_user_defined_operator() {
+        let context = Context::init_default().unwrap();
+
+        let mut operator =
+            UserDefinedBinaryOperator::<u8>::from_function(context.clone(), |x: &u8, y: &u8| {
+                x.saturating_add(*y)
+            })
+            .unwrap();
+        operator
+            .set_property(OperatorProperty::Name, String::from("saturating_add"))
+            .unwrap();
+
+        assert_eq!(
+            operator
+                .get_property(&context, OperatorProperty::Name)
+                .unwrap(),
+            "saturating_add"
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::context::Context;
+use crate::error::{LogicError, LogicErrorType, SparseLinearAlgebraError};
+use crate::graphblas_bindings::GrB_Info;
+
+pub(crate) use sealed::{GraphblasPropertyGetter, GraphblasPropertySetter};
+
+/// A field read from or written to a GraphBLAS object with GrB_get() and GrB_set().
+/// Object identifies the kind of GraphBLAS object, Value is the type of the value of the field.
+pub struct Property<Object, Value> {
+    graphblas_field: c_int,
+    name: &'static str,
+    _object_and_value: PhantomData<fn() -> (Object, Value)>,
+}
+
+impl<Object, Value> Property<Object, Value> {
+    pub(crate) const fn new(graphblas_field: c_int, name: &'static str) -> Self {
+        Self {
+            graphblas_field,
+            name,
+            _object_and_value: PhantomData,
+        }
+    }
+}
+
+impl<Object, Value> Clone for Property<Object, Value> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Object, Value> Copy for Property<Object, Value> {}
+
+impl<Object, Value> fmt::Debug for Property<Object, Value> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl<Object, Value> PartialEq for Property<Object, Value> {
+    fn eq(&self, other: &Self) -> bool {
+        self.graphblas_field == other.graphblas_field
+    }
+}
+
+/// The types of property values: i32, usize, String and Vec<u8>.
+pub trait PropertyValue: sealed::GetPropertyValue {}
+
+/// GraphBLAS does not set properties with usize values.
+pub trait SettablePropertyValue: PropertyValue + sealed::SetPropertyValue {}
+
+/// For GraphBLAS objects that are owned together with their Context.
+pub trait GetProperty<Object> {
+    fn get_property<Value: PropertyValue>(
+        &self,
+        field: Property<Object, Value>,
+    ) -> Result<Value, SparseLinearAlgebraError>;
+}
+
+/// For GraphBLAS objects that are owned together with their Context.
+///
+/// ```compile_fail
+/// use graphblas_sparse_linear_algebra::collections::sparse_matrix::SparseMatrix;
+/// use graphblas_sparse_linear_algebra::context::Context;
+/// use graphblas_sparse_linear_algebra::property::{MatrixProperty, SetProperty};
+///
+/// let context = Context::init_default().unwrap();
+/// let mut matrix = SparseMatrix::<f64>::new(context, (10, 5).into()).unwrap();
+/// matrix.set_property(MatrixProperty::Name, 3).unwrap();
+/// ```
+pub trait SetProperty<Object> {
+    fn set_property<Value: SettablePropertyValue>(
+        &mut self,
+        field: Property<Object, Value>,
+        value: Value,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+macro_rules! implement_graphblas_property_getter_and_setter {
+    ($graphblas_type:ty, $graphblas_object:ident) => {
+        paste::paste! {
+            impl crate::property::GraphblasPropertyGetter for $graphblas_type {
+                unsafe fn get_integer(self, value: *mut i32, field: std::ffi::c_int) -> crate::graphblas_bindings::GrB_Info {
+                    unsafe { crate::graphblas_bindings::[<$graphblas_object _get_INT32>](self, value, field) }
+                }
+
+                unsafe fn get_size(self, value: *mut usize, field: std::ffi::c_int) -> crate::graphblas_bindings::GrB_Info {
+                    unsafe { crate::graphblas_bindings::[<$graphblas_object _get_SIZE>](self, value, field) }
+                }
+
+                unsafe fn get_string(self, value: *mut std::ffi::c_char, field: std::ffi::c_int) -> crate::graphblas_bindings::GrB_Info {
+                    unsafe { crate::graphblas_bindings::[<$graphblas_object _get_String>](self, value, field) }
+                }
+
+                unsafe fn get_bytes(self, value: *mut std::ffi::c_void, field: std::ffi::c_int) -> crate::graphblas_bindings::GrB_Info {
+                    unsafe { crate::graphblas_bindings::[<$graphblas_object _get_VOID>](self, value, field) }
+                }
+            }
+
+            impl crate::property::GraphblasPropertySetter for $graphblas_type {
+                unsafe fn set_integer(self, value: i32, field: std::ffi::c_int) -> crate::graphblas_bindings::GrB_Info {
+                    unsafe { crate::graphblas_bindings::[<$graphblas_object _set_INT32>](self, value, field) }
+                }
+
+                unsafe fn set_string(self, value: *mut std::ffi::c_char, field: std::ffi::c_int) -> crate::graphblas_bindings::GrB_Info {
+                    unsafe { crate::graphblas_bindings::[<$graphblas_object _set_String>](self, value, field) }
+                }
+
+                unsafe fn set_bytes(self, value: *mut std::ffi::c_void, field: std::ffi::c_int, size: usize) -> crate::graphblas_bindings::GrB_Info {
+                    unsafe { crate::graphblas_bindings::[<$graphblas_object _set_VOID>](self, value, field, size) }
+                }
+            }
+        }
+    };
+}
+pub(crate) use implement_graphblas_property_getter_and_setter;
+
+pub(crate) fn get_graphblas_property<Object, Value: PropertyValue>(
+    context: &Context,
+    graphblas_object: impl GraphblasPropertyGetter,
+    field: Property<Object, Value>,
+) -> Result<Value, SparseLinearAlgebraError> {
+    Value::get(context, graphblas_object, field)
+}
+
+pub(crate) fn set_graphblas_property<Object, Value: SettablePropertyValue>(
+    context: &Context,
+    graphblas_object: impl GraphblasPropertySetter,
+    field: Property<Object, Value>,
+    value: Value,
+) -> Result<(), SparseLinearAlgebraError> {
+    value.set(context, graphblas_object, field)
+}
+
+// Not nameable outside the crate, which seals PropertyValue and SettablePropertyValue.
+mod sealed {
+    use super::*;
+
+    // Wraps the typed GrB_<Object>_get_<Kind> functions of a GraphBLAS object kind.
+    pub trait GraphblasPropertyGetter: Copy {
+        unsafe fn get_integer(self, value: *mut i32, field: c_int) -> GrB_Info;
+        unsafe fn get_size(self, value: *mut usize, field: c_int) -> GrB_Info;
+        unsafe fn get_string(self, value: *mut c_char, field: c_int) -> GrB_Info;
+        unsafe fn get_bytes(self, value: *mut c_void, field: c_int) -> GrB_Info;
+    }
+
+    // Wraps the typed GrB_<Object>_set_<Kind> functions of a GraphBLAS object kind.
+    pub trait GraphblasPropertySetter: Copy {
+        unsafe fn set_integer(self, value: i32, field: c_int) -> GrB_Info;
+        unsafe fn set_string(self, value: *mut c_char, field: c_int) -> GrB_Info;
+        unsafe fn set_bytes(self, value: *mut c_void, field: c_int, size: usize) -> GrB_Info;
+    }
+
+    pub trait GetPropertyValue: Sized {
+        fn get<Object>(
+            context: &Context,
+            graphblas_object: impl GraphblasPropertyGetter,
+            field: Property<Object, Self>,
+        ) -> Result<Self, SparseLinearAlgebraError>;
+    }
+
+    pub trait SetPropertyValue: Sized {
+        fn set<Object>(
+            self,
+            context: &Context,
+            graphblas_object: impl GraphblasPropertySetter,
+            field: Property<Object, Self>,
+        ) -> Result<(), SparseLinearAlgebraError>;
+    }
+}
+
+impl PropertyValue for i32 {}
+impl PropertyValue for usize {}
+impl PropertyValue for String {}
+impl PropertyValue for Vec<u8> {}
+
+impl SettablePropertyValue for i32 {}
+impl SettablePropertyValue for String {}
+impl SettablePropertyValue for Vec<u8> {}
+
+impl sealed::GetPropertyValue for i32 {
+    fn get<Object>(
+        context: &Context,
+        graphblas_object: impl GraphblasPropertyGetter,
+        field: Property<Object, Self>,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut value: i32 = 0;
+        context.call_without_detailed_error_information(|| unsafe {
+            graphblas_object.get_integer(&mut value, field.graphblas_field)
+        })?;
+        Ok(value)
+    }
+}
+
+impl sealed::GetPropertyValue for usize {
+    fn get<Object>(
+        context: &Context,
+        graphblas_object: impl GraphblasPropertyGetter,
+        field: Property<Object, Self>,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut value: usize = 0;
+        context.call_without_detailed_error_information(|| unsafe {
+            graphblas_object.get_size(&mut value, field.graphblas_field)
+        })?;
+        Ok(value)
+    }
+}
+
+impl sealed::GetPropertyValue for String {
+    fn get<Object>(
+        context: &Context,
+        graphblas_object: impl GraphblasPropertyGetter,
+        field: Property<Object, Self>,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let size = size_of_property(context, graphblas_object, field.graphblas_field)?;
+        let mut value: Vec<c_char> = vec![0; size.max(1)];
+        context.call_without_detailed_error_information(|| unsafe {
+            graphblas_object.get_string(value.as_mut_ptr(), field.graphblas_field)
+        })?;
+        let value: Vec<u8> = value.into_iter().map(|character| character as u8).collect();
+        string_from_nul_terminated_bytes(&value, field)
+    }
+}
+
+impl sealed::GetPropertyValue for Vec<u8> {
+    fn get<Object>(
+        context: &Context,
+        graphblas_object: impl GraphblasPropertyGetter,
+        field: Property<Object, Self>,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let size = size_of_property(context, graphblas_object, field.graphblas_field)?;
+        let mut value: Vec<u8> = vec![0; size];
+        context.call_without_detailed_error_information(|| unsafe {
+            graphblas_object.get_bytes(value.as_mut_ptr().cast(), field.graphblas_field)
+        })?;
+        Ok(value)
+    }
+}
+
+impl sealed::SetPropertyValue for i32 {
+    fn set<Object>(
+        self,
+        context: &Context,
+        graphblas_object: impl GraphblasPropertySetter,
+        field: Property<Object, Self>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        context.call_without_detailed_error_information(|| unsafe {
+            graphblas_object.set_integer(self, field.graphblas_field)
+        })?;
+        Ok(())
+    }
+}
+
+impl sealed::SetPropertyValue for String {
+    fn set<Object>(
+        self,
+        context: &Context,
+        graphblas_object: impl GraphblasPropertySetter,
+        field: Property<Object, Self>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let value = match CString::new(self) {
+            Ok(value) => value,
+            Err(error) => {
+                return Err(LogicError::new(
+                    LogicErrorType::Other,
+                    format!("Unable to set property {:?}: {}", field, error),
+                    None,
+                )
+                .into())
+            }
+        };
+        // GraphBLAS copies the string.
+        context.call_without_detailed_error_information(|| unsafe {
+            graphblas_object.set_string(value.as_ptr() as *mut c_char, field.graphblas_field)
+        })?;
+        Ok(())
+    }
+}
+
+impl sealed::SetPropertyValue for Vec<u8> {
+    fn set<Object>(
+        mut self,
+        context: &Context,
+        graphblas_object: impl GraphblasPropertySetter,
+        field: Property<Object, Self>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        context.call_without_detailed_error_information(|| unsafe {
+            graphblas_object.set_bytes(self.as_mut_ptr().cast(), field.graphblas_field, self.len())
+        })?;
+        Ok(())
+    }
+}
+
+// For string and byte properties, GrB_get_SIZE returns the size of the buffer to provide.
+fn size_of_property(
+    context: &Context,
+    graphblas_object: impl GraphblasPropertyGetter,
+    graphblas_field: c_int,
+) -> Result<usize, SparseLinearAlgebraError> {
+    let mut size: usize = 0;
+    context.call_without_detailed_error_information(|| unsafe {
+        graphblas_object.get_size(&mut size, graphblas_field)
+    })?;
+    Ok(size)
+}
+
+fn string_from_nul_terminated_bytes<Object>(
+    value: &[u8],
+    field: Property<Object, String>,
+) -> Result<String, SparseLinearAlgebraError> {
+    match CStr::from_bytes_until_nul(value) {
+        Ok(value) => Ok(value.to_string_lossy().into_owned()),
+        Err(error) => Err(LogicError::new(
+            LogicErrorType::Other,
+            format!(
+                "Unable to read property {:?} returned by GraphBLAS: {}",
+                field, error
+            ),
+            None,
+        )
+        .into()),
+    }
+}
+
+// Defines a type that groups the property fields of a kind of GraphBLAS object.
+// Each field is an associated constant with its GraphBLAS field and value type.
+macro_rules! define_property_fields {
+    ($(#[$attribute:meta])* $name:ident { $($(#[$field_attribute:meta])* $field:ident => ($graphblas_field:ident, $value_type:ty)),* $(,)? }) => {
+        $(#[$attribute])*
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub enum $name {}
+
+        #[allow(non_upper_case_globals)]
+        impl $name {
+            $(
+                $(#[$field_attribute])*
+                pub const $field: crate::property::Property<$name, $value_type> =
+                    crate::property::Property::new(
+                        crate::graphblas_bindings::$graphblas_field as std::ffi::c_int,
+                        stringify!($field),
+                    );
+            )*
+        }
+    };
+}
+pub(crate) use define_property_fields;
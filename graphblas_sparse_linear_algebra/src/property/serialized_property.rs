@@ -0,0 +1,106 @@
+use std::ffi::{c_char, c_int, c_void};
+
+use crate::context::Context;
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{
+    GrB_Info, GxB_Serialized_get_INT32, GxB_Serialized_get_SIZE, GxB_Serialized_get_String,
+    GxB_Serialized_get_VOID,
+};
+
+use super::{
+    define_property_fields, get_graphblas_property, GraphblasPropertyGetter, Property,
+    PropertyValue,
+};
+
+define_property_fields!(
+    /// Properties of a matrix or vector, read from its serialized form without deserializing it.
+    SerializedProperty {
+        Name => (GxB_Option_Field_GrB_NAME, String),
+        ElementTypeCode => (GxB_Option_Field_GrB_EL_TYPE_CODE, i32),
+        ElementTypeString => (GxB_Option_Field_GrB_EL_TYPE_STRING, String),
+        ElementTypeJitCName => (GxB_Option_Field_GxB_JIT_C_NAME, String),
+        StorageOrientationHint => (GxB_Option_Field_GrB_STORAGE_ORIENTATION_HINT, i32),
+        Format => (GxB_Option_Field_GxB_FORMAT, i32),
+        SparsityControl => (GxB_Option_Field_GxB_SPARSITY_CONTROL, i32),
+        SparsityStatus => (GxB_Option_Field_GxB_SPARSITY_STATUS, i32),
+    }
+);
+
+#[derive(Clone, Copy)]
+struct SerializedBlob<'a>(&'a [u8]);
+
+impl<'a> GraphblasPropertyGetter for SerializedBlob<'a> {
+    unsafe fn get_integer(self, value: *mut i32, field: c_int) -> GrB_Info {
+        unsafe { GxB_Serialized_get_INT32(self.0.as_ptr().cast(), value, field, self.0.len()) }
+    }
+
+    unsafe fn get_size(self, value: *mut usize, field: c_int) -> GrB_Info {
+        unsafe { GxB_Serialized_get_SIZE(self.0.as_ptr().cast(), value, field, self.0.len()) }
+    }
+
+    unsafe fn get_string(self, value: *mut c_char, field: c_int) -> GrB_Info {
+        unsafe { GxB_Serialized_get_String(self.0.as_ptr().cast(), value, field, self.0.len()) }
+    }
+
+    unsafe fn get_bytes(self, value: *mut c_void, field: c_int) -> GrB_Info {
+        unsafe { GxB_Serialized_get_VOID(self.0.as_ptr().cast(), value, field, self.0.len()) }
+    }
+}
+
+pub trait GetSerializedProperty {
+    fn get_property<Value: PropertyValue>(
+        &self,
+        context: &Context,
+        field: Property<SerializedProperty, Value>,
+    ) -> Result<Value, SparseLinearAlgebraError>;
+}
+
+impl GetSerializedProperty for [u8] {
+    fn get_property<Value: PropertyValue>(
+        &self,
+        context: &Context,
+        field: Property<SerializedProperty, Value>,
+    ) -> Result<Value, SparseLinearAlgebraError> {
+        get_graphblas_property(context, SerializedBlob(self), field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::SerializeSuitesparseGraphblasSparseMatrix;
+    use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
+    use crate::collections::SerializerWithoutCompression;
+    use crate::property::{MatrixProperty, SetProperty};
+
+    #[test]
+    fn get_properties_of_serialized_matrix() {
+        let context = Context::init_default().unwrap();
+
+        let mut matrix = SparseMatrix::<i16>::new(context.clone(), (4, 4).into()).unwrap();
+        matrix
+            .set_property(MatrixProperty::Name, String::from("serialized"))
+            .unwrap();
+
+        let serializer = SerializerWithoutCompression::new(context.clone()).unwrap();
+        let serialized_matrix = unsafe {
+            serializer
+                .serialize_suitesparse_grapblas_sparse_matrix(matrix.graphblas_matrix_ptr())
+                .unwrap()
+        };
+
+        assert_eq!(
+            serialized_matrix
+                .get_property(&context, SerializedProperty::Name)
+                .unwrap(),
+            "serialized"
+        );
+        assert_eq!(
+            serialized_matrix
+                .get_property(&context, SerializedProperty::ElementTypeString)
+                .unwrap(),
+            "GrB_INT16"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use crate::context::Context;
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::GrB_Type;
+use crate::value_type::ValueType;
+
+use super::{
+    define_property_fields, get_graphblas_property, implement_graphblas_property_getter_and_setter,
+    Property, PropertyValue,
+};
+
+implement_graphblas_property_getter_and_setter!(GrB_Type, GrB_Type);
+
+define_property_fields!(
+    ValueTypeProperty {
+        Name => (GxB_Option_Field_GrB_NAME, String),
+        TypeCode => (GxB_Option_Field_GrB_EL_TYPE_CODE, i32),
+        /// The size of a value in bytes.
+        Size => (GxB_Option_Field_GrB_SIZE, usize),
+        JitCName => (GxB_Option_Field_GxB_JIT_C_NAME, String),
+        JitCDefinition => (GxB_Option_Field_GxB_JIT_C_DEFINITION, String),
+    }
+);
+
+pub trait GetValueTypeProperty {
+    fn get_property<Value: PropertyValue>(
+        context: &Context,
+        field: Property<ValueTypeProperty, Value>,
+    ) -> Result<Value, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> GetValueTypeProperty for T {
+    fn get_property<Value: PropertyValue>(
+        context: &Context,
+        field: Property<ValueTypeProperty, Value>,
+    ) -> Result<Value, SparseLinearAlgebraError> {
+        get_graphblas_property(context, T::to_graphblas_type(), field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_value_type_properties() {
+        let context = Context::init_default().unwrap();
+
+        assert_eq!(
+            f64::get_property(&context, ValueTypeProperty::Size).unwrap(),
+            8
+        );
+        assert_eq!(
+            u32::get_property(&context, ValueTypeProperty::Name).unwrap(),
+            "GrB_UINT32"
+        );
+        assert_eq!(
+            isize::get_property(&context, ValueTypeProperty::Size).unwrap(),
+            std::mem::size_of::<isize>()
+        );
+    }
+}
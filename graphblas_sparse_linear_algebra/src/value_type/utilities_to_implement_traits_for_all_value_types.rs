@@ -74,6 +74,17 @@ macro_rules! implement_macro_for_all_integers {
 }
 pub(crate) use implement_macro_for_all_integers;
 
+macro_rules! implement_macro_for_all_unsigned_integers {
+    ($macro_identifier:ident) => {
+        $macro_identifier!(u8);
+        $macro_identifier!(u16);
+        $macro_identifier!(u32);
+        $macro_identifier!(u64);
+        $macro_identifier!(usize);
+    };
+}
+pub(crate) use implement_macro_for_all_unsigned_integers;
+
 macro_rules! implement_macro_for_all_graphblas_index_integers {
     ($macro_identifier:ident) => {
         // $macro_identifier!(bool);
@@ -574,6 +585,19 @@ macro_rules! implement_macro_with_1_type_trait_and_typed_graphblas_function_for_
 }
 pub(crate) use implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_with_postfix;
 
+macro_rules! implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers {
+    ($macro_identifier:ident, $trait:ty, $graphblas_identifier:ident) => {
+        paste::paste! {
+            $macro_identifier!($trait, [<$graphblas_identifier _UINT8>], u8);
+            $macro_identifier!($trait, [<$graphblas_identifier _UINT16>], u16);
+            $macro_identifier!($trait, [<$graphblas_identifier _UINT32>], u32);
+            $macro_identifier!($trait, [<$graphblas_identifier _UINT64>], u64);
+            graphblas_sparse_linear_algebra_proc_macros::implement_macro_with_1_type_trait_and_typed_graphblas_function_for_usize!($macro_identifier, $trait, $graphblas_identifier);
+        }
+    };
+}
+pub(crate) use implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers;
+
 macro_rules! implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers_with_postfix {
     ($macro_identifier:ident, $trait:ty, $graphblas_identifier:ident, $postfix:ident) => {
         paste::paste! {
@@ -3,7 +3,12 @@ use crate::graphblas_bindings::{
     GrB_UINT32, GrB_UINT64, GrB_UINT8,
 };
 
-use crate::value_type::utilities_to_implement_traits_for_all_value_types::implement_macro_for_all_value_types_and_graphblas_function;
+use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
+    implement_macro_for_all_floating_point_value_types,
+    implement_macro_for_all_graphblas_index_integers, implement_macro_for_all_integers,
+    implement_macro_for_all_unsigned_integers,
+    implement_macro_for_all_value_types_and_graphblas_function,
+};
 
 pub trait ValueType {
     fn to_graphblas_type() -> GrB_Type;
@@ -23,3 +28,63 @@ implement_macro_for_all_value_types_and_graphblas_function!(
     implement_value_type_for_graphblas_built_in_type,
     GrB
 );
+
+// Marker traits restrict operators to the value types for which GraphBLAS defines them.
+
+/// Operators like SquareRoot are only defined for floating point types.
+///
+/// ```compile_fail
+/// use graphblas_sparse_linear_algebra::operators::unary_operator::SquareRoot;
+///
+/// let square_root = SquareRoot::<i32>::new();
+/// ```
+pub trait FloatingPoint: ValueType {}
+
+/// Bitwise operators are only defined for integer types.
+///
+/// ```compile_fail
+/// use graphblas_sparse_linear_algebra::operators::unary_operator::BitwiseNegation;
+///
+/// let bitwise_negation = BitwiseNegation::<f32>::new();
+/// ```
+pub trait Integer: ValueType {}
+
+/// Bitwise monoids and semirings are only defined for unsigned integers.
+pub trait UnsignedInteger: Integer {}
+
+/// The integer types used by GraphBLAS for indices, as returned by positional operators.
+///
+/// ```compile_fail
+/// use graphblas_sparse_linear_algebra::operators::semiring::MinRowIndexFirstArgument;
+///
+/// let positional_semiring = MinRowIndexFirstArgument::<f64>::new();
+/// ```
+pub trait GraphBlasIndexInteger: Integer {}
+
+macro_rules! implement_floating_point {
+    ($value_type: ty) => {
+        impl FloatingPoint for $value_type {}
+    };
+}
+implement_macro_for_all_floating_point_value_types!(implement_floating_point);
+
+macro_rules! implement_integer {
+    ($value_type: ty) => {
+        impl Integer for $value_type {}
+    };
+}
+implement_macro_for_all_integers!(implement_integer);
+
+macro_rules! implement_unsigned_integer {
+    ($value_type: ty) => {
+        impl UnsignedInteger for $value_type {}
+    };
+}
+implement_macro_for_all_unsigned_integers!(implement_unsigned_integer);
+
+macro_rules! implement_graphblas_index_integer {
+    ($value_type: ty) => {
+        impl GraphBlasIndexInteger for $value_type {}
+    };
+}
+implement_macro_for_all_graphblas_index_integers!(implement_graphblas_index_integer);